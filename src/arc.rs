@@ -0,0 +1,178 @@
+use crate::{rotate_vector_by_degrees, Shape};
+use std::f64::consts::PI;
+use vector2d::Vector2D;
+
+/// A circular arc stroked with round caps. Angles are measured counter-clockwise from the
+/// positive x axis; the arc runs from the start angle through the sweep angle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arc {
+    center: Vector2D<f64>,
+    radius: f64,
+    thickness: f64, // full stroke width
+    start_angle_in_degrees: f64,
+    sweep_angle_in_degrees: f64,
+}
+
+impl Arc {
+    pub fn new(
+        center: Vector2D<f64>,
+        radius: f64,
+        thickness: f64,
+        start_angle_in_degrees: f64,
+        sweep_angle_in_degrees: f64,
+    ) -> Self {
+        if !(0.0..=360.0).contains(&sweep_angle_in_degrees) {
+            panic!("Sweep angle must be between 0 and 360 degrees.");
+        }
+
+        Arc {
+            center,
+            radius,
+            thickness,
+            start_angle_in_degrees,
+            sweep_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    pub fn thickness(&self) -> f64 {
+        self.thickness
+    }
+
+    pub fn start_angle_in_degrees(&self) -> f64 {
+        self.start_angle_in_degrees
+    }
+
+    pub fn sweep_angle_in_degrees(&self) -> f64 {
+        self.sweep_angle_in_degrees
+    }
+
+    pub fn end_angle_in_degrees(&self) -> f64 {
+        self.start_angle_in_degrees + self.sweep_angle_in_degrees
+    }
+
+    pub fn length(&self) -> f64 {
+        // along the center line
+        self.radius * self.sweep_angle_in_degrees.to_radians()
+    }
+
+    fn is_full_circle(&self) -> bool {
+        self.sweep_angle_in_degrees == 360.0
+    }
+}
+
+impl Shape for Arc {
+    /// Exact as long as half the thickness does not exceed the radius.
+    fn area(&self) -> f64 {
+        let half_thickness = self.thickness * 0.5;
+
+        if self.is_full_circle() {
+            self.length() * self.thickness
+        } else {
+            self.length() * self.thickness + PI * half_thickness * half_thickness
+        }
+    }
+
+    fn perimeter(&self) -> f64 {
+        if self.is_full_circle() {
+            2.0 * self.length()
+        } else {
+            2.0 * self.length() + PI * self.thickness
+        }
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        // translate to center the arc at origin and turn it so it is symmetric around +y
+        let bisector = self.start_angle_in_degrees + self.sweep_angle_in_degrees * 0.5;
+        let translated = rotate_vector_by_degrees(&(*point - self.center), 90.0 - bisector);
+        let p = Vector2D::new(translated.x.abs(), translated.y);
+
+        let half_sweep = (self.sweep_angle_in_degrees * 0.5).to_radians();
+        let (sine, cosine) = half_sweep.sin_cos();
+
+        let distance = if cosine * p.x > sine * p.y {
+            // beyond the end of the arc: distance to its end point
+            (p - Vector2D::new(sine, cosine) * self.radius).length()
+        } else {
+            (p.length() - self.radius).abs()
+        };
+
+        distance - self.thickness * 0.5
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::arc::Arc;
+    use crate::{get_area, get_sdf, Shape};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    #[test]
+    fn create_arc() {
+        let arc = Arc::new(Vector2D::new(10.0, 10.0), 10.0, 2.0, 0.0, 90.0);
+
+        assert_eq!(
+            format!("The arc is: {arc:?}"),
+            "The arc is: Arc { center: Vector2D { x: 10.0, y: 10.0 }, radius: 10.0, thickness: 2.0, start_angle_in_degrees: 0.0, sweep_angle_in_degrees: 90.0 }"
+        );
+
+        assert_eq!(arc.end_angle_in_degrees(), 90.0);
+        assert_eq!(arc.length(), 5.0 * PI);
+        assert_eq!(get_area(&arc), 10.0 * PI + PI);
+        assert_eq!(arc.perimeter(), 10.0 * PI + 2.0 * PI);
+
+        let full = Arc::new(Vector2D::new(10.0, 10.0), 10.0, 2.0, 0.0, 360.0);
+        assert_eq!(full.area(), PI * (11.0 * 11.0 - 9.0 * 9.0));
+        assert_eq!(full.perimeter(), 2.0 * PI * (11.0 + 9.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn arc_sweep_is_limited() {
+        Arc::new(Vector2D::new(0.0, 0.0), 10.0, 2.0, 0.0, 400.0);
+    }
+
+    #[test]
+    fn arc_equality() {
+        let arc1 = Arc::new(Vector2D::new(10.0, 10.0), 10.0, 2.0, 0.0, 90.0);
+        let arc2 = Arc::new(Vector2D::new(10.0, 10.0), 10.0, 2.0, 0.0, 90.0);
+        let arc3 = Arc::new(Vector2D::new(10.0, 10.0), 10.0, 2.0, 90.0, 90.0);
+
+        assert!(arc1 == arc2);
+        assert!(arc1 != arc3);
+    }
+
+    #[test]
+    fn arc_sdf() {
+        // the quarter from +x to +y
+        let arc = Arc::new(Vector2D::new(0.0, 0.0), 10.0, 2.0, 0.0, 90.0);
+
+        let on_arc = Vector2D::new(10.0, 10.0) * (0.5_f64.sqrt());
+        assert!((get_sdf(&arc, &on_arc) + 1.0).abs() < 1e-12);
+        assert!((arc.sdf(&(on_arc * 2.0)) - 9.0).abs() < 1e-12);
+        assert!((arc.sdf(&Vector2D::new(0.0, 0.0)) - 9.0).abs() < 1e-12);
+
+        // past the ends the distance is to the end points
+        assert!((arc.sdf(&Vector2D::new(10.0, -3.0)) - 2.0).abs() < 1e-12);
+        assert!((arc.sdf(&Vector2D::new(-4.0, 13.0)) - 4.0).abs() < 1e-12);
+        assert!((arc.sdf(&Vector2D::new(-10.0, 0.0)) - (200.0_f64.sqrt() - 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn arc_major_sdf() {
+        // three quarters, leaving out the lower right quadrant
+        let arc = Arc::new(Vector2D::new(0.0, 0.0), 10.0, 2.0, 0.0, 270.0);
+
+        assert!((arc.sdf(&Vector2D::new(-10.0, 0.0)) + 1.0).abs() < 1e-12);
+        assert!((arc.sdf(&Vector2D::new(0.0, -10.0)) + 1.0).abs() < 1e-12);
+        assert!((arc.sdf(&Vector2D::new(10.0, -10.0)) - 9.0).abs() < 1e-12);
+    }
+}
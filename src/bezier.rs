@@ -0,0 +1,772 @@
+use crate::{cross_product, dot_product, Shape};
+use std::f64::consts::PI;
+use vector2d::Vector2D;
+
+/// A single quadratic or cubic Bézier curve given by its control points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BezierCurve {
+    Quadratic(Vector2D<f64>, Vector2D<f64>, Vector2D<f64>),
+    Cubic(Vector2D<f64>, Vector2D<f64>, Vector2D<f64>, Vector2D<f64>),
+}
+
+impl BezierCurve {
+    pub fn start(&self) -> Vector2D<f64> {
+        match *self {
+            BezierCurve::Quadratic(p0, _, _) => p0,
+            BezierCurve::Cubic(p0, _, _, _) => p0,
+        }
+    }
+
+    pub fn end(&self) -> Vector2D<f64> {
+        match *self {
+            BezierCurve::Quadratic(_, _, p2) => p2,
+            BezierCurve::Cubic(_, _, _, p3) => p3,
+        }
+    }
+
+    pub fn point_at(&self, t: f64) -> Vector2D<f64> {
+        let s = 1.0 - t;
+
+        match *self {
+            BezierCurve::Quadratic(p0, p1, p2) => p0 * (s * s) + p1 * (2.0 * s * t) + p2 * (t * t),
+            BezierCurve::Cubic(p0, p1, p2, p3) => {
+                p0 * (s * s * s)
+                    + p1 * (3.0 * s * s * t)
+                    + p2 * (3.0 * s * t * t)
+                    + p3 * (t * t * t)
+            }
+        }
+    }
+
+    pub fn derivative_at(&self, t: f64) -> Vector2D<f64> {
+        let s = 1.0 - t;
+
+        match *self {
+            BezierCurve::Quadratic(p0, p1, p2) => (p1 - p0) * (2.0 * s) + (p2 - p1) * (2.0 * t),
+            BezierCurve::Cubic(p0, p1, p2, p3) => {
+                (p1 - p0) * (3.0 * s * s) + (p2 - p1) * (6.0 * s * t) + (p3 - p2) * (3.0 * t * t)
+            }
+        }
+    }
+
+    /// Arc length by composite Gauss-Legendre quadrature.
+    pub fn length(&self) -> f64 {
+        integrate(|t| self.derivative_at(t).length())
+    }
+
+    /// Parameter of the point on the curve closest to `point`.
+    pub fn closest_parameter(&self, point: &Vector2D<f64>) -> f64 {
+        let candidates = match *self {
+            BezierCurve::Quadratic(p0, p1, p2) => {
+                // d/dt |B(t) - p|^2 = 0 is a cubic in t and is solved analytically
+                let a = p1 - p0;
+                let b = p0 - p1 * 2.0 + p2;
+                let d = p0 - *point;
+
+                solve_cubic(
+                    dot_product(&b, &b),
+                    3.0 * dot_product(&a, &b),
+                    2.0 * dot_product(&a, &a) + dot_product(&d, &b),
+                    dot_product(&d, &a),
+                )
+            }
+            BezierCurve::Cubic(..) => self.closest_parameters_numerically(point),
+        };
+
+        candidates
+            .into_iter()
+            .filter(|t| (0.0..=1.0).contains(t))
+            .chain([0.0, 1.0])
+            .map(|t| (t, (self.point_at(t) - *point).length_squared()))
+            .fold((0.0, f64::MAX), |best, candidate| {
+                if candidate.1 < best.1 {
+                    candidate
+                } else {
+                    best
+                }
+            })
+            .0
+    }
+
+    pub fn distance(&self, point: &Vector2D<f64>) -> f64 {
+        (self.point_at(self.closest_parameter(point)) - *point).length()
+    }
+
+    /// The stationary points of the distance on a cubic satisfy a quintic; they are bracketed
+    /// by sampling the curve densely and then refined by bisection.
+    fn closest_parameters_numerically(&self, point: &Vector2D<f64>) -> Vec<f64> {
+        const SAMPLES: usize = 64;
+
+        let slope = |t: f64| dot_product(&(self.point_at(t) - *point), &self.derivative_at(t));
+
+        let mut candidates = Vec::new();
+        let mut previous = slope(0.0);
+        for i in 1..=SAMPLES {
+            let mut high = i as f64 / SAMPLES as f64;
+            let current = slope(high);
+
+            // a minimum is where the squared distance stops decreasing
+            if previous <= 0.0 && current >= 0.0 {
+                let mut low = (i - 1) as f64 / SAMPLES as f64;
+                for _ in 0..60 {
+                    let middle = (low + high) * 0.5;
+                    if slope(middle) <= 0.0 {
+                        low = middle;
+                    } else {
+                        high = middle;
+                    }
+                }
+                candidates.push((low + high) * 0.5);
+            }
+
+            previous = current;
+        }
+
+        candidates
+    }
+
+    /// Contribution of this curve to the signed enclosed area (Green's theorem).
+    fn signed_area(&self) -> f64 {
+        0.5 * integrate(|t| cross_product(&self.point_at(t), &self.derivative_at(t)))
+    }
+
+    /// Signed number of times the curve crosses the horizontal ray from `point` towards +x.
+    fn crossings(&self, point: &Vector2D<f64>) -> i32 {
+        let roots = match *self {
+            BezierCurve::Quadratic(p0, p1, p2) => solve_quadratic(
+                p0.y - 2.0 * p1.y + p2.y,
+                2.0 * (p1.y - p0.y),
+                p0.y - point.y,
+            ),
+            BezierCurve::Cubic(p0, p1, p2, p3) => solve_cubic(
+                -p0.y + 3.0 * p1.y - 3.0 * p2.y + p3.y,
+                3.0 * p0.y - 6.0 * p1.y + 3.0 * p2.y,
+                -3.0 * p0.y + 3.0 * p1.y,
+                p0.y - point.y,
+            ),
+        };
+
+        // a crossing at an end point is shared with the neighbouring curve, so the end points
+        // are left to the rule below and only roots strictly between them count here
+        let (start, end) = (self.point_at(0.0), self.point_at(1.0));
+        let interior: i32 = roots
+            .into_iter()
+            .filter(|t| *t > 0.0 && *t < 1.0 && self.point_at(*t).x > point.x)
+            .filter(|t| !(start.y == point.y && *t < 1e-9 || end.y == point.y && *t > 1.0 - 1e-9))
+            .map(|t| {
+                let dy = self.derivative_at(t).y;
+                if dy > 0.0 {
+                    1
+                } else if dy < 0.0 {
+                    -1
+                } else {
+                    0
+                }
+            })
+            .sum();
+
+        // an end point at the ray's height counts as below it: the curve crosses the ray when
+        // it leaves such a point upwards, or arrives at one from above, and a neighbour that
+        // turns back at the same point leaves the winding as it was
+        let leaves_upwards = start.y == point.y && start.x > point.x && self.heading(0.0) > 0.0;
+        let arrives_from_above = end.y == point.y && end.x > point.x && self.heading(1.0) < 0.0;
+
+        interior + i32::from(leaves_upwards) - i32::from(arrives_from_above)
+    }
+
+    /// Whether the curve is going up or down in y at `t`, looking just along it where it runs
+    /// flat there.
+    fn heading(&self, t: f64) -> f64 {
+        let dy = self.derivative_at(t).y;
+        if dy != 0.0 {
+            return dy;
+        }
+
+        let step = if t < 0.5 { 1e-3 } else { -1e-3 };
+
+        (self.point_at(t + step).y - self.point_at(t).y) * step.signum()
+    }
+}
+
+/// A single quadratic Bézier curve stroked with the given thickness and round caps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuadraticBezier {
+    start: Vector2D<f64>,
+    control: Vector2D<f64>,
+    end: Vector2D<f64>,
+    thickness: f64, // full stroke width
+}
+
+impl QuadraticBezier {
+    pub fn new(
+        start: Vector2D<f64>,
+        control: Vector2D<f64>,
+        end: Vector2D<f64>,
+        thickness: f64,
+    ) -> Self {
+        QuadraticBezier {
+            start,
+            control,
+            end,
+            thickness,
+        }
+    }
+
+    pub fn start(&self) -> Vector2D<f64> {
+        self.start
+    }
+
+    pub fn control(&self) -> Vector2D<f64> {
+        self.control
+    }
+
+    pub fn end(&self) -> Vector2D<f64> {
+        self.end
+    }
+
+    pub fn thickness(&self) -> f64 {
+        self.thickness
+    }
+
+    pub fn curve(&self) -> BezierCurve {
+        BezierCurve::Quadratic(self.start, self.control, self.end)
+    }
+
+    pub fn length(&self) -> f64 {
+        self.curve().length()
+    }
+}
+
+impl Shape for QuadraticBezier {
+    /// Exact as long as the stroke does not overlap itself, i.e. half the thickness stays below
+    /// the smallest radius of curvature.
+    fn area(&self) -> f64 {
+        stroke_area(self.length(), self.thickness)
+    }
+
+    fn perimeter(&self) -> f64 {
+        stroke_perimeter(self.length(), self.thickness)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        self.curve().distance(point) - self.thickness * 0.5
+    }
+}
+
+/// A single cubic Bézier curve stroked with the given thickness and round caps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicBezier {
+    start: Vector2D<f64>,
+    control1: Vector2D<f64>,
+    control2: Vector2D<f64>,
+    end: Vector2D<f64>,
+    thickness: f64, // full stroke width
+}
+
+impl CubicBezier {
+    pub fn new(
+        start: Vector2D<f64>,
+        control1: Vector2D<f64>,
+        control2: Vector2D<f64>,
+        end: Vector2D<f64>,
+        thickness: f64,
+    ) -> Self {
+        CubicBezier {
+            start,
+            control1,
+            control2,
+            end,
+            thickness,
+        }
+    }
+
+    pub fn start(&self) -> Vector2D<f64> {
+        self.start
+    }
+
+    pub fn control1(&self) -> Vector2D<f64> {
+        self.control1
+    }
+
+    pub fn control2(&self) -> Vector2D<f64> {
+        self.control2
+    }
+
+    pub fn end(&self) -> Vector2D<f64> {
+        self.end
+    }
+
+    pub fn thickness(&self) -> f64 {
+        self.thickness
+    }
+
+    pub fn curve(&self) -> BezierCurve {
+        BezierCurve::Cubic(self.start, self.control1, self.control2, self.end)
+    }
+
+    pub fn length(&self) -> f64 {
+        self.curve().length()
+    }
+}
+
+impl Shape for CubicBezier {
+    /// Exact as long as the stroke does not overlap itself, i.e. half the thickness stays below
+    /// the smallest radius of curvature.
+    fn area(&self) -> f64 {
+        stroke_area(self.length(), self.thickness)
+    }
+
+    fn perimeter(&self) -> f64 {
+        stroke_perimeter(self.length(), self.thickness)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        self.curve().distance(point) - self.thickness * 0.5
+    }
+}
+
+/// A chain of connected Bézier curves. An open path is stroked like a single curve, a closed
+/// path (the last curve ends where the first starts) is a filled region.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BezierPath {
+    curves: Vec<BezierCurve>,
+    thickness: f64, // full stroke width, zero for filled paths
+    closed: bool,
+}
+
+impl BezierPath {
+    pub fn new_stroke(curves: Vec<BezierCurve>, thickness: f64) -> Self {
+        Self::validate(&curves);
+
+        BezierPath {
+            curves,
+            thickness,
+            closed: false,
+        }
+    }
+
+    pub fn new_filled(curves: Vec<BezierCurve>) -> Self {
+        Self::validate(&curves);
+
+        if curves[curves.len() - 1].end() != curves[0].start() {
+            panic!("Bezier path must end where it starts to be filled.");
+        }
+
+        BezierPath {
+            curves,
+            thickness: 0.0,
+            closed: true,
+        }
+    }
+
+    pub fn curves(&self) -> &[BezierCurve] {
+        &self.curves
+    }
+
+    pub fn thickness(&self) -> f64 {
+        self.thickness
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn length(&self) -> f64 {
+        self.curves.iter().map(|curve| curve.length()).sum()
+    }
+
+    /// Number of times the path winds around `point`; only meaningful for closed paths.
+    pub fn winding_number(&self, point: &Vector2D<f64>) -> i32 {
+        self.curves.iter().map(|curve| curve.crossings(point)).sum()
+    }
+
+    fn validate(curves: &[BezierCurve]) {
+        if curves.is_empty() {
+            panic!("Bezier path must have at least one curve.");
+        }
+
+        if curves.windows(2).any(|w| w[0].end() != w[1].start()) {
+            panic!("Bezier path curves must be connected end to start.");
+        }
+    }
+}
+
+impl Shape for BezierPath {
+    /// Exact for filled paths. For strokes it is exact as long as the stroke does not overlap
+    /// itself, joins included.
+    fn area(&self) -> f64 {
+        if self.closed {
+            self.curves
+                .iter()
+                .map(|curve| curve.signed_area())
+                .sum::<f64>()
+                .abs()
+        } else {
+            stroke_area(self.length(), self.thickness)
+        }
+    }
+
+    fn perimeter(&self) -> f64 {
+        if self.closed {
+            self.length()
+        } else {
+            stroke_perimeter(self.length(), self.thickness)
+        }
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let distance = self
+            .curves
+            .iter()
+            .map(|curve| curve.distance(point))
+            .fold(f64::MAX, f64::min);
+
+        if !self.closed {
+            distance - self.thickness * 0.5
+        } else if self.winding_number(point) != 0 {
+            -distance
+        } else {
+            distance
+        }
+    }
+}
+
+fn stroke_area(length: f64, thickness: f64) -> f64 {
+    let r = thickness * 0.5;
+
+    thickness * length + PI * r * r
+}
+
+fn stroke_perimeter(length: f64, thickness: f64) -> f64 {
+    // the curvature terms of the two sides cancel out
+    2.0 * length + PI * thickness
+}
+
+/// Integrates `f` over [0, 1] with composite 5-point Gauss-Legendre quadrature.
+fn integrate<F: Fn(f64) -> f64>(f: F) -> f64 {
+    const INTERVALS: usize = 32;
+
+    let inner = (5.0 - 2.0 * (10.0_f64 / 7.0).sqrt()).sqrt() / 3.0;
+    let outer = (5.0 + 2.0 * (10.0_f64 / 7.0).sqrt()).sqrt() / 3.0;
+    let inner_weight = (322.0 + 13.0 * 70.0_f64.sqrt()) / 900.0;
+    let outer_weight = (322.0 - 13.0 * 70.0_f64.sqrt()) / 900.0;
+    let nodes = [
+        (0.0, 128.0 / 225.0),
+        (-inner, inner_weight),
+        (inner, inner_weight),
+        (-outer, outer_weight),
+        (outer, outer_weight),
+    ];
+
+    let half_width = 0.5 / INTERVALS as f64;
+    (0..INTERVALS)
+        .map(|i| {
+            let middle = (i as f64 + 0.5) / INTERVALS as f64;
+            nodes
+                .iter()
+                .map(|(x, w)| w * f(middle + x * half_width))
+                .sum::<f64>()
+                * half_width
+        })
+        .sum()
+}
+
+/// Real roots of `a t^2 + b t + c`.
+fn solve_quadratic(a: f64, b: f64, c: f64) -> Vec<f64> {
+    if a == 0.0 {
+        return if b == 0.0 { vec![] } else { vec![-c / b] };
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return vec![];
+    }
+
+    // avoid cancellation by computing the larger root first
+    let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+    if q == 0.0 {
+        return vec![0.0];
+    }
+
+    vec![q / a, c / q]
+}
+
+/// Real roots of `a t^3 + b t^2 + c t + d`, polished with a few Newton steps.
+fn solve_cubic(a: f64, b: f64, c: f64, d: f64) -> Vec<f64> {
+    if a.abs() <= 1e-12 * b.abs().max(c.abs()).max(d.abs()) {
+        return solve_quadratic(b, c, d);
+    }
+
+    // depressed cubic x^3 + p x + q with t = x - b / 3a
+    let (b, c, d) = (b / a, c / a, d / a);
+    let shift = b / 3.0;
+    let p = c - b * shift;
+    let q = 2.0 * shift * shift * shift - shift * c + d;
+    let discriminant = q * q * 0.25 + p * p * p / 27.0;
+
+    let roots = if discriminant > 0.0 {
+        let root = discriminant.sqrt();
+        vec![(-0.5 * q + root).cbrt() + (-0.5 * q - root).cbrt() - shift]
+    } else if p == 0.0 {
+        vec![-shift]
+    } else {
+        let radius = 2.0 * (-p / 3.0).sqrt();
+        let angle = ((3.0 * q / (p * radius)).clamp(-1.0, 1.0)).acos() / 3.0;
+
+        (0..3)
+            .map(|k| radius * (angle - 2.0 * PI * k as f64 / 3.0).cos() - shift)
+            .collect()
+    };
+
+    roots
+        .into_iter()
+        .map(|mut t| {
+            for _ in 0..2 {
+                let value = ((t + b) * t + c) * t + d;
+                let slope = (3.0 * t + 2.0 * b) * t + c;
+                if slope != 0.0 {
+                    t -= value / slope;
+                }
+            }
+            t
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bezier::{solve_cubic, BezierCurve, BezierPath, CubicBezier, QuadraticBezier};
+    use crate::segment::Segment;
+    use crate::{get_area, get_sdf, Shape};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    fn brute_force_distance(curve: &BezierCurve, point: &Vector2D<f64>) -> f64 {
+        (0..=100_000)
+            .map(|i| (curve.point_at(i as f64 / 100_000.0) - *point).length())
+            .fold(f64::MAX, f64::min)
+    }
+
+    fn circle_of_cubics(radius: f64) -> Vec<BezierCurve> {
+        let k = radius * 0.5522847498307936;
+        let r = radius;
+
+        vec![
+            BezierCurve::Cubic(
+                Vector2D::new(r, 0.0),
+                Vector2D::new(r, k),
+                Vector2D::new(k, r),
+                Vector2D::new(0.0, r),
+            ),
+            BezierCurve::Cubic(
+                Vector2D::new(0.0, r),
+                Vector2D::new(-k, r),
+                Vector2D::new(-r, k),
+                Vector2D::new(-r, 0.0),
+            ),
+            BezierCurve::Cubic(
+                Vector2D::new(-r, 0.0),
+                Vector2D::new(-r, -k),
+                Vector2D::new(-k, -r),
+                Vector2D::new(0.0, -r),
+            ),
+            BezierCurve::Cubic(
+                Vector2D::new(0.0, -r),
+                Vector2D::new(k, -r),
+                Vector2D::new(r, -k),
+                Vector2D::new(r, 0.0),
+            ),
+        ]
+    }
+
+    #[test]
+    fn cubic_roots() {
+        // (t - 1)(t - 2)(t - 3)
+        let mut roots = solve_cubic(1.0, -6.0, 11.0, -6.0);
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert_eq!(roots.len(), 3);
+        assert!((roots[0] - 1.0).abs() < 1e-12);
+        assert!((roots[1] - 2.0).abs() < 1e-12);
+        assert!((roots[2] - 3.0).abs() < 1e-12);
+
+        // t^3 + t + 2 has the single real root -1
+        let roots = solve_cubic(1.0, 0.0, 1.0, 2.0);
+        assert_eq!(roots.len(), 1);
+        assert!((roots[0] + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn create_quadratic_bezier() {
+        let bezier = QuadraticBezier::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(5.0, 0.0),
+            Vector2D::new(10.0, 0.0),
+            2.0,
+        );
+
+        assert_eq!(
+            format!("The bezier is: {bezier:?}"),
+            "The bezier is: QuadraticBezier { start: Vector2D { x: 0.0, y: 0.0 }, control: Vector2D { x: 5.0, y: 0.0 }, end: Vector2D { x: 10.0, y: 0.0 }, thickness: 2.0 }"
+        );
+
+        // a straight curve is a capsule
+        assert!((bezier.length() - 10.0).abs() < 1e-12);
+        assert!((get_area(&bezier) - (20.0 + PI)).abs() < 1e-12);
+        assert!((bezier.perimeter() - (20.0 + 2.0 * PI)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn quadratic_bezier_sdf() {
+        let straight = QuadraticBezier::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(5.0, 0.0),
+            Vector2D::new(10.0, 0.0),
+            0.0,
+        );
+        let segment = Segment::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 0.0));
+        for point in [
+            Vector2D::new(3.0, 4.0),
+            Vector2D::new(-3.0, 4.0),
+            Vector2D::new(12.0, -1.0),
+        ] {
+            assert!((straight.sdf(&point) - segment.sdf(&point)).abs() < 1e-12);
+        }
+
+        let bezier = QuadraticBezier::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(5.0, 10.0),
+            Vector2D::new(10.0, 0.0),
+            2.0,
+        );
+
+        // the apex of the curve is at (5, 5)
+        assert!((get_sdf(&bezier, &Vector2D::new(5.0, 5.0)) + 1.0).abs() < 1e-12);
+        assert!((bezier.sdf(&Vector2D::new(5.0, 8.0)) - 2.0).abs() < 1e-12);
+
+        for point in [
+            Vector2D::new(5.0, 0.0),
+            Vector2D::new(1.0, 7.0),
+            Vector2D::new(-2.0, -3.0),
+            Vector2D::new(9.0, 2.0),
+        ] {
+            let expected = brute_force_distance(&bezier.curve(), &point) - 1.0;
+            assert!((bezier.sdf(&point) - expected).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn create_cubic_bezier() {
+        let bezier = CubicBezier::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(10.0 / 3.0, 0.0),
+            Vector2D::new(20.0 / 3.0, 0.0),
+            Vector2D::new(10.0, 0.0),
+            2.0,
+        );
+
+        assert!((bezier.length() - 10.0).abs() < 1e-12);
+        assert!((get_area(&bezier) - (20.0 + PI)).abs() < 1e-12);
+        assert!((bezier.perimeter() - (20.0 + 2.0 * PI)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cubic_bezier_sdf() {
+        // an s-curve with several local distance minima
+        let bezier = CubicBezier::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(10.0, 10.0),
+            Vector2D::new(0.0, 10.0),
+            Vector2D::new(10.0, 0.0),
+            0.0,
+        );
+
+        for point in [
+            Vector2D::new(5.0, 5.0),
+            Vector2D::new(5.0, 9.0),
+            Vector2D::new(5.0, 0.0),
+            Vector2D::new(-3.0, 2.0),
+            Vector2D::new(12.0, 12.0),
+        ] {
+            let expected = brute_force_distance(&bezier.curve(), &point);
+            assert!((get_sdf(&bezier, &point) - expected).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn filled_bezier_path() {
+        let path = BezierPath::new_filled(circle_of_cubics(10.0));
+
+        assert!(path.is_closed());
+        assert_eq!(path.curves().len(), 4);
+
+        // the cubic approximation of a circle is off by a tiny fraction
+        assert!((get_area(&path) - PI * 100.0).abs() < 0.1);
+        assert!((path.perimeter() - 2.0 * PI * 10.0).abs() < 0.01);
+
+        assert!((path.sdf(&Vector2D::new(0.0, 0.0)) + 10.0).abs() < 0.01);
+        assert!((path.sdf(&Vector2D::new(5.0, 0.0)) + 5.0).abs() < 1e-9);
+        assert!((path.sdf(&Vector2D::new(0.0, 15.0)) - 5.0).abs() < 1e-9);
+        assert!((path.sdf(&Vector2D::new(-15.0, 0.0)) - 5.0).abs() < 1e-9);
+        assert_eq!(path.winding_number(&Vector2D::new(0.0, 0.0)), 1);
+        assert_eq!(path.winding_number(&Vector2D::new(20.0, 0.0)), 0);
+    }
+
+    #[test]
+    fn filled_bezier_path_through_a_vertex() {
+        // a diamond of straight quadratics, with rays through its corners at the top and bottom
+        let corners = [
+            Vector2D::new(-10.0, 0.0),
+            Vector2D::new(0.0, -10.0),
+            Vector2D::new(10.0, 0.0),
+            Vector2D::new(0.0, 10.0),
+        ];
+        let curves = (0..4)
+            .map(|i| {
+                let (a, b) = (corners[i], corners[(i + 1) % 4]);
+                BezierCurve::Quadratic(a, (a + b) * 0.5, b)
+            })
+            .collect();
+        let diamond = BezierPath::new_filled(curves);
+
+        for point in [Vector2D::new(-5.0, 10.0), Vector2D::new(-5.0, -10.0)] {
+            assert_eq!(diamond.winding_number(&point), 0);
+            assert!((diamond.sdf(&point) - 12.5_f64.sqrt()).abs() < 1e-9);
+        }
+
+        // and through the corners at the sides, where the outline carries on past the ray
+        assert_eq!(diamond.winding_number(&Vector2D::new(-20.0, 0.0)), 0);
+        assert_eq!(diamond.winding_number(&Vector2D::new(-5.0, 0.0)).abs(), 1);
+
+        // the circle has flat tangents at its top and bottom
+        let circle = BezierPath::new_filled(circle_of_cubics(10.0));
+        for point in [Vector2D::new(-5.0, 10.0), Vector2D::new(-5.0, -10.0)] {
+            assert_eq!(circle.winding_number(&point), 0);
+            assert!(circle.sdf(&point) > 0.0);
+        }
+    }
+
+    #[test]
+    fn stroked_bezier_path() {
+        let curves = circle_of_cubics(10.0);
+        let path = BezierPath::new_stroke(curves[..2].to_vec(), 2.0);
+
+        assert!(!path.is_closed());
+        assert!((path.sdf(&Vector2D::new(0.0, 0.0)) - 9.0).abs() < 0.01);
+        assert!((path.sdf(&Vector2D::new(0.0, -10.0)) - (200.0_f64.sqrt() - 1.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "connected")]
+    fn bezier_path_must_be_connected() {
+        let curves = circle_of_cubics(10.0);
+        BezierPath::new_stroke(vec![curves[0], curves[2]], 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "end where it starts")]
+    fn filled_bezier_path_must_be_closed() {
+        let curves = circle_of_cubics(10.0);
+        BezierPath::new_filled(curves[..3].to_vec());
+    }
+}
@@ -0,0 +1,373 @@
+use crate::numeric::{estimate_area, estimate_perimeter};
+use crate::Shape;
+use vector2d::Vector2D;
+
+/// How two distances are blended where they are within the blend radius of each other.
+/// Distances further apart than that are left as the hard result, except by `Exponential`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BlendKernel {
+    /// Quadratic fillet; cheap, and pulled in by a quarter of the blend radius where both
+    /// distances agree.
+    #[default]
+    Polynomial,
+    /// Log-sum-exp; the smoothest, pulled in as far as the polynomial kernel where both
+    /// distances agree, but it never quite stops blending: at the blend radius it is still
+    /// about 2% of the radius in.
+    Exponential,
+    /// A circular fillet, pulled in by 1 - 1/sqrt(2) of the blend radius where both distances
+    /// agree.
+    Circular,
+}
+
+impl BlendKernel {
+    /// A smooth minimum of `a` and `b`, blending them where they are less than `radius` apart.
+    pub fn blend(&self, a: f64, b: f64, radius: f64) -> f64 {
+        if radius <= 0.0 {
+            return a.min(b);
+        }
+
+        let difference = (a - b).abs();
+        match self {
+            BlendKernel::Polynomial => {
+                let h = (radius - difference).max(0.0) / radius;
+
+                a.min(b) - h * h * radius * 0.25
+            }
+            BlendKernel::Exponential => {
+                // -k log2(2^(-a/k) + 2^(-b/k)), rearranged to stay finite for large distances;
+                // k pulls in by k where the distances agree, so a quarter of the radius
+                let k = radius * 0.25;
+
+                a.min(b) - k * (-difference / k).exp2().ln_1p() / 2.0_f64.ln()
+            }
+            BlendKernel::Circular => {
+                let h = (radius - difference).max(0.0) / radius;
+
+                a.min(b) - radius * 0.5 * (1.0 + h - (1.0 - h * (h - 2.0)).sqrt())
+            }
+        }
+    }
+}
+
+/// A union whose shapes melt into each other where they come within `blend_radius`.
+pub struct SmoothUnion {
+    shapes: Vec<Box<dyn Shape>>,
+    blend_radius: f64,
+    kernel: BlendKernel,
+}
+
+impl SmoothUnion {
+    pub fn new(
+        a: Box<dyn Shape>,
+        b: Box<dyn Shape>,
+        blend_radius: f64,
+        kernel: BlendKernel,
+    ) -> Self {
+        SmoothUnion::from_shapes(vec![a, b], blend_radius, kernel)
+    }
+
+    pub fn from_shapes(
+        shapes: Vec<Box<dyn Shape>>,
+        blend_radius: f64,
+        kernel: BlendKernel,
+    ) -> Self {
+        if shapes.is_empty() {
+            panic!("Smooth union must have at least one shape.");
+        }
+
+        if blend_radius < 0.0 {
+            panic!("Blend radius must not be negative.");
+        }
+
+        SmoothUnion {
+            shapes,
+            blend_radius,
+            kernel,
+        }
+    }
+
+    pub fn shapes(&self) -> &[Box<dyn Shape>] {
+        &self.shapes
+    }
+
+    pub fn blend_radius(&self) -> f64 {
+        self.blend_radius
+    }
+
+    pub fn kernel(&self) -> BlendKernel {
+        self.kernel
+    }
+}
+
+impl Shape for SmoothUnion {
+    fn area(&self) -> f64 {
+        estimate_area(self)
+    }
+
+    fn perimeter(&self) -> f64 {
+        estimate_perimeter(self)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let mut distances = self.shapes.iter().map(|shape| shape.sdf(point));
+        let first = distances.next().unwrap_or(f64::MAX);
+
+        distances.fold(first, |a, b| self.kernel.blend(a, b, self.blend_radius))
+    }
+}
+
+/// An intersection with its creases rounded off by `blend_radius`.
+pub struct SmoothIntersection {
+    shapes: Vec<Box<dyn Shape>>,
+    blend_radius: f64,
+    kernel: BlendKernel,
+}
+
+impl SmoothIntersection {
+    pub fn new(
+        a: Box<dyn Shape>,
+        b: Box<dyn Shape>,
+        blend_radius: f64,
+        kernel: BlendKernel,
+    ) -> Self {
+        SmoothIntersection::from_shapes(vec![a, b], blend_radius, kernel)
+    }
+
+    pub fn from_shapes(
+        shapes: Vec<Box<dyn Shape>>,
+        blend_radius: f64,
+        kernel: BlendKernel,
+    ) -> Self {
+        if shapes.is_empty() {
+            panic!("Smooth intersection must have at least one shape.");
+        }
+
+        if blend_radius < 0.0 {
+            panic!("Blend radius must not be negative.");
+        }
+
+        SmoothIntersection {
+            shapes,
+            blend_radius,
+            kernel,
+        }
+    }
+
+    pub fn shapes(&self) -> &[Box<dyn Shape>] {
+        &self.shapes
+    }
+
+    pub fn blend_radius(&self) -> f64 {
+        self.blend_radius
+    }
+
+    pub fn kernel(&self) -> BlendKernel {
+        self.kernel
+    }
+}
+
+impl Shape for SmoothIntersection {
+    fn area(&self) -> f64 {
+        estimate_area(self)
+    }
+
+    fn perimeter(&self) -> f64 {
+        estimate_perimeter(self)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let mut distances = self.shapes.iter().map(|shape| shape.sdf(point));
+        let first = distances.next().unwrap_or(f64::MIN);
+
+        // smooth maximum
+        distances.fold(first, |a, b| -self.kernel.blend(-a, -b, self.blend_radius))
+    }
+}
+
+/// The first shape with the second cut out of it, the cut's edges rounded off by
+/// `blend_radius`.
+pub struct SmoothSubtraction {
+    shape: Box<dyn Shape>,
+    subtracted: Box<dyn Shape>,
+    blend_radius: f64,
+    kernel: BlendKernel,
+}
+
+impl SmoothSubtraction {
+    pub fn new(
+        shape: Box<dyn Shape>,
+        subtracted: Box<dyn Shape>,
+        blend_radius: f64,
+        kernel: BlendKernel,
+    ) -> Self {
+        if blend_radius < 0.0 {
+            panic!("Blend radius must not be negative.");
+        }
+
+        SmoothSubtraction {
+            shape,
+            subtracted,
+            blend_radius,
+            kernel,
+        }
+    }
+
+    pub fn shape(&self) -> &dyn Shape {
+        self.shape.as_ref()
+    }
+
+    pub fn subtracted(&self) -> &dyn Shape {
+        self.subtracted.as_ref()
+    }
+
+    pub fn blend_radius(&self) -> f64 {
+        self.blend_radius
+    }
+
+    pub fn kernel(&self) -> BlendKernel {
+        self.kernel
+    }
+}
+
+impl Shape for SmoothSubtraction {
+    fn area(&self) -> f64 {
+        estimate_area(self)
+    }
+
+    fn perimeter(&self) -> f64 {
+        estimate_perimeter(self)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let a = self.shape.sdf(point);
+        let b = self.subtracted.sdf(point);
+
+        // smooth maximum of a and -b
+        -self.kernel.blend(-a, b, self.blend_radius)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::blend::{BlendKernel, SmoothIntersection, SmoothSubtraction, SmoothUnion};
+    use crate::csg::Union;
+    use crate::{get_sdf, Shape, ShapeFactory};
+    use std::f64::consts::FRAC_1_SQRT_2;
+    use vector2d::Vector2D;
+
+    const KERNELS: [BlendKernel; 3] = [
+        BlendKernel::Polynomial,
+        BlendKernel::Exponential,
+        BlendKernel::Circular,
+    ];
+
+    fn circles() -> (Box<dyn Shape>, Box<dyn Shape>) {
+        (
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0),
+            ShapeFactory::new_circle(Vector2D::new(25.0, 0.0), 10.0),
+        )
+    }
+
+    #[test]
+    fn blend_kernels() {
+        assert_eq!(BlendKernel::default(), BlendKernel::Polynomial);
+
+        // at equal distances each kernel pulls the result in by its own share of the radius
+        assert_eq!(BlendKernel::Polynomial.blend(1.0, 1.0, 4.0), 0.0);
+        assert_eq!(BlendKernel::Exponential.blend(1.0, 1.0, 4.0), 0.0);
+        assert!(
+            (BlendKernel::Circular.blend(1.0, 1.0, 4.0) - (4.0 * FRAC_1_SQRT_2 - 3.0)).abs()
+                < 1e-12
+        );
+
+        // a radius or more apart the polynomial and circular kernels are exactly the hard
+        // minimum, and the exponential one nearly
+        for kernel in [BlendKernel::Polynomial, BlendKernel::Circular] {
+            assert_eq!(kernel.blend(1.0, 5.0, 4.0), 1.0);
+            assert!(kernel.blend(1.0, 4.9, 4.0) < 1.0);
+        }
+        let exponential = BlendKernel::Exponential.blend(1.0, 5.0, 4.0);
+        assert!(exponential < 1.0 && exponential > 1.0 - 0.025 * 4.0);
+        assert!((BlendKernel::Exponential.blend(1.0, 1000.0, 4.0) - 1.0).abs() < 1e-12);
+        assert!(BlendKernel::Exponential.blend(-1e4, 1e4, 0.1).is_finite());
+
+        for kernel in KERNELS {
+            assert_eq!(kernel.blend(1.0, 2.0, 0.0), 1.0);
+            assert!(kernel.blend(3.0, 2.0, 1.5) <= 2.0);
+        }
+    }
+
+    #[test]
+    fn smooth_union_sdf() {
+        for kernel in KERNELS {
+            let (a, b) = circles();
+            let smooth = SmoothUnion::new(a, b, 4.0, kernel);
+            let (a, b) = circles();
+            let hard = Union::new(a, b);
+
+            assert_eq!(smooth.shapes().len(), 2);
+            assert_eq!(smooth.blend_radius(), 4.0);
+            assert_eq!(smooth.kernel(), kernel);
+
+            // the gap between the circles is 5 wide: the blend narrows it, and a blend wider
+            // than the gap closes it
+            let gap = Vector2D::new(12.5, 0.0);
+            assert_eq!(hard.sdf(&gap), 2.5);
+            assert!(get_sdf(&smooth, &gap) < hard.sdf(&gap));
+            let (a, b) = circles();
+            let bridged = SmoothUnion::new(a, b, 12.0, kernel);
+            assert!(bridged.sdf(&gap) < 0.0);
+            assert!(bridged.area() > hard.area() + 1.0);
+
+            // away from the gap the blend fades out (the exponential kernel never entirely)
+            let far = Vector2D::new(-30.0, 0.0);
+            assert!((smooth.sdf(&far) - hard.sdf(&far)).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn smooth_union_bridges_the_gap() {
+        let (a, b) = circles();
+        let smooth = SmoothUnion::new(a, b, 12.0, BlendKernel::Polynomial);
+
+        // 2.5 - 12 (1 - 0)^2 / 4
+        assert_eq!(smooth.sdf(&Vector2D::new(12.5, 0.0)), -0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "must not be negative")]
+    fn smooth_union_needs_a_positive_radius() {
+        let (a, b) = circles();
+        SmoothUnion::new(a, b, -1.0, BlendKernel::Circular);
+    }
+
+    #[test]
+    fn smooth_intersection_sdf() {
+        let a = ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0);
+        let b = ShapeFactory::new_circle(Vector2D::new(10.0, 0.0), 10.0);
+        let smooth = SmoothIntersection::new(a, b, 4.0, BlendKernel::Circular);
+
+        assert_eq!(smooth.shapes().len(), 2);
+        // the lens' pointed tips at (5, +-8.66) are rounded away
+        let tip = Vector2D::new(5.0, 75.0_f64.sqrt());
+        assert!(get_sdf(&smooth, &tip) > 0.5);
+        // -(5 - 4 (1 - 1/sqrt(2)))
+        assert!((smooth.sdf(&Vector2D::new(5.0, 0.0)) + 1.0 + 4.0 * FRAC_1_SQRT_2).abs() < 1e-12);
+        assert!(smooth.area() < 122.83696986087567);
+    }
+
+    #[test]
+    fn smooth_subtraction_sdf() {
+        let a = ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0);
+        let b = ShapeFactory::new_circle(Vector2D::new(10.0, 0.0), 5.0);
+        let smooth = SmoothSubtraction::new(a, b, 2.0, BlendKernel::Polynomial);
+
+        assert_eq!(smooth.blend_radius(), 2.0);
+        assert!(smooth.shape().sdf(&Vector2D::new(0.0, 0.0)) < 0.0);
+        assert!(smooth.subtracted().sdf(&Vector2D::new(10.0, 0.0)) < 0.0);
+
+        assert_eq!(get_sdf(&smooth, &Vector2D::new(0.0, 0.0)), -5.0);
+        assert_eq!(smooth.sdf(&Vector2D::new(10.0, 0.0)), 5.0);
+        assert!(smooth.area() < 100.0 * std::f64::consts::PI);
+    }
+}
@@ -0,0 +1,303 @@
+use crate::rotate_vector_by_degrees;
+use vector2d::Vector2D;
+
+/// An axis aligned box, given by its minimum and maximum corners.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingBox {
+    min: Vector2D<f64>,
+    max: Vector2D<f64>,
+}
+
+impl BoundingBox {
+    pub fn new(min: Vector2D<f64>, max: Vector2D<f64>) -> Self {
+        if min.x > max.x || min.y > max.y {
+            panic!("Bounding box minimum must not be greater than its maximum.");
+        }
+
+        BoundingBox { min, max }
+    }
+
+    /// The smallest box around all `points`.
+    pub fn from_points(points: impl IntoIterator<Item = Vector2D<f64>>) -> Self {
+        let mut points = points.into_iter();
+        let first = match points.next() {
+            Some(point) => point,
+            None => panic!("Bounding box needs at least one point."),
+        };
+
+        points.fold(BoundingBox::new(first, first), |bounds, point| {
+            BoundingBox {
+                min: Vector2D::new(bounds.min.x.min(point.x), bounds.min.y.min(point.y)),
+                max: Vector2D::new(bounds.max.x.max(point.x), bounds.max.y.max(point.y)),
+            }
+        })
+    }
+
+    /// A box around a circle.
+    pub fn around_circle(center: Vector2D<f64>, radius: f64) -> Self {
+        BoundingBox::new(
+            center - Vector2D::new(radius, radius),
+            center + Vector2D::new(radius, radius),
+        )
+    }
+
+    /// A box covering the whole plane, for shapes that never end.
+    pub fn infinite() -> Self {
+        BoundingBox::new(
+            Vector2D::new(f64::NEG_INFINITY, f64::NEG_INFINITY),
+            Vector2D::new(f64::INFINITY, f64::INFINITY),
+        )
+    }
+
+    pub fn min(&self) -> Vector2D<f64> {
+        self.min
+    }
+
+    pub fn max(&self) -> Vector2D<f64> {
+        self.max
+    }
+
+    pub fn dimensions(&self) -> Vector2D<f64> {
+        self.max - self.min
+    }
+
+    pub fn width(&self) -> f64 {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max.y - self.min.y
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        (self.min + self.max) * 0.5
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.min.x.is_finite()
+            && self.min.y.is_finite()
+            && self.max.x.is_finite()
+            && self.max.y.is_finite()
+    }
+
+    /// Counter-clockwise, starting with the minimum corner.
+    pub fn corners(&self) -> [Vector2D<f64>; 4] {
+        [
+            self.min,
+            Vector2D::new(self.max.x, self.min.y),
+            self.max,
+            Vector2D::new(self.min.x, self.max.y),
+        ]
+    }
+
+    /// Points on the boundary count as inside.
+    pub fn contains(&self, point: &Vector2D<f64>) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+
+    /// Boxes that only touch count as intersecting.
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    /// The smallest box around both boxes.
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        BoundingBox::from_points([self.min, self.max, other.min, other.max])
+    }
+
+    /// The overlap of both boxes, if they intersect.
+    pub fn intersection(&self, other: &BoundingBox) -> Option<BoundingBox> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        Some(BoundingBox::new(
+            Vector2D::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            Vector2D::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        ))
+    }
+
+    /// The box grown by `margin` on every side.
+    pub fn expanded(&self, margin: f64) -> BoundingBox {
+        BoundingBox::new(
+            self.min - Vector2D::new(margin, margin),
+            self.max + Vector2D::new(margin, margin),
+        )
+    }
+}
+
+/// A box turned about its center, using the same rotation convention as the shapes: a
+/// positive angle turns it clockwise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OrientedBoundingBox {
+    center: Vector2D<f64>,
+    half_extents: Vector2D<f64>, // along the box's local x and y axes
+    rotation_angle_in_degrees: f64,
+}
+
+impl OrientedBoundingBox {
+    pub fn new(
+        center: Vector2D<f64>,
+        half_extents: Vector2D<f64>,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        if half_extents.x < 0.0 || half_extents.y < 0.0 {
+            panic!("Oriented bounding box half extents must not be negative.");
+        }
+
+        OrientedBoundingBox {
+            center,
+            half_extents,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn half_extents(&self) -> Vector2D<f64> {
+        self.half_extents
+    }
+
+    pub fn dimensions(&self) -> Vector2D<f64> {
+        self.half_extents * 2.0
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+
+    pub fn area(&self) -> f64 {
+        4.0 * self.half_extents.x * self.half_extents.y
+    }
+
+    /// Counter-clockwise in the box's own frame, starting with its local minimum corner.
+    pub fn corners(&self) -> [Vector2D<f64>; 4] {
+        let (x, y) = (self.half_extents.x, self.half_extents.y);
+
+        [
+            Vector2D::new(-x, -y),
+            Vector2D::new(x, -y),
+            Vector2D::new(x, y),
+            Vector2D::new(-x, y),
+        ]
+        .map(|v| rotate_vector_by_degrees(&v, -self.rotation_angle_in_degrees) + self.center)
+    }
+
+    /// Points on the boundary count as inside.
+    pub fn contains(&self, point: &Vector2D<f64>) -> bool {
+        let local =
+            rotate_vector_by_degrees(&(*point - self.center), self.rotation_angle_in_degrees);
+
+        local.x.abs() <= self.half_extents.x && local.y.abs() <= self.half_extents.y
+    }
+
+    /// The axis aligned box around this one.
+    pub fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(self.corners())
+    }
+}
+
+impl From<BoundingBox> for OrientedBoundingBox {
+    fn from(bounds: BoundingBox) -> Self {
+        OrientedBoundingBox::new(bounds.center(), bounds.dimensions() * 0.5, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bounds::{BoundingBox, OrientedBoundingBox};
+    use vector2d::Vector2D;
+
+    #[test]
+    fn create_bounding_box() {
+        let bounds = BoundingBox::new(Vector2D::new(-10.0, 0.0), Vector2D::new(30.0, 20.0));
+
+        assert_eq!(
+            format!("The bounds are: {bounds:?}"),
+            "The bounds are: BoundingBox { min: Vector2D { x: -10.0, y: 0.0 }, max: Vector2D { x: 30.0, y: 20.0 } }"
+        );
+
+        assert_eq!(bounds.width(), 40.0);
+        assert_eq!(bounds.height(), 20.0);
+        assert_eq!(bounds.center(), Vector2D::new(10.0, 10.0));
+        assert_eq!(bounds.corners()[1], Vector2D::new(30.0, 0.0));
+        assert!(bounds.is_finite());
+        assert!(!BoundingBox::infinite().is_finite());
+
+        assert_eq!(
+            BoundingBox::from_points([
+                Vector2D::new(3.0, -1.0),
+                Vector2D::new(-2.0, 4.0),
+                Vector2D::new(1.0, 1.0),
+            ]),
+            BoundingBox::new(Vector2D::new(-2.0, -1.0), Vector2D::new(3.0, 4.0))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "minimum must not be greater than its maximum")]
+    fn bounding_box_needs_ordered_corners() {
+        BoundingBox::new(Vector2D::new(1.0, 0.0), Vector2D::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn bounding_box_overlap() {
+        let a = BoundingBox::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 10.0));
+        let b = BoundingBox::new(Vector2D::new(5.0, 8.0), Vector2D::new(20.0, 12.0));
+        let c = BoundingBox::new(Vector2D::new(11.0, 0.0), Vector2D::new(12.0, 1.0));
+
+        assert!(a.contains(&Vector2D::new(10.0, 5.0)));
+        assert!(!a.contains(&Vector2D::new(10.5, 5.0)));
+
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&c));
+        assert_eq!(
+            a.intersection(&b),
+            Some(BoundingBox::new(
+                Vector2D::new(5.0, 8.0),
+                Vector2D::new(10.0, 10.0)
+            ))
+        );
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(
+            a.union(&c),
+            BoundingBox::new(Vector2D::new(0.0, 0.0), Vector2D::new(12.0, 10.0))
+        );
+        assert_eq!(
+            c.expanded(1.0),
+            BoundingBox::new(Vector2D::new(10.0, -1.0), Vector2D::new(13.0, 2.0))
+        );
+    }
+
+    #[test]
+    fn oriented_bounding_box() {
+        let obb = OrientedBoundingBox::new(Vector2D::new(10.0, 0.0), Vector2D::new(4.0, 1.0), 90.0);
+
+        assert_eq!(obb.dimensions(), Vector2D::new(8.0, 2.0));
+        assert_eq!(obb.area(), 16.0);
+
+        // turned clockwise a quarter, the long side runs along y
+        let bounds = obb.bounding_box();
+        assert!((bounds.width() - 2.0).abs() < 1e-12);
+        assert!((bounds.height() - 8.0).abs() < 1e-12);
+        assert!(obb.contains(&Vector2D::new(10.5, 3.5)));
+        assert!(!obb.contains(&Vector2D::new(13.0, 0.5)));
+
+        let from_bounds = OrientedBoundingBox::from(BoundingBox::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(4.0, 2.0),
+        ));
+        assert_eq!(
+            from_bounds,
+            OrientedBoundingBox::new(Vector2D::new(2.0, 1.0), Vector2D::new(2.0, 1.0), 0.0)
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{dot_product, Shape};
+use std::f64::consts::PI;
+use vector2d::Vector2D;
+
+/// The convex hull of two circles: one around `start` and one around `end`. With equal radii
+/// this is the familiar stadium shape, otherwise the sides are the two common tangents.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Capsule {
+    start: Vector2D<f64>,
+    end: Vector2D<f64>,
+    start_radius: f64,
+    end_radius: f64,
+}
+
+impl Capsule {
+    pub fn new(
+        start: Vector2D<f64>,
+        end: Vector2D<f64>,
+        start_radius: f64,
+        end_radius: f64,
+    ) -> Self {
+        Capsule {
+            start,
+            end,
+            start_radius,
+            end_radius,
+        }
+    }
+
+    pub fn start(&self) -> Vector2D<f64> {
+        self.start
+    }
+
+    pub fn end(&self) -> Vector2D<f64> {
+        self.end
+    }
+
+    pub fn start_radius(&self) -> f64 {
+        self.start_radius
+    }
+
+    pub fn end_radius(&self) -> f64 {
+        self.end_radius
+    }
+
+    pub fn length(&self) -> f64 {
+        // distance between the two circle centers
+        (self.end - self.start).length()
+    }
+
+    /// When one end circle contains the other the capsule is just the larger circle.
+    fn is_single_circle(&self) -> bool {
+        (self.start_radius - self.end_radius).abs() >= self.length()
+    }
+
+    /// Sine and cosine of the angle the tangent sides make with the axis.
+    fn side_slope(&self) -> (f64, f64) {
+        let sine = (self.start_radius - self.end_radius) / self.length();
+
+        (sine, (1.0 - sine * sine).sqrt())
+    }
+}
+
+impl Shape for Capsule {
+    fn area(&self) -> f64 {
+        let r1 = self.start_radius;
+        let r2 = self.end_radius;
+
+        if self.is_single_circle() {
+            return PI * r1.max(r2).powf(2.0);
+        }
+
+        let (sine, cosine) = self.side_slope();
+        let angle = sine.asin();
+
+        // the quadrilaterals between the centers and the tangent points plus two circle sectors
+        (r1 + r2) * cosine * self.length()
+            + 0.5 * r1 * r1 * (PI + 2.0 * angle)
+            + 0.5 * r2 * r2 * (PI - 2.0 * angle)
+    }
+
+    fn perimeter(&self) -> f64 {
+        let r1 = self.start_radius;
+        let r2 = self.end_radius;
+
+        if self.is_single_circle() {
+            return 2.0 * PI * r1.max(r2);
+        }
+
+        let (sine, cosine) = self.side_slope();
+        let angle = sine.asin();
+
+        2.0 * cosine * self.length() + r1 * (PI + 2.0 * angle) + r2 * (PI - 2.0 * angle)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        if self.is_single_circle() {
+            let start = (*point - self.start).length() - self.start_radius;
+            let end = (*point - self.end).length() - self.end_radius;
+
+            return start.min(end);
+        }
+
+        // local frame: start at the origin, the axis along +y and x folded onto the positive side
+        let length = self.length();
+        let axis = (self.end - self.start) / length;
+        let translated = *point - self.start;
+        let p = Vector2D::new(
+            (axis.x * translated.y - axis.y * translated.x).abs(),
+            dot_product(&translated, &axis),
+        );
+
+        let (sine, cosine) = self.side_slope();
+        let k = dot_product(&p, &Vector2D::new(-sine, cosine));
+
+        if k < 0.0 {
+            p.length() - self.start_radius
+        } else if k > cosine * length {
+            (p - Vector2D::new(0.0, length)).length() - self.end_radius
+        } else {
+            dot_product(&p, &Vector2D::new(cosine, sine)) - self.start_radius
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::capsule::Capsule;
+    use crate::{get_area, get_sdf, Shape};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    #[test]
+    fn create_capsule() {
+        let capsule = Capsule::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 0.0), 2.0, 2.0);
+
+        assert_eq!(
+            format!("The capsule is: {capsule:?}"),
+            "The capsule is: Capsule { start: Vector2D { x: 0.0, y: 0.0 }, end: Vector2D { x: 10.0, y: 0.0 }, start_radius: 2.0, end_radius: 2.0 }"
+        );
+
+        assert_eq!(capsule.length(), 10.0);
+        assert_eq!(get_area(&capsule), 40.0 + PI * 4.0);
+        assert_eq!(capsule.perimeter(), 20.0 + PI * 4.0);
+    }
+
+    #[test]
+    fn capsule_equality() {
+        let capsule1 = Capsule::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 0.0), 2.0, 2.0);
+        let capsule2 = Capsule::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 0.0), 2.0, 2.0);
+        let capsule3 = Capsule::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 0.0), 2.0, 1.0);
+
+        assert!(capsule1 == capsule2);
+        assert!(capsule1 != capsule3);
+    }
+
+    #[test]
+    fn capsule_sdf() {
+        let capsule = Capsule::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 0.0), 2.0, 2.0);
+
+        assert_eq!(get_sdf(&capsule, &Vector2D::new(5.0, 0.0)), -2.0);
+        assert_eq!(capsule.sdf(&Vector2D::new(5.0, 2.0)), 0.0);
+        assert_eq!(capsule.sdf(&Vector2D::new(5.0, -5.0)), 3.0);
+        assert_eq!(capsule.sdf(&Vector2D::new(-3.0, 4.0)), 3.0);
+        assert_eq!(capsule.sdf(&Vector2D::new(13.0, 4.0)), 3.0);
+    }
+
+    #[test]
+    fn uneven_capsule() {
+        // radii 3 and 1 four units apart: the sides are tilted by 30 degrees
+        let capsule = Capsule::new(Vector2D::new(0.0, 0.0), Vector2D::new(0.0, 4.0), 3.0, 1.0);
+
+        assert_eq!(capsule.sdf(&Vector2D::new(0.0, -3.0)), 0.0);
+        assert_eq!(capsule.sdf(&Vector2D::new(0.0, 5.0)), 0.0);
+        assert_eq!(capsule.sdf(&Vector2D::new(0.0, 0.0)), -3.0);
+
+        // a tangent point on the start circle is on the boundary and the normal continues outwards
+        let tangent = Vector2D::new(3.0 * 0.75_f64.sqrt(), 1.5);
+        assert!(capsule.sdf(&tangent).abs() < 1e-12);
+        assert!((capsule.sdf(&(tangent * 2.0)) - 3.0).abs() < 1e-12);
+
+        let expected_area =
+            4.0 * 4.0 * 0.75_f64.sqrt() + 0.5 * 9.0 * (PI + PI / 3.0) + 0.5 * (PI - PI / 3.0);
+        assert!((capsule.area() - expected_area).abs() < 1e-12);
+
+        let expected_perimeter =
+            2.0 * 4.0 * 0.75_f64.sqrt() + 3.0 * (PI + PI / 3.0) + (PI - PI / 3.0);
+        assert!((capsule.perimeter() - expected_perimeter).abs() < 1e-12);
+
+        // direction does not matter
+        let reversed = Capsule::new(Vector2D::new(0.0, 4.0), Vector2D::new(0.0, 0.0), 1.0, 3.0);
+        assert!((reversed.sdf(&tangent)).abs() < 1e-12);
+        assert!((reversed.area() - capsule.area()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn capsule_swallowed_end() {
+        let capsule = Capsule::new(Vector2D::new(0.0, 0.0), Vector2D::new(1.0, 0.0), 5.0, 1.0);
+
+        assert_eq!(capsule.area(), PI * 25.0);
+        assert_eq!(capsule.perimeter(), PI * 10.0);
+        assert_eq!(capsule.sdf(&Vector2D::new(0.0, 0.0)), -5.0);
+    }
+}
@@ -1,3 +1,7 @@
+use crate::bounds::BoundingBox;
+use crate::convex::ConvexShape;
+use crate::mass::SecondMomentOfArea;
+use crate::ray::{circle_crossings, first_crossing, hit_at, unit_direction, RayHit};
 use crate::Shape;
 use std::f64::consts;
 use vector2d::Vector2D;
@@ -28,6 +32,82 @@ impl Circle {
     pub fn diameter(&self) -> f64 {
         self.radius * 2.0
     }
+
+    /// The smallest circle around all `points` (Welzl's algorithm).
+    pub fn enclosing(points: &[Vector2D<f64>]) -> Self {
+        if points.is_empty() {
+            panic!("Enclosing circle needs at least one point.");
+        }
+
+        // visiting the points in a scrambled order keeps the expected running time linear
+        let mut points = points.to_vec();
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for i in (1..points.len()).rev() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            points.swap(i, (state % (i as u64 + 1)) as usize);
+        }
+
+        let mut circle = Circle::new(points[0], 0.0);
+        for i in 1..points.len() {
+            if circle.encloses(&points[i]) {
+                continue;
+            }
+
+            circle = Circle::new(points[i], 0.0);
+            for j in 0..i {
+                if circle.encloses(&points[j]) {
+                    continue;
+                }
+
+                circle = Circle::new(
+                    (points[i] + points[j]) * 0.5,
+                    (points[i] - points[j]).length() * 0.5,
+                );
+                for k in 0..j {
+                    if !circle.encloses(&points[k]) {
+                        circle = circle_through(points[i], points[j], points[k]);
+                    }
+                }
+            }
+        }
+
+        circle
+    }
+
+    /// Whether `point` is inside or on the circle, allowing for rounding.
+    fn encloses(&self, point: &Vector2D<f64>) -> bool {
+        (*point - self.center).length() <= self.radius * (1.0 + 1e-12) + 1e-12
+    }
+}
+
+/// The circle through three points, or around the two furthest apart if they are in line.
+fn circle_through(a: Vector2D<f64>, b: Vector2D<f64>, c: Vector2D<f64>) -> Circle {
+    let ab = b - a;
+    let ac = c - a;
+    let d = 2.0 * (ab.x * ac.y - ab.y * ac.x);
+
+    if d.abs() <= f64::EPSILON * ab.length_squared().max(ac.length_squared()) {
+        let [p, q] = [[a, b], [a, c], [b, c]]
+            .into_iter()
+            .fold([a, a], |furthest, [p, q]| {
+                if (q - p).length() > (furthest[1] - furthest[0]).length() {
+                    [p, q]
+                } else {
+                    furthest
+                }
+            });
+
+        return Circle::new((p + q) * 0.5, (q - p).length() * 0.5);
+    }
+
+    let offset = Vector2D::new(
+        ac.y * ab.length_squared() - ab.y * ac.length_squared(),
+        ab.x * ac.length_squared() - ac.x * ab.length_squared(),
+    ) / d;
+
+    Circle::new(a + offset, offset.length())
 }
 
 impl Shape for Circle {
@@ -45,12 +125,84 @@ impl Shape for Circle {
 
         translated.length() - self.radius
     }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::around_circle(self.center, self.radius)
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        Circle::new(self.center, self.radius)
+    }
+
+    fn largest_inscribed_circle(&self) -> Circle {
+        Circle::new(self.center, self.radius)
+    }
+
+    fn gradient(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        let translated = *point - self.center;
+        let length = translated.length();
+
+        if length > 0.0 {
+            translated / length
+        } else {
+            translated
+        }
+    }
+
+    /// From the center, every point of the outline is as near; this picks the one to its right.
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        let normal = self.normal(point);
+
+        if normal.length_squared() > 0.0 {
+            self.center + normal * self.radius
+        } else {
+            self.center + Vector2D::new(self.radius, 0.0)
+        }
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        let direction = unit_direction(direction);
+        let crossings = circle_crossings(origin, &direction, &self.center, self.radius)?;
+        let t = first_crossing(crossings, max_distance)?;
+
+        Some(hit_at(self, origin, &direction, t))
+    }
+
+    fn centroid(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        let moment = consts::PI * self.radius.powf(4.0) / 4.0;
+
+        SecondMomentOfArea::new(moment, moment, 0.0)
+    }
+}
+
+impl ConvexShape for Circle {
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        let length = direction.length();
+        if length == 0.0 {
+            return self.center;
+        }
+
+        self.center + *direction * (self.radius / length)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::bounds::{BoundingBox, OrientedBoundingBox};
     use crate::circle::Circle;
+    use crate::mass::SecondMomentOfArea;
+    use crate::numeric::estimate_area_integrals;
     use crate::{get_area, get_sdf, Shape};
+    use std::f64::consts;
     use vector2d::Vector2D;
 
     #[test]
@@ -81,8 +233,8 @@ mod tests {
         let circle2 = Circle::new(Vector2D::new(10.0, 10.0), 10.0);
         let circle3 = Circle::new(Vector2D::new(11.0, 11.0), 11.0);
 
-        assert_eq!(circle1 == circle2, true);
-        assert_eq!(circle1 != circle3, true);
+        assert!(circle1 == circle2);
+        assert!(circle1 != circle3);
     }
 
     #[test]
@@ -98,4 +250,151 @@ mod tests {
         assert_eq!(circle.sdf(&Vector2D::new(0.0, 0.0)), 4.142135623730951);
         assert_eq!(circle.sdf(&Vector2D::new(-10.0, -10.0)), 18.284271247461902);
     }
+
+    #[test]
+    fn circle_bounding_box() {
+        let circle = Circle::new(Vector2D::new(10.0, -5.0), 3.0);
+
+        assert_eq!(
+            circle.bounding_box(),
+            BoundingBox::new(Vector2D::new(7.0, -8.0), Vector2D::new(13.0, -2.0))
+        );
+        assert_eq!(
+            circle.oriented_bounding_box(),
+            OrientedBoundingBox::new(Vector2D::new(10.0, -5.0), Vector2D::new(3.0, 3.0), 0.0)
+        );
+    }
+
+    #[test]
+    fn enclosing_circle() {
+        // an acute triangle's circumcircle
+        let circle = Circle::enclosing(&[
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(6.0, 0.0),
+            Vector2D::new(3.0, 4.0),
+        ]);
+        assert!((circle.center() - Vector2D::new(3.0, 0.875)).length() < 1e-12);
+        assert!((circle.radius() - 3.125).abs() < 1e-12);
+
+        // points in line, repeated, or inside do not widen it
+        let circle = Circle::enclosing(&[
+            Vector2D::new(-2.0, 1.0),
+            Vector2D::new(0.0, 1.0),
+            Vector2D::new(8.0, 1.0),
+            Vector2D::new(8.0, 1.0),
+            Vector2D::new(3.0, 2.0),
+        ]);
+        assert_eq!(circle, Circle::new(Vector2D::new(3.0, 1.0), 5.0));
+
+        assert_eq!(
+            Circle::enclosing(&[Vector2D::new(1.0, 2.0)]),
+            Circle::new(Vector2D::new(1.0, 2.0), 0.0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "needs at least one point")]
+    fn enclosing_circle_needs_points() {
+        Circle::enclosing(&[]);
+    }
+
+    #[test]
+    fn circle_bounding_and_inscribed_circles() {
+        let circle = Circle::new(Vector2D::new(10.0, -5.0), 3.0);
+
+        assert_eq!(circle.bounding_circle(), circle);
+        assert_eq!(circle.largest_inscribed_circle(), circle);
+    }
+
+    #[test]
+    fn circle_gradient() {
+        let circle = Circle::new(Vector2D::new(10.0, 10.0), 10.0);
+
+        assert_eq!(
+            circle.gradient(&Vector2D::new(10.0, 30.0)),
+            Vector2D::new(0.0, 1.0)
+        );
+        assert_eq!(
+            circle.gradient(&Vector2D::new(7.0, 6.0)),
+            Vector2D::new(-0.6, -0.8)
+        );
+        assert_eq!(
+            circle.normal(&Vector2D::new(10.0, 10.0)),
+            Vector2D::new(0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn circle_closest_point() {
+        let circle = Circle::new(Vector2D::new(10.0, 10.0), 10.0);
+
+        assert_eq!(
+            circle.closest_point(&Vector2D::new(10.0, 30.0)),
+            Vector2D::new(10.0, 20.0)
+        );
+        assert_eq!(
+            circle.closest_point(&Vector2D::new(7.0, 6.0)),
+            Vector2D::new(4.0, 2.0)
+        );
+        assert_eq!(
+            circle.closest_point(&Vector2D::new(10.0, 10.0)),
+            Vector2D::new(20.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn circle_ray_intersect() {
+        let circle = Circle::new(Vector2D::new(10.0, 10.0), 10.0);
+        let right = Vector2D::new(1.0, 0.0);
+
+        let hit = circle
+            .ray_intersect(&Vector2D::new(-10.0, 10.0), &(right * 3.0), 100.0)
+            .unwrap();
+        assert_eq!(hit.distance(), 10.0);
+        assert_eq!(hit.point(), Vector2D::new(0.0, 10.0));
+        assert_eq!(hit.normal(), Vector2D::new(-1.0, 0.0));
+
+        // from inside it leaves on the far side
+        let hit = circle
+            .ray_intersect(&Vector2D::new(10.0, 10.0), &right, 100.0)
+            .unwrap();
+        assert_eq!(hit.point(), Vector2D::new(20.0, 10.0));
+
+        // touching the top counts, passing over it, pointing away or stopping short do not
+        let hit = circle.ray_intersect(&Vector2D::new(0.0, 20.0), &right, 100.0);
+        assert_eq!(hit.unwrap().point(), Vector2D::new(10.0, 20.0));
+        assert_eq!(
+            circle.ray_intersect(&Vector2D::new(0.0, 21.0), &right, 100.0),
+            None
+        );
+        assert_eq!(
+            circle.ray_intersect(&Vector2D::new(-10.0, 10.0), &-right, 100.0),
+            None
+        );
+        assert_eq!(
+            circle.ray_intersect(&Vector2D::new(-10.0, 10.0), &right, 9.0),
+            None
+        );
+    }
+
+    #[test]
+    fn circle_mass_properties() {
+        let circle = Circle::new(Vector2D::new(10.0, -5.0), 2.0);
+
+        assert_eq!(circle.centroid(), Vector2D::new(10.0, -5.0));
+        assert_eq!(
+            circle.second_moment_of_area(),
+            SecondMomentOfArea::new(4.0 * consts::PI, 4.0 * consts::PI, 0.0)
+        );
+        assert_eq!(circle.polar_moment_of_area(), 8.0 * consts::PI);
+        assert_eq!(circle.moment_of_inertia(0.5), 4.0 * consts::PI);
+
+        // and the numeric estimate agrees
+        let estimate = estimate_area_integrals(&circle);
+        assert!((estimate.centroid() - circle.centroid()).length() < 1e-6);
+        let moments = estimate.second_moment_of_area();
+        assert!((moments.ixx() - 4.0 * consts::PI).abs() / (4.0 * consts::PI) < 1e-3);
+        assert!((moments.iyy() - 4.0 * consts::PI).abs() / (4.0 * consts::PI) < 1e-3);
+        assert!(moments.ixy().abs() < 1e-6);
+    }
 }
@@ -0,0 +1,421 @@
+use crate::bounds::OrientedBoundingBox;
+use crate::circle::Circle;
+use crate::convex::{gjk_contact, gjk_epa, ConvexShape};
+use crate::hexagon::Hexagon;
+use crate::rectangle::Rectangle;
+use crate::{closest_point_on_edges, dot_product, Shape};
+use vector2d::Vector2D;
+
+/// How two shapes lie against each other.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Contact {
+    separation: f64,       // negative when they overlap
+    normal: Vector2D<f64>, // unit, from the first shape towards the second
+}
+
+impl Contact {
+    pub fn new(separation: f64, normal: Vector2D<f64>) -> Self {
+        Contact { separation, normal }
+    }
+
+    /// The gap between the shapes, or minus the penetration depth when they overlap.
+    pub fn separation(&self) -> f64 {
+        self.separation
+    }
+
+    /// Moving the second shape along the normal by the penetration depth separates them.
+    pub fn normal(&self) -> Vector2D<f64> {
+        self.normal
+    }
+
+    /// Shapes that only touch count as overlapping.
+    pub fn overlaps(&self) -> bool {
+        self.separation <= 0.0
+    }
+
+    /// How far the shapes reach into each other, zero when they are apart.
+    pub fn penetration_depth(&self) -> f64 {
+        (-self.separation).max(0.0)
+    }
+}
+
+pub fn circle_circle(a: &Circle, b: &Circle) -> Contact {
+    let between = b.center() - a.center();
+    let distance = between.length();
+    let normal = if distance > 0.0 {
+        between / distance
+    } else {
+        Vector2D::new(1.0, 0.0) // any direction separates circles with the same center
+    };
+
+    Contact::new(distance - a.radius() - b.radius(), normal)
+}
+
+/// The normal points from the circle towards the rectangle.
+pub fn circle_rectangle(circle: &Circle, rectangle: &Rectangle) -> Contact {
+    // the rectangle's distance is exact, so the circle is its radius closer than its center
+    let center = circle.center();
+
+    Contact::new(
+        rectangle.sdf(&center) - circle.radius(),
+        -rectangle.normal(&center),
+    )
+}
+
+/// By separating axes for sharp rectangles and ones with all corners rounded alike, by GJK on
+/// their support otherwise.
+pub fn rectangle_rectangle(a: &Rectangle, b: &Rectangle) -> Contact {
+    match (a.corner_radius(), b.corner_radius()) {
+        (Some(radius_a), Some(radius_b)) => {
+            // rounded alike, a rectangle is a smaller sharp one grown by its corner radius
+            let contact = convex_polygons(&sharp_corners(a, radius_a), &sharp_corners(b, radius_b));
+
+            Contact::new(contact.separation - radius_a - radius_b, contact.normal)
+        }
+        _ => convex_shapes(a, b),
+    }
+}
+
+pub fn hexagon_hexagon(a: &Hexagon, b: &Hexagon) -> Contact {
+    convex_polygons(
+        &a.as_regular_polygon().vertices(),
+        &b.as_regular_polygon().vertices(),
+    )
+}
+
+/// Any two convex shapes, by GJK while they are apart and EPA once they overlap.
+pub fn convex_shapes<A: ConvexShape + ?Sized, B: ConvexShape + ?Sized>(a: &A, b: &B) -> Contact {
+    gjk_contact(a, b)
+}
+
+/// Like `convex_shapes` for shapes that do not know how far they reach, which are treated as
+/// their convex hulls.
+///
+/// The furthest point of a shape in a direction is found as the nearest point on its outline
+/// to a point very far out that way, which is good to about a millionth of the shape's size.
+pub fn any_shapes<A: Shape + ?Sized, B: Shape + ?Sized>(a: &A, b: &B) -> Contact {
+    let (reach_a, reach_b) = (OutlineSupport::new(a), OutlineSupport::new(b));
+    let scale = reach_a.radius + reach_b.radius + (reach_b.center - reach_a.center).length();
+
+    gjk_epa(
+        |direction| reach_a.support(direction) - reach_b.support(&-*direction),
+        scale,
+    )
+}
+
+/// The corners of `rectangle` with its rounding of `radius` taken off every side.
+fn sharp_corners(rectangle: &Rectangle, radius: f64) -> [Vector2D<f64>; 4] {
+    OrientedBoundingBox::new(
+        rectangle.center(),
+        rectangle.dimensions() * 0.5 - Vector2D::new(radius, radius),
+        rectangle.rotation_angle_in_degrees(),
+    )
+    .corners()
+}
+
+/// Separating axes while the polygons overlap, the nearest vertex and edge once they are apart.
+fn convex_polygons(a: &[Vector2D<f64>], b: &[Vector2D<f64>]) -> Contact {
+    let edges = |vertices: &[Vector2D<f64>]| {
+        let vertices = vertices.to_vec();
+
+        (0..vertices.len()).map(move |i| (vertices[i], vertices[(i + 1) % vertices.len()]))
+    };
+    let project = |vertices: &[Vector2D<f64>], axis: &Vector2D<f64>| {
+        vertices
+            .iter()
+            .map(|v| dot_product(v, axis))
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), d| {
+                (min.min(d), max.max(d))
+            })
+    };
+
+    let mut least = Contact::new(f64::NEG_INFINITY, Vector2D::new(1.0, 0.0));
+    for (start, end) in edges(a).chain(edges(b)) {
+        let edge = end - start;
+        let axis = Vector2D::new(edge.y, -edge.x) / edge.length();
+        let (min_a, max_a) = project(a, &axis);
+        let (min_b, max_b) = project(b, &axis);
+
+        // the overlap is the smaller push that clears it, taking b to whichever side is nearer
+        let contact = if max_a - min_b < max_b - min_a {
+            Contact::new(min_b - max_a, axis)
+        } else {
+            Contact::new(min_a - max_b, -axis)
+        };
+
+        if contact.separation > least.separation {
+            least = contact;
+        }
+    }
+
+    if least.overlaps() {
+        return least;
+    }
+
+    // apart, the nearest points are a vertex of one and a point on an edge of the other
+    let nearest_to_b = a.iter().map(|v| (*v, closest_point_on_edges(v, edges(b))));
+    let nearest_to_a = b.iter().map(|v| (closest_point_on_edges(v, edges(a)), *v));
+    let (from, to) = nearest_to_b
+        .chain(nearest_to_a)
+        .min_by(|(a1, b1), (a2, b2)| (*b1 - *a1).length().total_cmp(&(*b2 - *a2).length()))
+        .unwrap();
+    let between = to - from;
+
+    Contact::new(between.length(), between / between.length())
+}
+
+/// Where a shape reaches furthest, taken from its outline.
+struct OutlineSupport<'a, S: Shape + ?Sized> {
+    shape: &'a S,
+    center: Vector2D<f64>,
+    radius: f64,
+}
+
+impl<'a, S: Shape + ?Sized> OutlineSupport<'a, S> {
+    fn new(shape: &'a S) -> Self {
+        let circle = shape.bounding_circle();
+
+        OutlineSupport {
+            shape,
+            center: circle.center(),
+            radius: circle.radius(),
+        }
+    }
+
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        let far = (self.radius + 1.0) * 1e6;
+
+        self.shape
+            .closest_point(&(self.center + direction.normalise() * far))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::circle::Circle;
+    use crate::collision::{
+        any_shapes, circle_circle, circle_rectangle, convex_shapes, hexagon_hexagon,
+        rectangle_rectangle, Contact,
+    };
+    use crate::hexagon::Hexagon;
+    use crate::rectangle::{Rectangle, RoundFactors};
+    use crate::{HexagonOrientation, ShapeFactory};
+    use vector2d::Vector2D;
+
+    fn assert_contact(contact: Contact, separation: f64, normal: Vector2D<f64>, tolerance: f64) {
+        assert!(
+            (contact.separation() - separation).abs() < tolerance,
+            "{contact:?} should be {separation} apart"
+        );
+        assert!(
+            (contact.normal() - normal).length() < tolerance,
+            "{contact:?} should face {normal:?}"
+        );
+    }
+
+    fn square(center: Vector2D<f64>, angle: f64, radius: f64) -> Rectangle {
+        Rectangle::new(
+            center,
+            Vector2D::new(20.0, 20.0),
+            angle,
+            RoundFactors::new(radius, radius, radius, radius),
+        )
+    }
+
+    #[test]
+    fn create_contact() {
+        let contact = Contact::new(-2.0, Vector2D::new(0.0, 1.0));
+
+        assert_eq!(
+            format!("The contact is: {contact:?}"),
+            "The contact is: Contact { separation: -2.0, normal: Vector2D { x: 0.0, y: 1.0 } }"
+        );
+
+        assert!(contact.overlaps());
+        assert_eq!(contact.penetration_depth(), 2.0);
+        assert!(Contact::new(0.0, Vector2D::new(0.0, 1.0)).overlaps());
+        assert!(!Contact::new(0.5, Vector2D::new(0.0, 1.0)).overlaps());
+        assert_eq!(
+            Contact::new(0.5, Vector2D::new(0.0, 1.0)).penetration_depth(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn circles_collide() {
+        let a = Circle::new(Vector2D::new(0.0, 0.0), 10.0);
+
+        let apart = circle_circle(&a, &Circle::new(Vector2D::new(30.0, 40.0), 5.0));
+        assert_eq!(apart, Contact::new(35.0, Vector2D::new(0.6, 0.8)));
+
+        let overlapping = circle_circle(&a, &Circle::new(Vector2D::new(-12.0, 0.0), 5.0));
+        assert!(overlapping.overlaps());
+        assert_eq!(overlapping.penetration_depth(), 3.0);
+        assert_eq!(overlapping.normal(), Vector2D::new(-1.0, 0.0));
+
+        let same_center = circle_circle(&a, &Circle::new(Vector2D::new(0.0, 0.0), 5.0));
+        assert_eq!(same_center.separation(), -15.0);
+    }
+
+    #[test]
+    fn circle_and_rectangle_collide() {
+        // a wall turned a quarter, a thin bar along y
+        let wall = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(40.0, 2.0),
+            90.0,
+            RoundFactors::new(0.0, 0.0, 0.0, 0.0),
+        );
+
+        let contact = circle_rectangle(&Circle::new(Vector2D::new(-5.0, 3.0), 2.0), &wall);
+        assert_contact(contact, 2.0, Vector2D::new(1.0, 0.0), 1e-12);
+
+        let contact = circle_rectangle(&Circle::new(Vector2D::new(1.5, 0.0), 1.0), &wall);
+        assert!(contact.overlaps());
+        assert_contact(contact, -0.5, Vector2D::new(-1.0, 0.0), 1e-12);
+
+        // past the end, the corner is nearest
+        let contact = circle_rectangle(&Circle::new(Vector2D::new(4.0, 24.0), 1.0), &wall);
+        assert_contact(contact, 4.0, Vector2D::new(-0.6, -0.8), 1e-12);
+    }
+
+    #[test]
+    fn rectangles_collide() {
+        let a = square(Vector2D::new(0.0, 0.0), 0.0, 0.0);
+
+        let contact = rectangle_rectangle(&a, &square(Vector2D::new(25.0, 0.0), 0.0, 0.0));
+        assert_contact(contact, 5.0, Vector2D::new(1.0, 0.0), 1e-12);
+
+        let contact = rectangle_rectangle(&a, &square(Vector2D::new(3.0, 18.0), 0.0, 0.0));
+        assert_contact(contact, -2.0, Vector2D::new(0.0, 1.0), 1e-12);
+
+        // a diamond whose corner points into the side of the square
+        let diamond = square(Vector2D::new(10.0 + 200.0_f64.sqrt(), 0.0), 45.0, 0.0);
+        let contact = rectangle_rectangle(&a, &diamond);
+        assert_contact(contact, 0.0, Vector2D::new(1.0, 0.0), 1e-12);
+        assert!(contact.overlaps());
+
+        // corner to corner, the gap runs along the diagonal
+        let contact = rectangle_rectangle(&a, &square(Vector2D::new(23.0, 24.0), 0.0, 0.0));
+        assert_contact(contact, 5.0, Vector2D::new(0.6, 0.8), 1e-12);
+
+        // rounded alike, the same corners are their radii further apart
+        let contact = rectangle_rectangle(
+            &square(Vector2D::new(0.0, 0.0), 0.0, 2.0),
+            &square(Vector2D::new(23.0, 24.0), 0.0, 1.0),
+        );
+        let gap = (Vector2D::new(3.0_f64, 4.0) + Vector2D::new(3.0, 3.0)).length() - 3.0;
+        assert_contact(contact, gap, Vector2D::new(6.0_f64, 7.0).normalise(), 1e-12);
+    }
+
+    #[test]
+    fn rectangles_with_mixed_rounding_collide() {
+        let rounded = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(20.0, 20.0),
+            0.0,
+            RoundFactors::new(0.0, 5.0, 0.0, 0.0),
+        );
+        let contact = rectangle_rectangle(&rounded, &square(Vector2D::new(25.0, 25.0), 0.0, 0.0));
+
+        // from the rounded corner to the other square's corner
+        let gap = Vector2D::new(10.0_f64, 10.0).length() - 5.0;
+        assert_contact(contact, gap, Vector2D::new(1.0_f64, 1.0).normalise(), 1e-6);
+    }
+
+    #[test]
+    fn hexagons_collide() {
+        let a = Hexagon::new(
+            Vector2D::new(0.0, 0.0),
+            10.0,
+            HexagonOrientation::Horizontal,
+        );
+        let inradius = a.inradius();
+
+        // side to side
+        let b = Hexagon::new(
+            Vector2D::new(2.0 * inradius + 1.0, 0.0),
+            10.0,
+            HexagonOrientation::Horizontal,
+        );
+        assert_contact(hexagon_hexagon(&a, &b), 1.0, Vector2D::new(1.0, 0.0), 1e-12);
+
+        // tip into side
+        let b = Hexagon::new(
+            Vector2D::new(0.0, 10.0 + inradius - 2.0),
+            10.0,
+            HexagonOrientation::Vertical,
+        );
+        assert_contact(
+            hexagon_hexagon(&a, &b),
+            -2.0,
+            Vector2D::new(0.0, 1.0),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn convex_shapes_collide() {
+        let a = Circle::new(Vector2D::new(0.0, 0.0), 10.0);
+        let b = Circle::new(Vector2D::new(30.0, 40.0), 5.0);
+        assert_contact(convex_shapes(&a, &b), 35.0, Vector2D::new(0.6, 0.8), 1e-6);
+
+        let b = Circle::new(Vector2D::new(-12.0, 0.0), 5.0);
+        assert_contact(convex_shapes(&a, &b), -3.0, Vector2D::new(-1.0, 0.0), 1e-6);
+
+        // agrees with the exact answers for the other primitives
+        let wall = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(40.0, 2.0),
+            30.0,
+            RoundFactors::new(0.0, 0.0, 0.0, 0.0),
+        );
+        for i in 0..40 {
+            let angle = (i as f64 * 9.0).to_radians();
+            let circle = Circle::new(Vector2D::new(angle.cos(), angle.sin()) * 12.0, 3.0);
+
+            let exact = circle_rectangle(&circle, &wall);
+            let contact = convex_shapes(&circle, &wall);
+            assert_contact(contact, exact.separation(), exact.normal(), 1e-6);
+        }
+
+        let hexagon = Hexagon::new(Vector2D::new(0.0, 0.0), 10.0, HexagonOrientation::Vertical);
+        let other = Hexagon::new(
+            Vector2D::new(15.0, 5.0),
+            10.0,
+            HexagonOrientation::Horizontal,
+        );
+        let exact = hexagon_hexagon(&hexagon, &other);
+        assert_contact(
+            convex_shapes(&hexagon, &other),
+            exact.separation(),
+            exact.normal(),
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn any_shapes_collide() {
+        let a = ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0);
+        let b = ShapeFactory::new_ellipse(Vector2D::new(0.0, 25.0), Vector2D::new(10.0, 5.0));
+        assert_contact(any_shapes(&a, &b), 10.0, Vector2D::new(0.0, 1.0), 1e-6);
+
+        let b = ShapeFactory::new_ellipse(Vector2D::new(0.0, 12.0), Vector2D::new(10.0, 5.0));
+        assert_contact(any_shapes(&a, &b), -3.0, Vector2D::new(0.0, 1.0), 1e-6);
+
+        // a polygon's corners are only found to within a millionth of its size
+        let hexagon = Hexagon::new(Vector2D::new(0.0, 0.0), 10.0, HexagonOrientation::Vertical);
+        let other = Hexagon::new(
+            Vector2D::new(15.0, 5.0),
+            10.0,
+            HexagonOrientation::Horizontal,
+        );
+        let exact = hexagon_hexagon(&hexagon, &other);
+        assert_contact(
+            any_shapes(&hexagon, &other),
+            exact.separation(),
+            exact.normal(),
+            1e-4,
+        );
+    }
+}
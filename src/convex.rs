@@ -0,0 +1,430 @@
+use crate::bounds::BoundingBox;
+use crate::circle::Circle;
+use crate::collision::Contact;
+use crate::{cross_product, dot_product, Shape};
+use std::f64::consts::TAU;
+use vector2d::Vector2D;
+
+/// A convex shape, known by how far it reaches in every direction (its support mapping). That
+/// is all GJK needs, so any two of them can be measured against each other.
+pub trait ConvexShape: Shape {
+    /// The point of the shape furthest along `direction`, which need not be of unit length.
+    /// Where a whole side faces that way, any point of it will do.
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64>;
+}
+
+impl<S: ConvexShape + ?Sized> ConvexShape for Box<S> {
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        (**self).support(direction)
+    }
+}
+
+/// The gap between two convex shapes, by GJK; zero when they overlap.
+pub fn distance<A: ConvexShape + ?Sized, B: ConvexShape + ?Sized>(a: &A, b: &B) -> f64 {
+    gjk_contact(a, b).separation().max(0.0)
+}
+
+/// Every point of one shape added to every point of the other: sliding `b`'s origin over all
+/// of `a` sweeps out the sum. A sharp rectangle summed with a circle is a rounded rectangle.
+///
+/// The signed distance to the sum comes from GJK (EPA inside it) and is exact.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MinkowskiSum<A: ConvexShape, B: ConvexShape> {
+    a: A,
+    b: B,
+}
+
+impl<A: ConvexShape, B: ConvexShape> MinkowskiSum<A, B> {
+    /// How many directions the outline is sampled in for the area and bounding circle.
+    const OUTLINE_SAMPLES: usize = 4096;
+
+    pub fn new(a: A, b: B) -> Self {
+        MinkowskiSum { a, b }
+    }
+
+    pub fn a(&self) -> &A {
+        &self.a
+    }
+
+    pub fn b(&self) -> &B {
+        &self.b
+    }
+
+    /// How the sum lies against `point`, the normal pointing from the sum to it.
+    fn contact(&self, point: &Vector2D<f64>) -> Contact {
+        let bounds = self.bounding_box();
+        let scale = bounds.dimensions().length() + (*point - bounds.center()).length();
+
+        gjk_epa(|direction| self.support(direction) - *point, scale)
+    }
+
+    /// Points of the outline, counter-clockwise: its furthest points in evenly spread directions.
+    fn outline(&self) -> Vec<Vector2D<f64>> {
+        (0..Self::OUTLINE_SAMPLES)
+            .map(|i| {
+                let angle = i as f64 / Self::OUTLINE_SAMPLES as f64 * TAU;
+
+                self.support(&Vector2D::new(angle.cos(), angle.sin()))
+            })
+            .collect()
+    }
+}
+
+impl<A: ConvexShape, B: ConvexShape> Shape for MinkowskiSum<A, B> {
+    fn area(&self) -> f64 {
+        // exact when both are polygons, whose corners the samples all land on
+        let outline = self.outline();
+
+        (0..outline.len())
+            .map(|i| cross_product(&outline[i], &outline[(i + 1) % outline.len()]))
+            .sum::<f64>()
+            * 0.5
+    }
+
+    fn perimeter(&self) -> f64 {
+        // the outline of the sum is made of the outlines of both
+        self.a.perimeter() + self.b.perimeter()
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        self.contact(point).separation()
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        support_bounds(self)
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        Circle::enclosing(&self.outline())
+    }
+
+    fn gradient(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        // the distance grows fastest straight away from the nearest point of the outline
+        self.contact(point).normal()
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        let contact = self.contact(point);
+
+        *point - contact.normal() * contact.separation()
+    }
+}
+
+impl<A: ConvexShape, B: ConvexShape> ConvexShape for MinkowskiSum<A, B> {
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        self.a.support(direction) + self.b.support(direction)
+    }
+}
+
+/// The vertex furthest along `direction`.
+pub(crate) fn furthest_vertex(
+    vertices: impl IntoIterator<Item = Vector2D<f64>>,
+    direction: &Vector2D<f64>,
+) -> Vector2D<f64> {
+    vertices
+        .into_iter()
+        .max_by(|a, b| dot_product(a, direction).total_cmp(&dot_product(b, direction)))
+        .unwrap()
+}
+
+/// The tightest box around a convex shape, from its furthest points along the axes.
+pub(crate) fn support_bounds<S: ConvexShape + ?Sized>(shape: &S) -> BoundingBox {
+    let (x, y) = (Vector2D::new(1.0, 0.0), Vector2D::new(0.0, 1.0));
+
+    BoundingBox::new(
+        Vector2D::new(shape.support(&-x).x, shape.support(&-y).y),
+        Vector2D::new(shape.support(&x).x, shape.support(&y).y),
+    )
+}
+
+/// How two convex shapes lie against each other, by GJK on their Minkowski difference.
+pub(crate) fn gjk_contact<A: ConvexShape + ?Sized, B: ConvexShape + ?Sized>(
+    a: &A,
+    b: &B,
+) -> Contact {
+    let (bounds_a, bounds_b) = (support_bounds(a), support_bounds(b));
+    let scale = bounds_a.dimensions().length()
+        + bounds_b.dimensions().length()
+        + (bounds_b.center() - bounds_a.center()).length();
+
+    gjk_epa(
+        |direction| a.support(direction) - b.support(&-*direction),
+        scale,
+    )
+}
+
+const MAX_ITERATIONS: usize = 256;
+
+/// GJK on the Minkowski difference of two shapes, given by its `support` (the furthest point
+/// in a direction), for the gap between them; EPA from the last simplex when they overlap.
+pub(crate) fn gjk_epa(support: impl Fn(&Vector2D<f64>) -> Vector2D<f64>, scale: f64) -> Contact {
+    // against a curved outline the normal is only good to about the root of the tolerance
+    let tolerance = 1e-13 * scale;
+
+    let mut direction = Vector2D::new(1.0, 0.0);
+    let mut simplex = vec![support(&direction)];
+    let mut closest = simplex[0];
+
+    for _ in 0..MAX_ITERATIONS {
+        if closest.length() <= tolerance {
+            return match simplex[..] {
+                [a, b, c] => expand(vec![a, b, c], &support, tolerance),
+                [a, b] => enclose(a, b, &support, tolerance),
+                _ => Contact::new(0.0, direction.normalise()),
+            };
+        }
+
+        direction = -closest;
+        let w = support(&direction);
+        if dot_product(&closest, &closest) - dot_product(&closest, &w)
+            <= tolerance * closest.length()
+        {
+            // nothing reaches further towards the origin
+            break;
+        }
+
+        simplex.push(w);
+        (simplex, closest) = nearest_on_simplex(simplex);
+    }
+
+    Contact::new(closest.length(), -closest / closest.length())
+}
+
+/// The part of the simplex nearest the origin, and the nearest point on it.
+fn nearest_on_simplex(simplex: Vec<Vector2D<f64>>) -> (Vec<Vector2D<f64>>, Vector2D<f64>) {
+    match simplex[..] {
+        [a, b] => {
+            let ab = b - a;
+            if ab.length_squared() == 0.0 {
+                return (vec![a], a);
+            }
+
+            let t = (-dot_product(&a, &ab) / ab.length_squared()).clamp(0.0, 1.0);
+
+            if t == 0.0 {
+                (vec![a], a)
+            } else if t == 1.0 {
+                (vec![b], b)
+            } else {
+                (vec![a, b], a + ab * t)
+            }
+        }
+        [a, b, c] => {
+            let sides = [
+                cross_product(&(b - a), &-a),
+                cross_product(&(c - b), &-b),
+                cross_product(&(a - c), &-c),
+            ];
+            if sides.iter().all(|s| *s >= 0.0) || sides.iter().all(|s| *s <= 0.0) {
+                return (simplex, Vector2D::new(0.0, 0.0));
+            }
+
+            [[a, b], [b, c], [c, a]]
+                .map(|edge| nearest_on_simplex(edge.to_vec()))
+                .into_iter()
+                .min_by(|(_, p), (_, q)| p.length().total_cmp(&q.length()))
+                .unwrap()
+        }
+        _ => (simplex.clone(), simplex[0]),
+    }
+}
+
+/// Grows a segment through the origin into a triangle around it, unless the difference is
+/// flat across the segment, in which case the shapes only touch.
+fn enclose(
+    a: Vector2D<f64>,
+    b: Vector2D<f64>,
+    support: &impl Fn(&Vector2D<f64>) -> Vector2D<f64>,
+    tolerance: f64,
+) -> Contact {
+    let across = Vector2D::new(b.y - a.y, a.x - b.x).normalise();
+
+    for normal in [across, -across] {
+        let w = support(&normal);
+        if dot_product(&normal, &w) <= tolerance {
+            return Contact::new(0.0, normal);
+        }
+    }
+
+    expand(vec![a, b, support(&across)], support, tolerance)
+}
+
+/// EPA: pushes out the polygon's edge nearest the origin until it meets the difference's
+/// outline, which is then the penetration depth away along its normal.
+fn expand(
+    mut polygon: Vec<Vector2D<f64>>,
+    support: &impl Fn(&Vector2D<f64>) -> Vector2D<f64>,
+    tolerance: f64,
+) -> Contact {
+    if cross_product(&(polygon[1] - polygon[0]), &(polygon[2] - polygon[0])) < 0.0 {
+        polygon.swap(1, 2); // counter-clockwise, so the edge normals point out
+    }
+
+    let mut nearest = Contact::new(0.0, Vector2D::new(1.0, 0.0));
+    for _ in 0..MAX_ITERATIONS {
+        let (index, distance, normal) = (0..polygon.len())
+            .filter_map(|i| {
+                let (start, end) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+                let edge = end - start;
+                let length = edge.length();
+                let normal = Vector2D::new(edge.y, -edge.x) / length;
+
+                (length > 0.0).then(|| (i, dot_product(&normal, &start), normal))
+            })
+            .min_by(|(_, d1, _), (_, d2, _)| d1.total_cmp(d2))
+            .unwrap();
+        nearest = Contact::new(-distance, normal);
+
+        let w = support(&normal);
+        if dot_product(&normal, &w) - distance <= tolerance {
+            break;
+        }
+
+        polygon.insert(index + 1, w);
+    }
+
+    nearest
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bounds::BoundingBox;
+    use crate::circle::Circle;
+    use crate::convex::{distance, ConvexShape, MinkowskiSum};
+    use crate::hexagon::Hexagon;
+    use crate::rectangle::{Rectangle, RoundFactors};
+    use crate::triangle::Triangle;
+    use crate::{HexagonOrientation, Shape};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    fn sharp(center: Vector2D<f64>, dimensions: Vector2D<f64>, angle: f64) -> Rectangle {
+        Rectangle::new(
+            center,
+            dimensions,
+            angle,
+            RoundFactors::new(0.0, 0.0, 0.0, 0.0),
+        )
+    }
+
+    #[test]
+    fn support() {
+        let circle = Circle::new(Vector2D::new(10.0, 10.0), 5.0);
+        assert_eq!(
+            circle.support(&Vector2D::new(0.0, -3.0)),
+            Vector2D::new(10.0, 5.0)
+        );
+
+        let rectangle = sharp(Vector2D::new(0.0, 0.0), Vector2D::new(20.0, 10.0), 90.0);
+        let furthest = rectangle.support(&Vector2D::new(1.0, 1.0));
+        assert!((furthest - Vector2D::new(5.0, 10.0)).length() < 1e-12);
+
+        // a rounded corner reaches out along the direction
+        let rounded = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(20.0, 10.0),
+            0.0,
+            RoundFactors::new(2.0, 2.0, 2.0, 2.0),
+        );
+        let furthest = rounded.support(&Vector2D::new(1.0, 1.0));
+        let expected = Vector2D::new(8.0, 3.0) + Vector2D::new(1.0, 1.0) * 2.0_f64.sqrt();
+        assert!((furthest - expected).length() < 1e-12);
+
+        let hexagon = Hexagon::new(
+            Vector2D::new(0.0, 0.0),
+            10.0,
+            HexagonOrientation::Horizontal,
+        );
+        let tip = hexagon.support(&Vector2D::new(0.1, 1.0));
+        assert!((tip - Vector2D::new(0.0, 10.0)).length() < 1e-12);
+
+        // boxed shapes keep their support
+        let boxed: Box<dyn ConvexShape> = Box::new(circle);
+        assert_eq!(
+            boxed.support(&Vector2D::new(2.0, 0.0)),
+            Vector2D::new(15.0, 10.0)
+        );
+        assert_eq!(boxed.area(), 25.0 * PI);
+    }
+
+    #[test]
+    fn gjk_distance() {
+        let square = sharp(Vector2D::new(0.0, 0.0), Vector2D::new(20.0, 20.0), 0.0);
+        let circle = Circle::new(Vector2D::new(15.0, 15.0), 2.0);
+        let gap = 50.0_f64.sqrt() - 2.0;
+        assert!((distance(&square, &circle) - gap).abs() < 1e-9);
+
+        let triangle = Triangle::new(
+            Vector2D::new(12.0, 0.0),
+            Vector2D::new(20.0, 5.0),
+            Vector2D::new(20.0, -5.0),
+        );
+        assert!((distance(&square, &triangle) - 2.0).abs() < 1e-12);
+
+        let overlapping = Circle::new(Vector2D::new(10.0, 0.0), 2.0);
+        assert_eq!(distance(&square, &overlapping), 0.0);
+
+        let shapes: Vec<Box<dyn ConvexShape>> = vec![Box::new(square), Box::new(circle)];
+        assert!((distance(&shapes[0], &shapes[1]) - gap).abs() < 1e-9);
+    }
+
+    #[test]
+    fn minkowski_sum_rounds_a_rectangle() {
+        let sum = MinkowskiSum::new(
+            sharp(Vector2D::new(10.0, 10.0), Vector2D::new(30.0, 10.0), 30.0),
+            Circle::new(Vector2D::new(0.0, 0.0), 5.0),
+        );
+        let rounded = Rectangle::new(
+            Vector2D::new(10.0, 10.0),
+            Vector2D::new(40.0, 20.0),
+            30.0,
+            RoundFactors::new(5.0, 5.0, 5.0, 5.0),
+        );
+
+        assert_eq!(sum.a().width(), 30.0);
+        assert_eq!(sum.b().radius(), 5.0);
+
+        for i in 0..400 {
+            let p = Vector2D::new((i % 20) as f64 * 3.1 - 20.0, (i / 20) as f64 * 2.3 - 13.0);
+
+            assert!((sum.sdf(&p) - rounded.sdf(&p)).abs() < 1e-9);
+            let closest = sum.closest_point(&p);
+            assert!(rounded.sdf(&closest).abs() < 1e-9);
+        }
+
+        assert!((sum.area() - rounded.area()).abs() / rounded.area() < 1e-6);
+        assert!((sum.perimeter() - rounded.perimeter()).abs() < 1e-9);
+        assert!((sum.bounding_circle().radius() - rounded.bounding_circle().radius()).abs() < 1e-6);
+
+        let bounds = sum.bounding_box();
+        let expected = rounded.bounding_box();
+        assert!((bounds.min() - expected.min()).length() < 1e-12);
+        assert!((bounds.max() - expected.max()).length() < 1e-12);
+    }
+
+    #[test]
+    fn minkowski_sum_of_polygons() {
+        let sum = MinkowskiSum::new(
+            Triangle::new(
+                Vector2D::new(0.0, 0.0),
+                Vector2D::new(4.0, 0.0),
+                Vector2D::new(0.0, 4.0),
+            ),
+            sharp(Vector2D::new(0.0, 0.0), Vector2D::new(2.0, 2.0), 0.0),
+        );
+
+        // the pentagon (-1, -1), (5, -1), (5, 1), (1, 5), (-1, 5)
+        assert!((sum.area() - 28.0).abs() < 1e-9);
+        assert_eq!(
+            sum.bounding_box(),
+            BoundingBox::new(Vector2D::new(-1.0, -1.0), Vector2D::new(5.0, 5.0))
+        );
+        assert_eq!(
+            sum.support(&Vector2D::new(2.0, 1.0)),
+            Vector2D::new(5.0, 1.0)
+        );
+        assert!((sum.sdf(&Vector2D::new(3.0, 3.0)) - 0.0).abs() < 1e-12);
+        assert!((sum.sdf(&Vector2D::new(7.0, 0.0)) - 2.0).abs() < 1e-12);
+        assert!((sum.sdf(&Vector2D::new(0.0, 0.0)) + 1.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,422 @@
+use crate::bounds::BoundingBox;
+use crate::numeric::{estimate_area, estimate_perimeter};
+use crate::Shape;
+use vector2d::Vector2D;
+
+/// Everything covered by at least one of the shapes.
+pub struct Union {
+    shapes: Vec<Box<dyn Shape>>,
+}
+
+impl Union {
+    pub fn new(a: Box<dyn Shape>, b: Box<dyn Shape>) -> Self {
+        Union::from_shapes(vec![a, b])
+    }
+
+    pub fn from_shapes(shapes: Vec<Box<dyn Shape>>) -> Self {
+        if shapes.is_empty() {
+            panic!("Union must have at least one shape.");
+        }
+
+        Union { shapes }
+    }
+
+    pub fn shapes(&self) -> &[Box<dyn Shape>] {
+        &self.shapes
+    }
+}
+
+impl Shape for Union {
+    fn area(&self) -> f64 {
+        estimate_area(self)
+    }
+
+    fn perimeter(&self) -> f64 {
+        estimate_perimeter(self)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        self.shapes
+            .iter()
+            .map(|shape| shape.sdf(point))
+            .fold(f64::MAX, f64::min)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        union_bounds(&self.shapes)
+    }
+}
+
+/// Only what is covered by all of the shapes.
+pub struct Intersection {
+    shapes: Vec<Box<dyn Shape>>,
+}
+
+impl Intersection {
+    pub fn new(a: Box<dyn Shape>, b: Box<dyn Shape>) -> Self {
+        Intersection::from_shapes(vec![a, b])
+    }
+
+    pub fn from_shapes(shapes: Vec<Box<dyn Shape>>) -> Self {
+        if shapes.is_empty() {
+            panic!("Intersection must have at least one shape.");
+        }
+
+        Intersection { shapes }
+    }
+
+    pub fn shapes(&self) -> &[Box<dyn Shape>] {
+        &self.shapes
+    }
+}
+
+impl Shape for Intersection {
+    fn area(&self) -> f64 {
+        estimate_area(self)
+    }
+
+    fn perimeter(&self) -> f64 {
+        estimate_perimeter(self)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        self.shapes
+            .iter()
+            .map(|shape| shape.sdf(point))
+            .fold(f64::MIN, f64::max)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        let mut boxes = self.shapes.iter().map(|shape| shape.bounding_box());
+        let first = boxes.next().unwrap_or_else(BoundingBox::infinite);
+
+        // shapes that do not overlap have nothing in common, which any box bounds
+        boxes
+            .try_fold(first, |a, b| a.intersection(&b))
+            .unwrap_or(first)
+    }
+}
+
+/// The first shape with every one of the subtracted shapes cut out of it.
+pub struct Subtraction {
+    shape: Box<dyn Shape>,
+    subtracted: Vec<Box<dyn Shape>>,
+}
+
+impl Subtraction {
+    pub fn new(shape: Box<dyn Shape>, subtracted: Box<dyn Shape>) -> Self {
+        Subtraction::from_shapes(shape, vec![subtracted])
+    }
+
+    pub fn from_shapes(shape: Box<dyn Shape>, subtracted: Vec<Box<dyn Shape>>) -> Self {
+        Subtraction { shape, subtracted }
+    }
+
+    pub fn shape(&self) -> &dyn Shape {
+        self.shape.as_ref()
+    }
+
+    pub fn subtracted(&self) -> &[Box<dyn Shape>] {
+        &self.subtracted
+    }
+}
+
+impl Shape for Subtraction {
+    fn area(&self) -> f64 {
+        if self.subtracted.is_empty() {
+            self.shape.area()
+        } else {
+            estimate_area(self)
+        }
+    }
+
+    fn perimeter(&self) -> f64 {
+        if self.subtracted.is_empty() {
+            self.shape.perimeter()
+        } else {
+            estimate_perimeter(self)
+        }
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        self.subtracted
+            .iter()
+            .map(|shape| -shape.sdf(point))
+            .fold(self.shape.sdf(point), f64::max)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        self.shape.bounding_box()
+    }
+}
+
+/// What is covered by an odd number of the shapes; for two shapes, either one but not both.
+pub struct Xor {
+    shapes: Vec<Box<dyn Shape>>,
+}
+
+impl Xor {
+    pub fn new(a: Box<dyn Shape>, b: Box<dyn Shape>) -> Self {
+        Xor::from_shapes(vec![a, b])
+    }
+
+    pub fn from_shapes(shapes: Vec<Box<dyn Shape>>) -> Self {
+        if shapes.is_empty() {
+            panic!("Xor must have at least one shape.");
+        }
+
+        Xor { shapes }
+    }
+
+    pub fn shapes(&self) -> &[Box<dyn Shape>] {
+        &self.shapes
+    }
+}
+
+impl Shape for Xor {
+    fn area(&self) -> f64 {
+        estimate_area(self)
+    }
+
+    fn perimeter(&self) -> f64 {
+        estimate_perimeter(self)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let mut distances = self.shapes.iter().map(|shape| shape.sdf(point));
+        let first = distances.next().unwrap_or(f64::MAX);
+
+        distances.fold(first, |a, b| a.min(b).max(-a.max(b)))
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        union_bounds(&self.shapes)
+    }
+}
+
+fn union_bounds(shapes: &[Box<dyn Shape>]) -> BoundingBox {
+    BoundingBox::from_points(shapes.iter().flat_map(|shape| {
+        let bounds = shape.bounding_box();
+
+        [bounds.min(), bounds.max()]
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bounds::BoundingBox;
+    use crate::csg::{Intersection, Subtraction, Union, Xor};
+    use crate::{get_sdf, Shape, ShapeFactory};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    fn circles() -> (Box<dyn Shape>, Box<dyn Shape>) {
+        // two circles of radius 10 whose centers are a radius apart
+        (
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0),
+            ShapeFactory::new_circle(Vector2D::new(10.0, 0.0), 10.0),
+        )
+    }
+
+    // area shared by the two circles: 2r^2 acos(d / 2r) - (d / 2) sqrt(4r^2 - d^2)
+    fn lens_area() -> f64 {
+        200.0 * 0.5_f64.acos() - 5.0 * 300.0_f64.sqrt()
+    }
+
+    #[test]
+    fn union_sdf() {
+        let (a, b) = circles();
+        let union = Union::new(a, b);
+
+        assert_eq!(union.shapes().len(), 2);
+        assert_eq!(get_sdf(&union, &Vector2D::new(0.0, 0.0)), -10.0);
+        assert_eq!(union.sdf(&Vector2D::new(10.0, 0.0)), -10.0);
+        assert_eq!(union.sdf(&Vector2D::new(25.0, 0.0)), 5.0);
+        assert_eq!(union.sdf(&Vector2D::new(-15.0, 0.0)), 5.0);
+    }
+
+    #[test]
+    fn union_area_and_perimeter() {
+        let (a, b) = circles();
+        let union = Union::new(a, b);
+
+        let expected_area = 200.0 * PI - lens_area();
+        assert!((union.area() - expected_area).abs() / expected_area < 1e-3);
+
+        // each circle keeps 240 degrees of its outline
+        let expected_perimeter = 2.0 * (2.0 / 3.0) * 2.0 * PI * 10.0;
+        assert!((union.perimeter() - expected_perimeter).abs() / expected_perimeter < 1e-3);
+    }
+
+    #[test]
+    fn union_of_many() {
+        let union = Union::from_shapes(vec![
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 1.0),
+            ShapeFactory::new_circle(Vector2D::new(10.0, 0.0), 1.0),
+            ShapeFactory::new_rectangle(Vector2D::new(0.0, 10.0), Vector2D::new(2.0, 2.0)),
+        ]);
+
+        assert_eq!(union.sdf(&Vector2D::new(0.0, 0.0)), -1.0);
+        assert_eq!(union.sdf(&Vector2D::new(10.0, 0.0)), -1.0);
+        assert_eq!(union.sdf(&Vector2D::new(0.0, 10.0)), -1.0);
+        assert_eq!(union.sdf(&Vector2D::new(5.0, 0.0)), 4.0);
+        assert!((union.area() - (2.0 * PI + 4.0)).abs() < 0.05);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one shape")]
+    fn union_needs_a_shape() {
+        Union::from_shapes(vec![]);
+    }
+
+    #[test]
+    fn intersection_sdf() {
+        let (a, b) = circles();
+        let intersection = Intersection::new(a, b);
+
+        assert_eq!(get_sdf(&intersection, &Vector2D::new(5.0, 0.0)), -5.0);
+        assert_eq!(intersection.sdf(&Vector2D::new(0.0, 0.0)), 0.0);
+        assert_eq!(intersection.sdf(&Vector2D::new(-5.0, 0.0)), 5.0);
+
+        assert!((intersection.area() - lens_area()).abs() / lens_area() < 1e-3);
+
+        // each circle contributes 120 degrees of its outline
+        let expected_perimeter = 2.0 * (1.0 / 3.0) * 2.0 * PI * 10.0;
+        assert!((intersection.perimeter() - expected_perimeter).abs() / expected_perimeter < 1e-3);
+    }
+
+    #[test]
+    fn disjoint_intersection_is_empty() {
+        let intersection = Intersection::new(
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 1.0),
+            ShapeFactory::new_circle(Vector2D::new(10.0, 0.0), 1.0),
+        );
+
+        assert!(intersection.sdf(&Vector2D::new(5.0, 0.0)) > 0.0);
+        assert_eq!(intersection.area(), 0.0);
+        assert_eq!(intersection.perimeter(), 0.0);
+    }
+
+    #[test]
+    fn subtraction_sdf() {
+        let (a, b) = circles();
+        let subtraction = Subtraction::new(a, b);
+
+        assert!(subtraction.shape().sdf(&Vector2D::new(5.0, 0.0)) < 0.0);
+        assert_eq!(subtraction.subtracted().len(), 1);
+        assert_eq!(get_sdf(&subtraction, &Vector2D::new(5.0, 0.0)), 5.0);
+        assert_eq!(subtraction.sdf(&Vector2D::new(-5.0, 0.0)), -5.0);
+        assert_eq!(subtraction.sdf(&Vector2D::new(10.0, 0.0)), 10.0);
+
+        let expected_area = 100.0 * PI - lens_area();
+        assert!((subtraction.area() - expected_area).abs() / expected_area < 1e-3);
+
+        let ring = Subtraction::from_shapes(
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0),
+            vec![ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 5.0)],
+        );
+        assert!((ring.area() - 75.0 * PI).abs() / (75.0 * PI) < 1e-3);
+        assert!((ring.perimeter() - 30.0 * PI).abs() / (30.0 * PI) < 1e-3);
+
+        let nothing_removed = Subtraction::from_shapes(
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0),
+            vec![],
+        );
+        assert_eq!(nothing_removed.area(), 100.0 * PI);
+    }
+
+    #[test]
+    fn xor_sdf() {
+        let (a, b) = circles();
+        let xor = Xor::new(a, b);
+
+        assert_eq!(get_sdf(&xor, &Vector2D::new(5.0, 0.0)), 5.0);
+        assert_eq!(xor.sdf(&Vector2D::new(-5.0, 0.0)), -5.0);
+        assert_eq!(xor.sdf(&Vector2D::new(15.0, 0.0)), -5.0);
+        assert_eq!(xor.sdf(&Vector2D::new(25.0, 0.0)), 5.0);
+
+        let expected_area = 200.0 * PI - 2.0 * lens_area();
+        assert!((xor.area() - expected_area).abs() / expected_area < 1e-3);
+    }
+
+    #[test]
+    fn csg_through_the_factory() {
+        let (a, b) = circles();
+        let union = ShapeFactory::new_union(a, b);
+        let grid = crate::get_sdf_grid(&union, 4, 4);
+
+        assert_eq!(grid.get_value(0, 3), -10.0);
+    }
+
+    #[test]
+    fn csg_bounding_boxes() {
+        let (a, b) = circles();
+        assert_eq!(
+            Union::new(a, b).bounding_box(),
+            BoundingBox::new(Vector2D::new(-10.0, -10.0), Vector2D::new(20.0, 10.0))
+        );
+
+        let (a, b) = circles();
+        assert_eq!(
+            Intersection::new(a, b).bounding_box(),
+            BoundingBox::new(Vector2D::new(0.0, -10.0), Vector2D::new(10.0, 10.0))
+        );
+
+        let (a, b) = circles();
+        assert_eq!(
+            Subtraction::new(a, b).bounding_box(),
+            BoundingBox::new(Vector2D::new(-10.0, -10.0), Vector2D::new(10.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn union_ray_intersect() {
+        // sphere traced: the ray passes a hair above the first circle and hits the second
+        let union = Union::new(
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0),
+            ShapeFactory::new_circle(Vector2D::new(40.0, 10.0), 10.0),
+        );
+        let origin = Vector2D::new(-20.0, 10.0001);
+
+        let hit = union
+            .ray_intersect(&origin, &Vector2D::new(1.0, 0.0), 100.0)
+            .unwrap();
+        assert!((hit.point() - Vector2D::new(30.0, 10.0001)).length() < 1e-9);
+        assert!((hit.normal() - Vector2D::new(-1.0, 0.0)).length() < 1e-4);
+
+        let hit = union
+            .ray_intersect(&Vector2D::new(-20.0, 0.0), &Vector2D::new(1.0, 0.0), 100.0)
+            .unwrap();
+        assert!((hit.point() - Vector2D::new(-10.0, 0.0)).length() < 1e-9);
+
+        assert_eq!(
+            union.ray_intersect(&origin, &Vector2D::new(1.0, 0.0), 40.0),
+            None
+        );
+        assert_eq!(
+            union.ray_intersect(&origin, &Vector2D::new(0.0, 1.0), 100.0),
+            None
+        );
+    }
+
+    #[test]
+    fn union_mass_properties() {
+        // an L of a 6 by 2 bar with a 2 by 4 post standing on it, integrated numerically
+        let union = Union::new(
+            ShapeFactory::new_rectangle(Vector2D::new(0.0, 0.0), Vector2D::new(6.0, 2.0)),
+            ShapeFactory::new_rectangle(Vector2D::new(2.0, 3.0), Vector2D::new(2.0, 4.0)),
+        );
+
+        assert!((union.centroid() - Vector2D::new(0.8, 1.2)).length() < 1e-3);
+
+        let moments = union.second_moment_of_area();
+        for (moment, expected) in [
+            (moments.ixx(), 868.0 / 15.0),
+            (moments.iyy(), 868.0 / 15.0),
+            (moments.ixy(), 28.8),
+        ] {
+            assert!((moment - expected).abs() / expected < 1e-3);
+        }
+        assert!((union.moment_of_inertia(3.0) - 3.0 * 2.0 * 868.0 / 15.0).abs() < 0.5);
+    }
+}
@@ -0,0 +1,849 @@
+use crate::{closest_point_on_segment, cross_product, to_local, Shape};
+use std::f64::consts::{PI, SQRT_2};
+use vector2d::Vector2D;
+
+/// A regular star with `points` tips on the outer radius and as many notches on the inner
+/// radius. With no rotation one tip points straight up (+y).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Star {
+    center: Vector2D<f64>,
+    points: usize,
+    outer_radius: f64,
+    inner_radius: f64,
+    rotation_angle_in_degrees: f64,
+}
+
+impl Star {
+    pub fn new(
+        center: Vector2D<f64>,
+        points: usize,
+        outer_radius: f64,
+        inner_radius: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        if points < 3 {
+            panic!("Star must have at least three points.");
+        }
+
+        if inner_radius > outer_radius {
+            panic!("Star inner radius must not be greater than its outer radius.");
+        }
+
+        Star {
+            center,
+            points,
+            outer_radius,
+            inner_radius,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn points(&self) -> usize {
+        self.points
+    }
+
+    pub fn outer_radius(&self) -> f64 {
+        self.outer_radius
+    }
+
+    pub fn inner_radius(&self) -> f64 {
+        self.inner_radius
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+
+    /// The tip on the +y axis and the notch next to it, clockwise.
+    fn tip_and_notch(&self) -> (Vector2D<f64>, Vector2D<f64>) {
+        let half_angle = PI / self.points as f64;
+        let tip = Vector2D::new(0.0, self.outer_radius);
+        let notch = Vector2D::new(half_angle.sin(), half_angle.cos()) * self.inner_radius;
+
+        (tip, notch)
+    }
+}
+
+impl Shape for Star {
+    fn area(&self) -> f64 {
+        let half_angle = PI / self.points as f64;
+
+        self.points as f64 * self.outer_radius * self.inner_radius * half_angle.sin()
+    }
+
+    fn perimeter(&self) -> f64 {
+        let (tip, notch) = self.tip_and_notch();
+
+        2.0 * self.points as f64 * (notch - tip).length()
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let translated = to_local(point, self.center, self.rotation_angle_in_degrees);
+
+        // fold the point into the wedge between a tip and its neighbouring notch
+        let half_angle = PI / self.points as f64;
+        let mut angle = translated
+            .x
+            .atan2(translated.y)
+            .rem_euclid(2.0 * half_angle);
+        if angle > half_angle {
+            angle = 2.0 * half_angle - angle;
+        }
+        let p = Vector2D::new(angle.sin(), angle.cos()) * translated.length();
+
+        let (tip, notch) = self.tip_and_notch();
+        let distance = (p - closest_point_on_segment(&p, &tip, &notch)).length();
+
+        if cross_product(&(notch - tip), &(p - tip)) < 0.0 {
+            -distance
+        } else {
+            distance
+        }
+    }
+}
+
+/// A plus sign made of two perpendicular bars of equal length and width.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cross {
+    center: Vector2D<f64>,
+    arm_length: f64, // from the end of one arm to the end of the opposite arm
+    arm_width: f64,
+    rotation_angle_in_degrees: f64,
+}
+
+impl Cross {
+    pub fn new(
+        center: Vector2D<f64>,
+        arm_length: f64,
+        arm_width: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        if arm_width > arm_length {
+            panic!("Cross arm width must not be greater than its arm length.");
+        }
+
+        Cross {
+            center,
+            arm_length,
+            arm_width,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn arm_length(&self) -> f64 {
+        self.arm_length
+    }
+
+    pub fn arm_width(&self) -> f64 {
+        self.arm_width
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+}
+
+impl Shape for Cross {
+    fn area(&self) -> f64 {
+        2.0 * self.arm_length * self.arm_width - self.arm_width.powf(2.0)
+    }
+
+    fn perimeter(&self) -> f64 {
+        4.0 * self.arm_length
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let translated = to_local(point, self.center, self.rotation_angle_in_degrees);
+
+        // fold into the eighth below the diagonal of the first quadrant
+        let mut p = Vector2D::new(translated.x.abs(), translated.y.abs());
+        if p.y > p.x {
+            p = Vector2D::new(p.y, p.x);
+        }
+
+        let half_length = self.arm_length * 0.5;
+        let half_width = self.arm_width * 0.5;
+        let q = p - Vector2D::new(half_length, half_width);
+        let k = q.x.max(q.y);
+        let w = if k > 0.0 {
+            q
+        } else {
+            Vector2D::new(half_width - p.x, -k)
+        };
+
+        k.signum() * Vector2D::new(w.x.max(0.0), w.y.max(0.0)).length()
+    }
+}
+
+/// A heart made of a square standing on one corner with two half discs on its upper sides.
+/// `size` is the distance from the bottom tip to the notch at the top; `center` is halfway
+/// between the two.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Heart {
+    center: Vector2D<f64>,
+    size: f64,
+    rotation_angle_in_degrees: f64,
+}
+
+impl Heart {
+    pub fn new(center: Vector2D<f64>, size: f64, rotation_angle_in_degrees: f64) -> Self {
+        Heart {
+            center,
+            size,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn size(&self) -> f64 {
+        self.size
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+}
+
+impl Shape for Heart {
+    fn area(&self) -> f64 {
+        // the unit square of side sqrt(2) / 2 plus two half discs of radius sqrt(2) / 4
+        (0.5 + PI / 8.0) * self.size.powf(2.0)
+    }
+
+    fn perimeter(&self) -> f64 {
+        SQRT_2 * (1.0 + PI * 0.5) * self.size
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        // unit heart with its tip at the origin and its notch at (0, 1)
+        let translated = to_local(point, self.center, self.rotation_angle_in_degrees);
+        let p =
+            Vector2D::new(translated.x.abs(), translated.y) / self.size + Vector2D::new(0.0, 0.5);
+
+        let distance = if p.x + p.y > 1.0 {
+            (p - Vector2D::new(0.25, 0.75)).length() - SQRT_2 / 4.0
+        } else {
+            let to_notch = (p - Vector2D::new(0.0, 1.0)).length_squared();
+            let to_side =
+                (p - Vector2D::new(1.0, 1.0) * (0.5 * (p.x + p.y).max(0.0))).length_squared();
+
+            to_notch.min(to_side).sqrt() * (p.x - p.y).signum()
+        };
+
+        distance * self.size
+    }
+}
+
+/// A crescent: a disc with a second disc cut away. The cut disc sits `cut_offset` away from
+/// the center along the local +x axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Moon {
+    center: Vector2D<f64>,
+    radius: f64,
+    cut_radius: f64,
+    cut_offset: f64,
+    rotation_angle_in_degrees: f64,
+}
+
+impl Moon {
+    pub fn new(
+        center: Vector2D<f64>,
+        radius: f64,
+        cut_radius: f64,
+        cut_offset: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        Moon {
+            center,
+            radius,
+            cut_radius,
+            cut_offset,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    pub fn cut_radius(&self) -> f64 {
+        self.cut_radius
+    }
+
+    pub fn cut_offset(&self) -> f64 {
+        self.cut_offset
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+
+    fn circles_cross(&self) -> bool {
+        self.cut_offset < self.radius + self.cut_radius
+            && self.cut_offset > (self.radius - self.cut_radius).abs()
+    }
+
+    /// Upper intersection point of the two circles, when they cross.
+    fn horn(&self) -> Vector2D<f64> {
+        let (ra, rb, d) = (self.radius, self.cut_radius, self.cut_offset);
+        let a = (ra * ra - rb * rb + d * d) / (2.0 * d);
+
+        Vector2D::new(a, (ra * ra - a * a).max(0.0).sqrt())
+    }
+}
+
+impl Shape for Moon {
+    fn area(&self) -> f64 {
+        let (ra, rb, d) = (self.radius, self.cut_radius, self.cut_offset);
+
+        if d >= ra + rb {
+            PI * ra * ra
+        } else if rb >= d + ra {
+            0.0
+        } else if ra >= d + rb {
+            PI * (ra * ra - rb * rb)
+        } else {
+            // minus the lens where the discs overlap
+            let horn = self.horn();
+            let alpha = horn.y.atan2(horn.x);
+            let beta = horn.y.atan2(d - horn.x);
+            let lens = ra * ra * (alpha - 0.5 * (2.0 * alpha).sin())
+                + rb * rb * (beta - 0.5 * (2.0 * beta).sin());
+
+            PI * ra * ra - lens
+        }
+    }
+
+    fn perimeter(&self) -> f64 {
+        let (ra, rb, d) = (self.radius, self.cut_radius, self.cut_offset);
+
+        if d >= ra + rb {
+            2.0 * PI * ra
+        } else if rb >= d + ra {
+            0.0
+        } else if ra >= d + rb {
+            2.0 * PI * (ra + rb)
+        } else {
+            // the outer arc outside the cut disc and the inner arc inside the disc
+            let horn = self.horn();
+            let alpha = horn.y.atan2(horn.x);
+            let beta = horn.y.atan2(d - horn.x);
+
+            ra * (2.0 * PI - 2.0 * alpha) + rb * 2.0 * beta
+        }
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let translated = to_local(point, self.center, self.rotation_angle_in_degrees);
+        let p = Vector2D::new(translated.x, translated.y.abs());
+        let cut_center = Vector2D::new(self.cut_offset, 0.0);
+
+        if self.circles_cross() {
+            // points in the wedge beyond the horns are closest to a horn
+            let d = self.cut_offset;
+            let horn = self.horn();
+            if d * (p.x * horn.y - p.y * horn.x) > d * d * (horn.y - p.y).max(0.0) {
+                return (p - horn).length();
+            }
+        }
+
+        (p.length() - self.radius).max(self.cut_radius - (p - cut_center).length())
+    }
+}
+
+/// The lens where two discs of the same radius overlap. The disc centers sit `distance`
+/// to either side of the center along the local x axis, so the lens tips point up and down.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vesica {
+    center: Vector2D<f64>,
+    radius: f64,
+    distance: f64,
+    rotation_angle_in_degrees: f64,
+}
+
+impl Vesica {
+    pub fn new(
+        center: Vector2D<f64>,
+        radius: f64,
+        distance: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        if distance >= radius {
+            panic!("Vesica disc distance must be smaller than its radius.");
+        }
+
+        Vesica {
+            center,
+            radius,
+            distance,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+
+    pub fn height(&self) -> f64 {
+        2.0 * self.half_height()
+    }
+
+    pub fn width(&self) -> f64 {
+        2.0 * (self.radius - self.distance)
+    }
+
+    fn half_height(&self) -> f64 {
+        (self.radius.powf(2.0) - self.distance.powf(2.0)).sqrt()
+    }
+
+    /// Half of the angle each arc subtends at its own disc center.
+    fn half_arc_angle(&self) -> f64 {
+        (self.distance / self.radius).acos()
+    }
+}
+
+impl Shape for Vesica {
+    fn area(&self) -> f64 {
+        let angle = self.half_arc_angle();
+
+        2.0 * self.radius.powf(2.0) * (angle - 0.5 * (2.0 * angle).sin())
+    }
+
+    fn perimeter(&self) -> f64 {
+        4.0 * self.radius * self.half_arc_angle()
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let translated = to_local(point, self.center, self.rotation_angle_in_degrees);
+        let p = Vector2D::new(translated.x.abs(), translated.y.abs());
+
+        let d = self.distance;
+        let b = self.half_height();
+
+        if (p.y - b) * d > p.x * b {
+            // beyond the tip
+            (p - Vector2D::new(0.0, b)).length()
+        } else {
+            (p + Vector2D::new(d, 0.0)).length() - self.radius
+        }
+    }
+}
+
+/// An egg with a round bottom of `radius`, flanks bent with twice the base radius and a tip
+/// rounded with `top_radius`. `center` is the center of the bottom circle; the tip points up.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Egg {
+    center: Vector2D<f64>,
+    radius: f64,
+    top_radius: f64,
+    rotation_angle_in_degrees: f64,
+}
+
+impl Egg {
+    pub fn new(
+        center: Vector2D<f64>,
+        radius: f64,
+        top_radius: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        if top_radius > radius {
+            panic!("Egg top radius must not be greater than its radius.");
+        }
+
+        Egg {
+            center,
+            radius,
+            top_radius,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    pub fn top_radius(&self) -> f64 {
+        self.top_radius
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+
+    /// Radius of the bottom circle of the sharp egg that gets rounded by `top_radius`.
+    fn base_radius(&self) -> f64 {
+        self.radius - self.top_radius
+    }
+}
+
+impl Shape for Egg {
+    fn area(&self) -> f64 {
+        // the sharp egg grown outwards by the top radius
+        let r = self.base_radius();
+        let base_area = (11.0 * PI / 6.0 - 3.0_f64.sqrt()) * r * r;
+        let base_perimeter = 7.0 * PI * r / 3.0;
+
+        base_area + base_perimeter * self.top_radius + PI * self.top_radius.powf(2.0)
+    }
+
+    fn perimeter(&self) -> f64 {
+        7.0 * PI * self.base_radius() / 3.0 + 2.0 * PI * self.top_radius
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let translated = to_local(point, self.center, self.rotation_angle_in_degrees);
+        let p = Vector2D::new(translated.x.abs(), translated.y);
+
+        let k = 3.0_f64.sqrt();
+        let r = self.base_radius();
+
+        let distance = if p.y < 0.0 {
+            p.length() - r
+        } else if k * (p.x + r) < p.y {
+            // above the flanks: closest to the tip
+            (p - Vector2D::new(0.0, k * r)).length()
+        } else {
+            (p + Vector2D::new(r, 0.0)).length() - 2.0 * r
+        };
+
+        distance - self.top_radius
+    }
+}
+
+/// Two diagonal strokes with round ends crossing at the center. `width` is the side of the
+/// square spanned by the stroke center lines and `radius` is half the stroke thickness.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RoundedX {
+    center: Vector2D<f64>,
+    width: f64,
+    radius: f64,
+    rotation_angle_in_degrees: f64,
+}
+
+impl RoundedX {
+    pub fn new(
+        center: Vector2D<f64>,
+        width: f64,
+        radius: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        RoundedX {
+            center,
+            width,
+            radius,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+
+    fn stroke_length(&self) -> f64 {
+        SQRT_2 * self.width
+    }
+}
+
+impl Shape for RoundedX {
+    /// Exact as long as the strokes are long enough for their ends not to overlap.
+    fn area(&self) -> f64 {
+        let r = self.radius;
+
+        // two capsules minus the square where they cross
+        2.0 * (2.0 * r * self.stroke_length() + PI * r * r) - 4.0 * r * r
+    }
+
+    fn perimeter(&self) -> f64 {
+        let r = self.radius;
+
+        2.0 * (2.0 * self.stroke_length() + 2.0 * PI * r) - 8.0 * r
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let translated = to_local(point, self.center, self.rotation_angle_in_degrees);
+        let p = Vector2D::new(translated.x.abs(), translated.y.abs());
+
+        let along = (p.x + p.y).min(self.width) * 0.5;
+
+        (p - Vector2D::new(along, along)).length() - self.radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::decorative::{Cross, Egg, Heart, Moon, RoundedX, Star, Vesica};
+    use crate::{get_area, get_sdf, Shape};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    /// Area of the region where the sdf is negative, counted on a fine grid.
+    fn sampled_area<T: Shape>(shape: &T, half_extent: f64) -> f64 {
+        let samples = 1000;
+        let step = 2.0 * half_extent / samples as f64;
+
+        let mut inside = 0;
+        for row in 0..samples {
+            for column in 0..samples {
+                let p = Vector2D::new(
+                    -half_extent + (column as f64 + 0.5) * step,
+                    -half_extent + (row as f64 + 0.5) * step,
+                );
+                if shape.sdf(&p) < 0.0 {
+                    inside += 1;
+                }
+            }
+        }
+
+        inside as f64 * step * step
+    }
+
+    #[test]
+    fn create_star() {
+        let star = Star::new(Vector2D::new(0.0, 0.0), 5, 10.0, 4.0, 0.0);
+
+        assert_eq!(
+            format!("The star is: {star:?}"),
+            "The star is: Star { center: Vector2D { x: 0.0, y: 0.0 }, points: 5, outer_radius: 10.0, inner_radius: 4.0, rotation_angle_in_degrees: 0.0 }"
+        );
+
+        assert_eq!(star.points(), 5);
+        assert_eq!(get_area(&star), 117.55705045849463);
+        assert_eq!(star.perimeter(), 71.6091058804704);
+        assert!((star.area() - sampled_area(&star, 10.0)).abs() < 0.5);
+    }
+
+    #[test]
+    fn star_sdf() {
+        let star = Star::new(Vector2D::new(0.0, 0.0), 4, 10.0, 5.0, 0.0);
+
+        assert_eq!(get_sdf(&star, &Vector2D::new(0.0, 10.0)), 0.0); // tip
+        assert!(star.sdf(&Vector2D::new(10.0, 0.0)).abs() < 1e-12); // tip
+        assert!((star.sdf(&Vector2D::new(0.0, 15.0)) - 5.0).abs() < 1e-12);
+
+        // the notches are on the diagonals
+        let notch = Vector2D::new(1.0, 1.0) * (5.0 / 2.0_f64.sqrt());
+        assert!(star.sdf(&notch).abs() < 1e-12);
+        assert!(star.sdf(&(notch * 2.0)) > 0.0);
+        assert!(star.sdf(&(notch * 0.5)) < 0.0);
+        assert!(star.sdf(&Vector2D::new(0.0, 0.0)) < 0.0);
+    }
+
+    #[test]
+    fn create_cross() {
+        let cross = Cross::new(Vector2D::new(0.0, 0.0), 10.0, 2.0, 0.0);
+
+        assert_eq!(get_area(&cross), 36.0);
+        assert_eq!(cross.perimeter(), 40.0);
+        assert!((cross.area() - sampled_area(&cross, 6.0)).abs() < 0.5);
+    }
+
+    #[test]
+    fn cross_sdf() {
+        let cross = Cross::new(Vector2D::new(0.0, 0.0), 10.0, 2.0, 0.0);
+
+        // the inner corners are closest to the center
+        assert_eq!(
+            get_sdf(&cross, &Vector2D::new(0.0, 0.0)),
+            -std::f64::consts::SQRT_2
+        );
+        assert_eq!(cross.sdf(&Vector2D::new(4.0, 0.0)), -1.0);
+        assert_eq!(cross.sdf(&Vector2D::new(0.0, 7.0)), 2.0);
+        assert_eq!(cross.sdf(&Vector2D::new(3.0, 3.0)), 2.0);
+        assert_eq!(cross.sdf(&Vector2D::new(3.0, 1.5)), 0.5);
+        assert_eq!(cross.sdf(&Vector2D::new(8.0, 5.0)), 5.0);
+
+        let rotated = Cross::new(Vector2D::new(0.0, 0.0), 10.0, 2.0, 45.0);
+        let diagonal = Vector2D::new(1.0, 1.0) * (4.0 / 2.0_f64.sqrt());
+        assert!((rotated.sdf(&diagonal) + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn create_heart() {
+        let heart = Heart::new(Vector2D::new(0.0, 0.0), 10.0, 0.0);
+
+        assert_eq!(get_area(&heart), 89.26990816987241);
+        assert_eq!(heart.perimeter(), 36.356550314522785);
+        assert!((heart.area() - sampled_area(&heart, 8.0)).abs() < 0.5);
+    }
+
+    #[test]
+    fn heart_sdf() {
+        let heart = Heart::new(Vector2D::new(0.0, 0.0), 10.0, 0.0);
+
+        assert_eq!(get_sdf(&heart, &Vector2D::new(0.0, -5.0)), 0.0); // tip
+        assert_eq!(heart.sdf(&Vector2D::new(0.0, 5.0)), 0.0); // notch
+        assert_eq!(heart.sdf(&Vector2D::new(0.0, -7.0)), 1.9999999999999996);
+        assert!(heart.sdf(&Vector2D::new(0.0, 0.0)) < 0.0);
+
+        // the top of each lobe
+        let lobe_top = 2.5 + 10.0 * 2.0_f64.sqrt() / 4.0;
+        assert!(heart.sdf(&Vector2D::new(2.5, lobe_top)).abs() < 1e-12);
+        assert!((heart.sdf(&Vector2D::new(-2.5, lobe_top + 1.0)) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn create_moon() {
+        let moon = Moon::new(Vector2D::new(0.0, 0.0), 10.0, 8.0, 6.0, 0.0);
+
+        assert!((get_area(&moon) - sampled_area(&moon, 10.0)).abs() < 0.5);
+
+        let full = Moon::new(Vector2D::new(0.0, 0.0), 10.0, 2.0, 20.0, 0.0);
+        assert_eq!(full.area(), 100.0 * PI);
+        assert_eq!(full.perimeter(), 20.0 * PI);
+
+        let holed = Moon::new(Vector2D::new(0.0, 0.0), 10.0, 2.0, 5.0, 0.0);
+        assert_eq!(holed.area(), 96.0 * PI);
+        assert_eq!(holed.perimeter(), 24.0 * PI);
+    }
+
+    #[test]
+    fn moon_perimeter() {
+        // two circles of radius 10 crossing at right angles
+        let moon = Moon::new(
+            Vector2D::new(0.0, 0.0),
+            10.0,
+            10.0,
+            10.0 * 2.0_f64.sqrt(),
+            0.0,
+        );
+
+        // three quarters of the disc remain on the outside, a quarter of the cut circle bounds
+        // the inside
+        let expected = 15.0 * PI + 5.0 * PI;
+        assert!((moon.perimeter() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn moon_sdf() {
+        let moon = Moon::new(Vector2D::new(0.0, 0.0), 10.0, 8.0, 6.0, 0.0);
+
+        assert_eq!(get_sdf(&moon, &Vector2D::new(-10.0, 0.0)), 0.0);
+        assert_eq!(moon.sdf(&Vector2D::new(-9.0, 0.0)), -1.0);
+        assert_eq!(moon.sdf(&Vector2D::new(-12.0, 0.0)), 2.0);
+        assert_eq!(moon.sdf(&Vector2D::new(0.0, 0.0)), 2.0);
+        assert_eq!(moon.sdf(&Vector2D::new(-2.0, 0.0)), 0.0);
+
+        // the horns are where the circles cross
+        let horn = Vector2D::new((100.0 - 64.0 + 36.0) / 12.0, (100.0_f64 - 36.0).sqrt());
+        assert!(moon.sdf(&horn).abs() < 1e-12);
+        assert!((moon.sdf(&(horn + Vector2D::new(3.0, 0.0))) - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn create_vesica() {
+        let vesica = Vesica::new(Vector2D::new(0.0, 0.0), 10.0, 5.0, 0.0);
+
+        assert_eq!(vesica.height(), 17.320508075688775);
+        assert_eq!(vesica.width(), 10.0);
+        assert!((get_area(&vesica) - sampled_area(&vesica, 10.0)).abs() < 0.5);
+        assert!((vesica.perimeter() - 40.0 * PI / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn vesica_sdf() {
+        let vesica = Vesica::new(Vector2D::new(0.0, 0.0), 10.0, 5.0, 0.0);
+
+        assert_eq!(get_sdf(&vesica, &Vector2D::new(0.0, 0.0)), -5.0);
+        assert_eq!(vesica.sdf(&Vector2D::new(5.0, 0.0)), 0.0);
+        assert_eq!(vesica.sdf(&Vector2D::new(-7.0, 0.0)), 2.0);
+        assert!(vesica.sdf(&Vector2D::new(0.0, 8.660254037844387)).abs() < 1e-12);
+        assert!((vesica.sdf(&Vector2D::new(0.0, 10.660254037844387)) - 2.0).abs() < 1e-12);
+
+        assert_eq!(
+            Vesica::new(Vector2D::new(0.0, 0.0), 10.0, 5.0, 90.0).sdf(&Vector2D::new(0.0, 5.0)),
+            0.0
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn vesica_discs_must_overlap() {
+        Vesica::new(Vector2D::new(0.0, 0.0), 10.0, 10.0, 0.0);
+    }
+
+    #[test]
+    fn create_egg() {
+        let egg = Egg::new(Vector2D::new(0.0, 0.0), 6.0, 1.0, 0.0);
+
+        assert!((get_area(&egg) - sampled_area(&egg, 10.0)).abs() < 0.5);
+
+        let sharp = Egg::new(Vector2D::new(0.0, 0.0), 6.0, 0.0, 0.0);
+        assert_eq!(sharp.perimeter(), 14.0 * PI);
+    }
+
+    #[test]
+    fn egg_sdf() {
+        let egg = Egg::new(Vector2D::new(0.0, 0.0), 6.0, 1.0, 0.0);
+
+        assert_eq!(get_sdf(&egg, &Vector2D::new(0.0, 0.0)), -6.0);
+        assert_eq!(egg.sdf(&Vector2D::new(0.0, -6.0)), 0.0);
+        assert_eq!(egg.sdf(&Vector2D::new(6.0, 0.0)), 0.0);
+        assert_eq!(egg.sdf(&Vector2D::new(-8.0, 0.0)), 2.0);
+
+        // the rounded tip
+        let tip = 5.0 * 3.0_f64.sqrt();
+        assert!(egg.sdf(&Vector2D::new(0.0, tip + 1.0)).abs() < 1e-12);
+        assert!((egg.sdf(&Vector2D::new(0.0, tip)) + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn create_rounded_x() {
+        let x = RoundedX::new(Vector2D::new(0.0, 0.0), 10.0, 1.0, 0.0);
+
+        assert!((get_area(&x) - sampled_area(&x, 7.0)).abs() < 0.5);
+        assert!((x.perimeter() - (4.0 * 200.0_f64.sqrt() + 4.0 * PI - 8.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rounded_x_sdf() {
+        let x = RoundedX::new(Vector2D::new(0.0, 0.0), 10.0, 1.0, 0.0);
+
+        assert_eq!(get_sdf(&x, &Vector2D::new(0.0, 0.0)), -1.0);
+        assert_eq!(x.sdf(&Vector2D::new(5.0, 5.0)), -1.0);
+        assert_eq!(x.sdf(&Vector2D::new(-5.0, 8.0)), 2.0);
+        assert!((x.sdf(&Vector2D::new(3.0, 0.0)) - (4.5_f64.sqrt() - 1.0)).abs() < 1e-12);
+    }
+}
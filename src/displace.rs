@@ -0,0 +1,306 @@
+use crate::bounds::BoundingBox;
+use crate::numeric::{estimate_area, estimate_perimeter};
+use crate::{dot_product, Shape};
+use vector2d::Vector2D;
+
+/// Deterministic 2D gradient (Perlin) noise: random unit gradients on an integer lattice,
+/// blended with a quintic fade. The same seed always gives the same noise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GradientNoise {
+    seed: u64,
+    frequency: f64, // lattice cells per unit length
+    amplitude: f64,
+}
+
+impl GradientNoise {
+    /// Unit frequency, unit amplitude noise changes by at most this much per unit length:
+    /// the blended gradients contribute at most 1, and the fade (whose slope peaks at 15/8)
+    /// times the difference between two corner values (at most 2 sqrt(2) apart) contributes
+    /// at most 15/8 * 2 sqrt(2) along each axis, 15/8 * 4 in all.
+    const LIPSCHITZ_CONSTANT: f64 = 1.0 + 1.875 * 4.0;
+
+    pub fn new(seed: u64, frequency: f64, amplitude: f64) -> Self {
+        if frequency <= 0.0 {
+            panic!("Noise frequency must be greater than zero.");
+        }
+
+        if amplitude < 0.0 {
+            panic!("Noise amplitude must not be negative.");
+        }
+
+        GradientNoise {
+            seed,
+            frequency,
+            amplitude,
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn frequency(&self) -> f64 {
+        self.frequency
+    }
+
+    pub fn amplitude(&self) -> f64 {
+        self.amplitude
+    }
+
+    /// The noise at `point`, always within plus or minus the amplitude.
+    pub fn value(&self, point: &Vector2D<f64>) -> f64 {
+        let p = *point * self.frequency;
+        let cell = Vector2D::new(p.x.floor(), p.y.floor());
+        let offset = p - cell;
+        let (x, y) = (cell.x as i64, cell.y as i64);
+
+        let corner = |dx: i64, dy: i64| {
+            let to_point = offset - Vector2D::new(dx as f64, dy as f64);
+
+            dot_product(&self.gradient(x + dx, y + dy), &to_point)
+        };
+
+        let u = fade(offset.x);
+        let v = fade(offset.y);
+        let bottom = lerp(corner(0, 0), corner(1, 0), u);
+        let top = lerp(corner(0, 1), corner(1, 1), u);
+
+        lerp(bottom, top, v) * self.amplitude
+    }
+
+    /// A bound on how fast the noise can change: |value(p) - value(q)| <= L |p - q|.
+    pub fn lipschitz_constant(&self) -> f64 {
+        GradientNoise::LIPSCHITZ_CONSTANT * self.frequency * self.amplitude
+    }
+
+    fn gradient(&self, x: i64, y: i64) -> Vector2D<f64> {
+        let hash = mix(self.seed ^ mix((x as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15) ^ y as u64));
+        let angle = (hash >> 11) as f64 / (1u64 << 53) as f64 * std::f64::consts::TAU;
+
+        Vector2D::new(angle.cos(), angle.sin())
+    }
+}
+
+/// Any shape with its outline pushed in or out by a displacement: positive values shrink the
+/// shape, negative values grow it.
+///
+/// If the displacement changes by at most L per unit length, the displaced field changes by
+/// at most 1 + L (its Lipschitz bound). `sdf` divides by that bound, so it keeps the displaced
+/// outline and never over-estimates the distance to it, which keeps it safe to march along.
+pub struct Displaced<S: Shape> {
+    shape: S,
+    displacement: Displacement,
+    lipschitz_constant: f64,
+}
+
+type Displacement = Box<dyn Fn(&Vector2D<f64>) -> f64>;
+
+impl<S: Shape> Displaced<S> {
+    /// `lipschitz_constant` must bound how fast `displacement` changes per unit length.
+    pub fn new(
+        shape: S,
+        displacement: impl Fn(&Vector2D<f64>) -> f64 + 'static,
+        lipschitz_constant: f64,
+    ) -> Self {
+        if !(lipschitz_constant >= 0.0 && lipschitz_constant.is_finite()) {
+            panic!("Displacement Lipschitz constant must be finite and not negative.");
+        }
+
+        Displaced {
+            shape,
+            displacement: Box::new(displacement),
+            lipschitz_constant,
+        }
+    }
+
+    pub fn with_noise(shape: S, noise: GradientNoise) -> Self {
+        Displaced::new(
+            shape,
+            move |point| noise.value(point),
+            noise.lipschitz_constant(),
+        )
+    }
+
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    pub fn displacement_at(&self, point: &Vector2D<f64>) -> f64 {
+        (self.displacement)(point)
+    }
+
+    pub fn lipschitz_constant(&self) -> f64 {
+        self.lipschitz_constant
+    }
+
+    /// How fast the undivided displaced field can change per unit length.
+    pub fn lipschitz_bound(&self) -> f64 {
+        1.0 + self.lipschitz_constant
+    }
+
+    /// The shape's distance plus the displacement, before dividing by the Lipschitz bound.
+    pub fn displaced_distance(&self, point: &Vector2D<f64>) -> f64 {
+        self.shape.sdf(point) + self.displacement_at(point)
+    }
+}
+
+impl<S: Shape> Shape for Displaced<S> {
+    fn area(&self) -> f64 {
+        // far away the divided distance is too small for the estimates to find the shape by
+        estimate_area(&Undivided(self))
+    }
+
+    fn perimeter(&self) -> f64 {
+        estimate_perimeter(&Undivided(self))
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        self.displaced_distance(point) / self.lipschitz_bound()
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        Undivided(self).bounding_box()
+    }
+}
+
+struct Undivided<'a, S: Shape>(&'a Displaced<S>);
+
+impl<S: Shape> Shape for Undivided<'_, S> {
+    fn area(&self) -> f64 {
+        estimate_area(self)
+    }
+
+    fn perimeter(&self) -> f64 {
+        estimate_perimeter(self)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        self.0.displaced_distance(point)
+    }
+}
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+// splitmix64 finalizer
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::circle::Circle;
+    use crate::displace::{Displaced, GradientNoise};
+    use crate::{get_sdf, Shape, ShapeFactory};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    fn island() -> Circle {
+        Circle::new(Vector2D::new(0.0, 0.0), 10.0)
+    }
+
+    fn sample_points() -> impl Iterator<Item = Vector2D<f64>> {
+        (0..2000).map(|i| {
+            let i = i as f64;
+            Vector2D::new((i * 0.618034).fract() * 30.0 - 15.0, i * 0.015 - 15.0)
+        })
+    }
+
+    #[test]
+    fn create_noise() {
+        let noise = GradientNoise::new(7, 0.5, 2.0);
+
+        assert_eq!(
+            format!("The noise is: {noise:?}"),
+            "The noise is: GradientNoise { seed: 7, frequency: 0.5, amplitude: 2.0 }"
+        );
+
+        assert_eq!(noise.seed(), 7);
+        assert_eq!(noise.frequency(), 0.5);
+        assert_eq!(noise.amplitude(), 2.0);
+        assert_eq!(noise.lipschitz_constant(), 8.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "frequency must be greater than zero")]
+    fn noise_needs_a_frequency() {
+        GradientNoise::new(7, 0.0, 2.0);
+    }
+
+    #[test]
+    fn noise_values() {
+        let noise = GradientNoise::new(7, 0.5, 2.0);
+
+        // zero on the lattice, deterministic and seeded everywhere else
+        assert_eq!(noise.value(&Vector2D::new(4.0, -6.0)), 0.0);
+        let p = Vector2D::new(1.3, 2.7);
+        assert_eq!(noise.value(&p), GradientNoise::new(7, 0.5, 2.0).value(&p));
+        assert_ne!(noise.value(&p), GradientNoise::new(8, 0.5, 2.0).value(&p));
+
+        for p in sample_points() {
+            assert!(noise.value(&p).abs() <= noise.amplitude());
+
+            let q = p + Vector2D::new(1e-3, -2e-3);
+            let change = (noise.value(&q) - noise.value(&p)).abs();
+            assert!(change <= noise.lipschitz_constant() * (q - p).length());
+        }
+    }
+
+    #[test]
+    fn displaced_by_a_function() {
+        let displaced = Displaced::new(island(), |p: &Vector2D<f64>| p.x * 0.1, 0.1);
+
+        assert_eq!(displaced.lipschitz_bound(), 1.1);
+        assert_eq!(displaced.displacement_at(&Vector2D::new(10.0, 0.0)), 1.0);
+        assert_eq!(displaced.displaced_distance(&Vector2D::new(10.0, 0.0)), 1.0);
+        assert_eq!(get_sdf(&displaced, &Vector2D::new(11.0, 0.0)), 2.1 / 1.1);
+        // pushed in on the right, out on the left
+        assert!(displaced.sdf(&Vector2D::new(9.5, 0.0)) > 0.0);
+        assert!(displaced.sdf(&Vector2D::new(-10.5, 0.0)) < 0.0);
+    }
+
+    #[test]
+    fn displaced_by_noise() {
+        let noise = GradientNoise::new(42, 0.3, 1.5);
+        let displaced = Displaced::with_noise(island(), noise);
+
+        assert_eq!(displaced.shape().radius(), 10.0);
+        assert_eq!(displaced.lipschitz_constant(), noise.lipschitz_constant());
+
+        // the coast stays within the amplitude of the circle
+        assert!(displaced.sdf(&Vector2D::new(0.0, 0.0)) < 0.0);
+        for p in sample_points() {
+            let distance = island().sdf(&p);
+            if distance.abs() > noise.amplitude() {
+                assert_eq!(displaced.sdf(&p) < 0.0, distance < 0.0);
+            }
+
+            // never changes faster than a distance
+            let q = p + Vector2D::new(-2e-3, 1e-3);
+            assert!((displaced.sdf(&q) - displaced.sdf(&p)).abs() <= (q - p).length());
+        }
+
+        let area = displaced.area();
+        assert!(area > PI * 8.5 * 8.5 && area < PI * 11.5 * 11.5);
+        assert!(displaced.perimeter() > 20.0 * PI);
+    }
+
+    #[test]
+    fn displaced_through_the_factory() {
+        let shape = ShapeFactory::new_displaced_noise(
+            ShapeFactory::new_hexagon_horizontal(Vector2D::new(0.0, 0.0), 10.0),
+            GradientNoise::new(1, 0.2, 1.0),
+        );
+
+        assert!(shape.sdf(&Vector2D::new(0.0, 0.0)) < 0.0);
+        assert!(shape.sdf(&Vector2D::new(0.0, 20.0)) > 0.0);
+    }
+}
@@ -0,0 +1,348 @@
+use crate::bounds::{BoundingBox, OrientedBoundingBox};
+use crate::circle::Circle;
+use crate::{to_local, to_world, Shape};
+use std::f64::consts::PI;
+use vector2d::Vector2D;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ellipse {
+    center: Vector2D<f64>,
+    radii: Vector2D<f64>, // semi-axes along the local x and y axes
+    rotation_angle_in_degrees: f64,
+}
+
+impl Ellipse {
+    pub fn new(
+        center: Vector2D<f64>,
+        radii: Vector2D<f64>,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        Ellipse {
+            center,
+            radii,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn radii(&self) -> Vector2D<f64> {
+        self.radii
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+
+    pub fn semi_major_axis(&self) -> f64 {
+        // a
+        self.radii.x.max(self.radii.y)
+    }
+
+    pub fn semi_minor_axis(&self) -> f64 {
+        // b
+        self.radii.x.min(self.radii.y)
+    }
+
+    pub fn eccentricity(&self) -> f64 {
+        let a = self.semi_major_axis();
+        let b = self.semi_minor_axis();
+
+        (1.0 - (b * b) / (a * a)).sqrt()
+    }
+
+    /// Closest point on the ellipse boundary to the local (centered, unrotated) point
+    /// `translated`, together with a flag telling whether that point lies inside the ellipse.
+    fn closest_local_point(&self, translated: &Vector2D<f64>) -> (Vector2D<f64>, bool) {
+        // the solver works in the first quadrant with the major axis along x
+        let swapped = self.radii.x < self.radii.y;
+        let (e0, e1, y0, y1) = if swapped {
+            (
+                self.radii.y,
+                self.radii.x,
+                translated.y.abs(),
+                translated.x.abs(),
+            )
+        } else {
+            (
+                self.radii.x,
+                self.radii.y,
+                translated.x.abs(),
+                translated.y.abs(),
+            )
+        };
+
+        let (x0, x1) = closest_point_in_first_quadrant(e0, e1, y0, y1);
+        let inside = (y0 / e0).powf(2.0) + (y1 / e1).powf(2.0) < 1.0;
+
+        let (x, y) = if swapped { (x1, x0) } else { (x0, x1) };
+        let closest = Vector2D::new(x.copysign(translated.x), y.copysign(translated.y));
+
+        (closest, inside)
+    }
+}
+
+impl Shape for Ellipse {
+    fn area(&self) -> f64 {
+        PI * self.radii.x * self.radii.y
+    }
+
+    fn perimeter(&self) -> f64 {
+        // Gauss-Kummer via the arithmetic-geometric mean; exact to machine precision
+        let a = self.semi_major_axis();
+        let b = self.semi_minor_axis();
+
+        if b == 0.0 {
+            return 4.0 * a;
+        }
+
+        let mut an = a;
+        let mut bn = b;
+        let mut sum = 0.5 * (a * a - b * b);
+        let mut power = 1.0;
+
+        for _ in 0..64 {
+            let cn = (an - bn) * 0.5;
+            let next_an = (an + bn) * 0.5;
+            bn = (an * bn).sqrt();
+            an = next_an;
+            sum += power * cn * cn;
+            power *= 2.0;
+
+            if cn.abs() <= f64::EPSILON * an {
+                break;
+            }
+        }
+
+        2.0 * PI * (a * a - sum) / an
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let translated = to_local(point, self.center, self.rotation_angle_in_degrees);
+        let (closest, inside) = self.closest_local_point(&translated);
+        let distance = (translated - closest).length();
+
+        if inside {
+            -distance
+        } else {
+            distance
+        }
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        // along each axis the half extent is the length of the rotated semi-axes' projections
+        let (sin, cos) = self.rotation_angle_in_degrees.to_radians().sin_cos();
+        let a = Vector2D::new(self.radii.x * cos, self.radii.x * sin);
+        let b = Vector2D::new(self.radii.y * sin, self.radii.y * cos);
+        let half_extents = Vector2D::new(a.x.hypot(b.x), a.y.hypot(b.y));
+
+        BoundingBox::new(self.center - half_extents, self.center + half_extents)
+    }
+
+    fn oriented_bounding_box(&self) -> OrientedBoundingBox {
+        OrientedBoundingBox::new(self.center, self.radii, self.rotation_angle_in_degrees)
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        Circle::new(self.center, self.semi_major_axis())
+    }
+
+    fn largest_inscribed_circle(&self) -> Circle {
+        Circle::new(self.center, self.semi_minor_axis())
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        let local = to_local(point, self.center, self.rotation_angle_in_degrees);
+        let (closest, _) = self.closest_local_point(&local);
+
+        let [closest] = to_world([closest], self.center, self.rotation_angle_in_degrees);
+
+        closest
+    }
+}
+
+/// Robust point-to-ellipse distance (Eberly) for an ellipse with semi-axes `e0 >= e1 > 0`
+/// and a query point `(y0, y1)` in the first quadrant. The root of the distance equation
+/// is found by bisection, so the result is exact up to floating point precision.
+fn closest_point_in_first_quadrant(e0: f64, e1: f64, y0: f64, y1: f64) -> (f64, f64) {
+    if y1 > 0.0 {
+        if y0 > 0.0 {
+            let z0 = y0 / e0;
+            let z1 = y1 / e1;
+            let g = z0 * z0 + z1 * z1 - 1.0;
+
+            if g != 0.0 {
+                let r0 = (e0 / e1).powf(2.0);
+                let sbar = get_root(r0, z0, z1, g);
+
+                (r0 * y0 / (sbar + r0), y1 / (sbar + 1.0))
+            } else {
+                (y0, y1)
+            }
+        } else {
+            (0.0, e1)
+        }
+    } else {
+        let numerator = e0 * y0;
+        let denominator = e0 * e0 - e1 * e1;
+
+        if numerator < denominator {
+            let xde0 = numerator / denominator;
+
+            (e0 * xde0, e1 * (1.0 - xde0 * xde0).sqrt())
+        } else {
+            (e0, 0.0)
+        }
+    }
+}
+
+fn get_root(r0: f64, z0: f64, z1: f64, g: f64) -> f64 {
+    let n0 = r0 * z0;
+    let mut s0 = z1 - 1.0;
+    let mut s1 = if g < 0.0 { 0.0 } else { n0.hypot(z1) - 1.0 };
+    let mut s = 0.0;
+
+    for _ in 0..1074 {
+        s = (s0 + s1) * 0.5;
+        if s == s0 || s == s1 {
+            break;
+        }
+
+        let ratio0 = n0 / (s + r0);
+        let ratio1 = z1 / (s + 1.0);
+        let g = ratio0 * ratio0 + ratio1 * ratio1 - 1.0;
+
+        if g > 0.0 {
+            s0 = s;
+        } else if g < 0.0 {
+            s1 = s;
+        } else {
+            break;
+        }
+    }
+
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ellipse::Ellipse;
+    use crate::{get_area, get_sdf, to_local, Shape};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    #[test]
+    fn create_ellipse() {
+        let ellipse = Ellipse::new(Vector2D::new(10.0, 10.0), Vector2D::new(20.0, 10.0), 0.0);
+
+        assert_eq!(
+            format!("The ellipse is: {ellipse:?}"),
+            "The ellipse is: Ellipse { center: Vector2D { x: 10.0, y: 10.0 }, radii: Vector2D { x: 20.0, y: 10.0 }, rotation_angle_in_degrees: 0.0 }"
+        );
+
+        assert_eq!(ellipse.center().x, 10.0);
+        assert_eq!(ellipse.center().y, 10.0);
+        assert_eq!(ellipse.radii().x, 20.0);
+        assert_eq!(ellipse.radii().y, 10.0);
+        assert_eq!(ellipse.semi_major_axis(), 20.0);
+        assert_eq!(ellipse.semi_minor_axis(), 10.0);
+        assert_eq!(ellipse.eccentricity(), 0.8660254037844386);
+
+        assert_eq!(get_area(&ellipse), 628.3185307179587);
+        assert!((ellipse.perimeter() - 96.88448220547676).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ellipse_perimeter_of_circle() {
+        let ellipse = Ellipse::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 10.0), 0.0);
+
+        assert_eq!(ellipse.perimeter(), 2.0 * PI * 10.0);
+
+        let degenerate = Ellipse::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 0.0), 0.0);
+
+        assert_eq!(degenerate.perimeter(), 40.0);
+    }
+
+    #[test]
+    fn ellipse_equality() {
+        let ellipse1 = Ellipse::new(Vector2D::new(10.0, 10.0), Vector2D::new(20.0, 10.0), 0.0);
+        let ellipse2 = Ellipse::new(Vector2D::new(10.0, 10.0), Vector2D::new(20.0, 10.0), 0.0);
+        let ellipse3 = Ellipse::new(Vector2D::new(10.0, 10.0), Vector2D::new(20.0, 10.0), 45.0);
+
+        assert!(ellipse1 == ellipse2);
+        assert!(ellipse1 != ellipse3);
+    }
+
+    #[test]
+    fn ellipse_sdf() {
+        let ellipse = Ellipse::new(Vector2D::new(10.0, 10.0), Vector2D::new(20.0, 10.0), 0.0);
+
+        assert_eq!(get_sdf(&ellipse, &Vector2D::new(10.0, 10.0)), -10.0); // center
+        assert_eq!(ellipse.sdf(&Vector2D::new(30.0, 10.0)), 0.0);
+        assert_eq!(ellipse.sdf(&Vector2D::new(10.0, 20.0)), 0.0);
+        assert_eq!(ellipse.sdf(&Vector2D::new(40.0, 10.0)), 10.0);
+        assert_eq!(ellipse.sdf(&Vector2D::new(10.0, -5.0)), 5.0);
+        assert_eq!(ellipse.sdf(&Vector2D::new(15.0, 10.0)), -9.574271077563381);
+
+        // the distance is a true euclidean distance: the closest point is at distance |sdf|
+        let point = Vector2D::new(35.0, 25.0);
+        let distance = ellipse.sdf(&point);
+        let local = to_local(&point, ellipse.center, ellipse.rotation_angle_in_degrees);
+        let (closest, _) = ellipse.closest_local_point(&local);
+        let on_boundary = (closest.x / 20.0).powf(2.0) + (closest.y / 10.0).powf(2.0);
+        assert!((on_boundary - 1.0).abs() < 1e-12);
+        assert!(((point - ellipse.center() - closest).length() - distance).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ellipse_oriented_sdf() {
+        let ellipse = Ellipse::new(Vector2D::new(10.0, 10.0), Vector2D::new(20.0, 10.0), 90.0);
+
+        assert!(ellipse.sdf(&Vector2D::new(10.0, 30.0)).abs() < 1e-12);
+        assert!(ellipse.sdf(&Vector2D::new(20.0, 10.0)).abs() < 1e-12);
+        assert!((ellipse.sdf(&Vector2D::new(10.0, 40.0)) - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ellipse_bounding_box() {
+        let ellipse = Ellipse::new(Vector2D::new(0.0, 0.0), Vector2D::new(20.0, 10.0), 0.0);
+        let bounds = ellipse.bounding_box();
+        assert!((bounds.max() - Vector2D::new(20.0, 10.0)).length() < 1e-12);
+
+        // turned a quarter the semi-axes swap
+        let ellipse_oriented =
+            Ellipse::new(Vector2D::new(5.0, 5.0), Vector2D::new(20.0, 10.0), 90.0);
+        let bounds = ellipse_oriented.bounding_box();
+        assert!((bounds.width() - 20.0).abs() < 1e-12);
+        assert!((bounds.height() - 40.0).abs() < 1e-12);
+
+        // at 45 degrees both reach sqrt((400 + 100) / 2), and the outline touches the box
+        let ellipse_oriented =
+            Ellipse::new(Vector2D::new(0.0, 0.0), Vector2D::new(20.0, 10.0), 45.0);
+        let bounds = ellipse_oriented.bounding_box();
+        assert!((bounds.max().x - 250.0_f64.sqrt()).abs() < 1e-12);
+        assert!((bounds.max().y - 250.0_f64.sqrt()).abs() < 1e-12);
+        assert!(ellipse_oriented.sdf(&Vector2D::new(bounds.max().x, 0.0)) > 0.0);
+        assert!(ellipse_oriented.sdf(&Vector2D::new(bounds.max().x - 0.01, 0.0)) > -0.01);
+
+        let oriented = ellipse_oriented.oriented_bounding_box();
+        assert_eq!(oriented.half_extents(), Vector2D::new(20.0, 10.0));
+        assert_eq!(oriented.rotation_angle_in_degrees(), 45.0);
+    }
+
+    #[test]
+    fn ellipse_closest_point() {
+        let ellipse_oriented =
+            Ellipse::new(Vector2D::new(5.0, 5.0), Vector2D::new(20.0, 10.0), 30.0);
+
+        for i in 0..100 {
+            let p = Vector2D::new((i % 10) as f64 * 5.3 - 20.0, (i / 10) as f64 * 3.7 - 12.0);
+            let closest = ellipse_oriented.closest_point(&p);
+
+            assert!(ellipse_oriented.sdf(&closest).abs() < 1e-9);
+            assert!(((p - closest).length() - ellipse_oriented.sdf(&p).abs()).abs() < 1e-9);
+        }
+    }
+}
@@ -49,12 +49,12 @@ impl Grid2D {
 
     pub fn get_smallest_number(&self) -> f64 {
         //*self.grid.iter().min().unwrap()
-        self.grid.iter().cloned().fold(0.0 / 0.0, f64::min)
+        self.grid.iter().cloned().fold(f64::NAN, f64::min)
     }
 
     pub fn get_largest_number(&self) -> f64 {
         //*self.grid.iter().max().unwrap()
-        self.grid.iter().cloned().fold(0.0 / 0.0, f64::max)
+        self.grid.iter().cloned().fold(f64::NAN, f64::max)
     }
 
     fn get_index(&self, column: usize, row: usize) -> usize {
@@ -1,6 +1,10 @@
-use crate::{
-    abs_vector, dot_product, max_f64, rotate_vector_by_30_degrees, HexagonOrientation, Shape,
-};
+use crate::bounds::BoundingBox;
+use crate::circle::Circle;
+use crate::convex::ConvexShape;
+use crate::mass::SecondMomentOfArea;
+use crate::ray::RayHit;
+use crate::regular_polygon::RegularPolygon;
+use crate::{HexagonOrientation, Shape};
 use vector2d::Vector2D;
 
 #[derive(Debug, PartialEq)]
@@ -28,9 +32,13 @@ impl Hexagon {
         self.circumradius
     }
 
+    pub fn orientation(&self) -> &HexagonOrientation {
+        &self.orientation
+    }
+
     pub fn inradius(&self) -> f64 {
         // r
-        (1.7320508 / 2.0) * self.circumradius()
+        self.as_regular_polygon().inradius()
     }
 
     pub fn maximal_diameter(&self) -> f64 {
@@ -52,11 +60,22 @@ impl Hexagon {
         // t
         self.circumradius()
     }
+
+    /// A horizontal (pointy) hexagon is the unrotated six sided polygon, a vertical (flat) one
+    /// is that polygon turned by 30 degrees.
+    pub fn as_regular_polygon(&self) -> RegularPolygon {
+        let rotation_angle_in_degrees = match self.orientation {
+            HexagonOrientation::Horizontal => 0.0,
+            HexagonOrientation::Vertical => 30.0,
+        };
+
+        RegularPolygon::new(self.center, 6, self.circumradius, rotation_angle_in_degrees)
+    }
 }
 
 impl Shape for Hexagon {
     fn area(&self) -> f64 {
-        2.0 * self.inradius().powf(2.0) * 1.7320508
+        self.as_regular_polygon().area()
     }
 
     fn perimeter(&self) -> f64 {
@@ -64,23 +83,58 @@ impl Shape for Hexagon {
     }
 
     fn sdf(&self, point: &Vector2D<f64>) -> f64 {
-        // translate to center the circle at origin
-        let translated = *point - self.center;
+        self.as_regular_polygon().sdf(point)
+    }
 
-        let translated = match self.orientation {
-            HexagonOrientation::Horizontal => translated,
-            HexagonOrientation::Vertical => rotate_vector_by_30_degrees(&translated),
-        };
+    fn bounding_box(&self) -> BoundingBox {
+        self.as_regular_polygon().bounding_box()
+    }
 
-        let s = Vector2D::new(1.0, 1.7320508) * 0.5;
-        let p = abs_vector(&translated);
+    fn bounding_circle(&self) -> Circle {
+        self.as_regular_polygon().bounding_circle()
+    }
+
+    fn largest_inscribed_circle(&self) -> Circle {
+        self.as_regular_polygon().largest_inscribed_circle()
+    }
 
-        max_f64(dot_product(&p, &s), p.x) - self.inradius()
+    fn gradient(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        self.as_regular_polygon().gradient(point)
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        self.as_regular_polygon().closest_point(point)
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        self.as_regular_polygon()
+            .ray_intersect(origin, direction, max_distance)
+    }
+
+    fn centroid(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        self.as_regular_polygon().second_moment_of_area()
+    }
+}
+
+impl ConvexShape for Hexagon {
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        self.as_regular_polygon().support(direction)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::mass::AreaIntegrals;
+    use crate::numeric::{estimate_area_integrals, estimate_gradient, sphere_trace};
     use crate::{get_area, get_sdf, Hexagon, HexagonOrientation, Shape};
     use vector2d::Vector2D;
 
@@ -100,14 +154,14 @@ mod tests {
         assert_eq!(hexagon.center().x, 10.0);
         assert_eq!(hexagon.center().y, 10.0);
         assert_eq!(hexagon.circumradius(), 10.0);
-        assert_eq!(hexagon.inradius(), 8.660254);
+        assert_eq!(hexagon.inradius(), 8.660254037844387);
         assert_eq!(hexagon.maximal_diameter(), 20.0);
-        assert_eq!(hexagon.minimal_diameter(), 17.320508);
-        assert_eq!(hexagon.apothem(), 8.660254);
+        assert_eq!(hexagon.minimal_diameter(), 17.320508075688775);
+        assert_eq!(hexagon.apothem(), 8.660254037844387);
         assert_eq!(hexagon.side_length(), 10.0);
 
-        assert_eq!(get_area(&hexagon), 259.8076177293368);
-        //assert_eq!(hexagon.area(), 259.8076177293368);
+        assert_eq!(get_area(&hexagon), 259.8076211353316);
+        //assert_eq!(hexagon.area(), 259.8076211353316);
         assert_eq!(hexagon.perimeter(), 60.0);
     }
 
@@ -129,8 +183,8 @@ mod tests {
             HexagonOrientation::Vertical,
         );
 
-        assert_eq!(hexagon1 == hexagon2, true);
-        assert_eq!(hexagon1 != hexagon3, true);
+        assert!(hexagon1 == hexagon2);
+        assert!(hexagon1 != hexagon3);
     }
 
     #[test]
@@ -141,19 +195,26 @@ mod tests {
             HexagonOrientation::Horizontal,
         );
 
-        assert_eq!(get_sdf(&hexagon, &Vector2D::new(10.0, 10.0)), -8.660254); // center
-        assert_eq!(hexagon.sdf(&Vector2D::new(5.0, 5.0)), -1.830127);
+        assert_eq!(
+            get_sdf(&hexagon, &Vector2D::new(10.0, 10.0)),
+            -8.660254037844387
+        ); // center
+        assert_eq!(hexagon.sdf(&Vector2D::new(5.0, 5.0)), -1.8301270189221936);
 
-        assert_eq!(hexagon.sdf(&Vector2D::new(10.0, 0.0)), 0.0);
+        assert_eq!(
+            hexagon.sdf(&Vector2D::new(10.0, 0.0)),
+            1.7763568394002505e-15
+        );
         assert_eq!(
             hexagon.sdf(&Vector2D::new(1.339, 10.0)),
-            0.0007459999999994693
+            0.0007459621556122897
         );
 
         assert_eq!(hexagon.sdf(&Vector2D::new(0.0, 0.0)), 5.0);
+        // nearest feature is the lower left vertex, not the edge's supporting line
         assert_eq!(
             hexagon.sdf(&Vector2D::new(-10.0, -10.0)),
-            18.660254000000002
+            18.803984643852075
         );
     }
 
@@ -165,19 +226,164 @@ mod tests {
             HexagonOrientation::Vertical,
         );
 
-        assert_eq!(get_sdf(&hexagon, &Vector2D::new(10.0, 10.0)), -8.660254); // center
-        assert_eq!(hexagon.sdf(&Vector2D::new(5.0, 5.0)), -1.830127006956321);
+        assert_eq!(
+            get_sdf(&hexagon, &Vector2D::new(10.0, 10.0)),
+            -8.660254037844387
+        ); // center
+        assert_eq!(hexagon.sdf(&Vector2D::new(5.0, 5.0)), -1.8301270189221936);
+
+        assert_eq!(hexagon.sdf(&Vector2D::new(10.0, 0.0)), 1.3397459621556127);
+        assert_eq!(hexagon.sdf(&Vector2D::new(0.0, 10.0)), 0.0);
 
-        assert_eq!(hexagon.sdf(&Vector2D::new(10.0, 0.0)), 1.3397459671873602);
+        assert_eq!(hexagon.sdf(&Vector2D::new(0.0, 0.0)), 5.0);
         assert_eq!(
-            hexagon.sdf(&Vector2D::new(0.0, 10.0)),
-            3.7799999574872345e-8
+            hexagon.sdf(&Vector2D::new(-10.0, -10.0)),
+            18.803984643852075
+        );
+    }
+
+    #[test]
+    fn hexagon_as_regular_polygon() {
+        let hexagon = Hexagon::new(
+            Vector2D::new(10.0, 10.0),
+            10.0,
+            HexagonOrientation::Vertical,
         );
+        let polygon = hexagon.as_regular_polygon();
+
+        assert_eq!(polygon.sides(), 6);
+        assert_eq!(polygon.circumradius(), 10.0);
+        assert_eq!(polygon.rotation_angle_in_degrees(), 30.0);
+        assert!((polygon.side_length() - hexagon.side_length()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn hexagon_bounding_box() {
+        let center = Vector2D::new(10.0, 10.0);
+
+        // pointy: a vertex at the top and bottom, flat sides left and right
+        let horizontal = Hexagon::new(center, 10.0, HexagonOrientation::Horizontal);
+        let bounds = horizontal.bounding_box();
+        assert!((bounds.width() - horizontal.minimal_diameter()).abs() < 1e-12);
+        assert!((bounds.height() - horizontal.maximal_diameter()).abs() < 1e-12);
+        assert!((bounds.center() - center).length() < 1e-12);
+
+        // flat: the other way around
+        let vertical = Hexagon::new(center, 10.0, HexagonOrientation::Vertical);
+        let bounds = vertical.bounding_box();
+        assert!((bounds.width() - vertical.maximal_diameter()).abs() < 1e-12);
+        assert!((bounds.height() - vertical.minimal_diameter()).abs() < 1e-12);
+        assert!((bounds.center() - center).length() < 1e-12);
 
-        assert_eq!(hexagon.sdf(&Vector2D::new(0.0, 0.0)), 4.999999986087358);
+        let oriented = vertical.oriented_bounding_box();
+        assert_eq!(oriented.rotation_angle_in_degrees(), 0.0);
+        assert_eq!(oriented.bounding_box(), bounds);
+    }
+
+    #[test]
+    fn hexagon_bounding_and_inscribed_circles() {
+        let hexagon = Hexagon::new(
+            Vector2D::new(10.0, 10.0),
+            10.0,
+            HexagonOrientation::Vertical,
+        );
+
+        assert_eq!(hexagon.bounding_circle().radius(), hexagon.circumradius());
         assert_eq!(
-            hexagon.sdf(&Vector2D::new(-10.0, -10.0)),
-            18.660253972174715
+            hexagon.largest_inscribed_circle().radius(),
+            hexagon.inradius()
+        );
+        assert_eq!(
+            hexagon.largest_inscribed_circle().center(),
+            Vector2D::new(10.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn hexagon_gradient() {
+        for orientation in [HexagonOrientation::Horizontal, HexagonOrientation::Vertical] {
+            let hexagon = Hexagon::new(Vector2D::new(10.0, 10.0), 10.0, orientation);
+
+            for i in 0..400 {
+                let p = Vector2D::new((i % 20) as f64 * 1.7 - 6.0, (i / 20) as f64 * 1.3 - 3.0);
+                let estimate = estimate_gradient(&hexagon, &p);
+
+                if estimate.length() > 0.999 {
+                    assert!((hexagon.gradient(&p) - estimate).length() < 1e-6);
+                }
+            }
+        }
+
+        // straight out of the side to the right of a pointy hexagon, straight up past the tip
+        let hexagon = Hexagon::new(
+            Vector2D::new(0.0, 0.0),
+            10.0,
+            HexagonOrientation::Horizontal,
+        );
+        assert!(
+            (hexagon.normal(&Vector2D::new(20.0, 0.0)) - Vector2D::new(1.0, 0.0)).length() < 1e-12
+        );
+        assert!(
+            (hexagon.normal(&Vector2D::new(0.0, 20.0)) - Vector2D::new(0.0, 1.0)).length() < 1e-12
         );
     }
+
+    #[test]
+    fn hexagon_ray_intersect() {
+        let hexagon = Hexagon::new(
+            Vector2D::new(0.0, 0.0),
+            10.0,
+            HexagonOrientation::Horizontal,
+        );
+
+        // a pointy hexagon's side is its inradius away
+        let hit = hexagon
+            .ray_intersect(&Vector2D::new(20.0, 0.0), &Vector2D::new(-1.0, 0.0), 100.0)
+            .unwrap();
+        assert!((hit.distance() - (20.0 - 10.0 * 3.0_f64.sqrt() * 0.5)).abs() < 1e-12);
+        assert!((hit.normal() - Vector2D::new(1.0, 0.0)).length() < 1e-12);
+
+        let hit = hexagon
+            .ray_intersect(&Vector2D::new(0.0, 0.0), &Vector2D::new(0.0, 1.0), 100.0)
+            .unwrap();
+        assert!((hit.point() - Vector2D::new(0.0, 10.0)).length() < 1e-12);
+
+        for i in 0..100 {
+            let origin = Vector2D::new(i as f64 * 0.7 - 35.0, 25.0);
+            let direction = Vector2D::new((i as f64 * 0.3).sin(), -1.0);
+
+            let exact = hexagon.ray_intersect(&origin, &direction, 100.0);
+            let traced = sphere_trace(&hexagon, &origin, &direction.normalise(), 100.0);
+            match (exact, traced) {
+                (Some(exact), Some(traced)) => {
+                    assert!((exact.distance() - traced.distance()).abs() < 1e-9)
+                }
+                (exact, traced) => assert_eq!(exact, traced),
+            }
+        }
+    }
+
+    #[test]
+    fn hexagon_mass_properties() {
+        let hexagon = Hexagon::new(Vector2D::new(3.0, 4.0), 10.0, HexagonOrientation::Vertical);
+        let moments = hexagon.second_moment_of_area();
+
+        // 5 sqrt(3) / 16 of the side to the fourth about either axis
+        assert_eq!(hexagon.centroid(), Vector2D::new(3.0, 4.0));
+        assert!((moments.ixx() - 5.0 * 3.0_f64.sqrt() / 16.0 * 1e4).abs() < 1e-9);
+        assert!((moments.iyy() - moments.ixx()).abs() < 1e-9);
+        assert_eq!(moments.ixy(), 0.0);
+        assert_eq!(hexagon.moment_of_inertia(2.0), 2.0 * moments.polar());
+
+        let exact = AreaIntegrals::polygon(&hexagon.as_regular_polygon().vertices());
+        let estimate = estimate_area_integrals(&hexagon);
+        for (integrals, tolerance) in [(exact, 1e-12), (estimate, 1e-3)] {
+            let expected = integrals.second_moment_of_area();
+
+            assert!((integrals.centroid() - hexagon.centroid()).length() < 1e-6);
+            assert!((expected.ixx() - moments.ixx()).abs() / moments.ixx() < tolerance);
+            assert!((expected.iyy() - moments.iyy()).abs() / moments.iyy() < tolerance);
+            assert!(expected.ixy().abs() / moments.ixx() < tolerance);
+        }
+    }
 }
@@ -1,13 +1,63 @@
+use crate::arc::Arc;
+use crate::bezier::{BezierCurve, BezierPath, CubicBezier, QuadraticBezier};
+use crate::blend::{BlendKernel, SmoothIntersection, SmoothSubtraction, SmoothUnion};
+use crate::bounds::{BoundingBox, OrientedBoundingBox};
+use crate::capsule::Capsule;
 use crate::circle::Circle;
+use crate::csg::{Intersection, Subtraction, Union, Xor};
+use crate::decorative::{Cross, Egg, Heart, Moon, RoundedX, Star, Vesica};
+use crate::displace::{Displaced, GradientNoise};
+use crate::ellipse::Ellipse;
 use crate::grid_2d::Grid2D;
 use crate::hexagon::Hexagon;
+use crate::mass::SecondMomentOfArea;
+use crate::modifier::{Elongated, Mirrored, Onion, Rounded, Symmetric};
+use crate::morph::{Easing, Morph};
+use crate::pie::Pie;
+use crate::polygon::Polygon;
+use crate::polyline::Polyline;
+use crate::quadrilateral::{Parallelogram, Rhombus, Trapezoid};
+use crate::ray::{unit_direction, RayHit};
 use crate::rectangle::{Rectangle, RoundFactors};
+use crate::regular_polygon::RegularPolygon;
+use crate::repeat::{HexagonalRepeat, LimitedRepeat, PolarRepeat, Repeat};
+use crate::ring::Ring;
+use crate::segment::Segment;
+use crate::transform::{Affine2D, Transformed};
+use crate::triangle::{EquilateralTriangle, IsoscelesTriangle, Triangle};
 use vector2d::Vector2D;
 
+pub mod arc;
+pub mod bezier;
+pub mod blend;
+pub mod bounds;
+pub mod capsule;
 pub mod circle;
+pub mod collision;
+pub mod convex;
+pub mod csg;
+pub mod decorative;
+pub mod displace;
+pub mod ellipse;
 pub mod grid_2d;
 pub mod hexagon;
+pub mod mass;
+pub mod modifier;
+pub mod morph;
+pub mod motion;
+mod numeric;
+pub mod pie;
+pub mod polygon;
+pub mod polyline;
+pub mod quadrilateral;
+pub mod ray;
 pub mod rectangle;
+pub mod regular_polygon;
+pub mod repeat;
+pub mod ring;
+pub mod segment;
+pub mod transform;
+pub mod triangle;
 
 pub trait Shape {
     fn area(&self) -> f64;
@@ -19,6 +69,174 @@ pub trait Shape {
     /// The sign of the return value indicates whether the point is inside that surface (negative)
     /// or outside (positive). A return value of zero indicates the point is exactly on the surface.
     fn sdf(&self, point: &Vector2D<f64>) -> f64;
+
+    /// The smallest axis aligned box around the shape.
+    ///
+    /// By default this is found by probing the signed distance from far away, which is tight
+    /// for shapes whose distance is exact there; shapes that know their extent override it.
+    fn bounding_box(&self) -> BoundingBox {
+        numeric::estimate_bounds(self)
+    }
+
+    /// A box around the shape turned to fit it, for shapes with a rotation of their own.
+    /// Shapes without one get their axis aligned box.
+    fn oriented_bounding_box(&self) -> OrientedBoundingBox {
+        OrientedBoundingBox::from(self.bounding_box())
+    }
+
+    /// The smallest circle around the shape.
+    ///
+    /// By default this is found from where the outline crosses a sample grid over the shape's
+    /// bounding box, so it is accurate to a fraction of a sample.
+    fn bounding_circle(&self) -> Circle {
+        numeric::estimate_bounding_circle(self)
+    }
+
+    /// The largest circle that fits inside the shape, centered on its deepest point.
+    ///
+    /// By default this is found by searching for the lowest signed distance; as the radius is
+    /// that distance the circle always fits, though it may fall slightly short of the largest.
+    fn largest_inscribed_circle(&self) -> Circle {
+        numeric::estimate_inscribed_circle(self)
+    }
+
+    /// The gradient of the signed distance at `point`, pointing away from the shape. For an
+    /// exact distance it has unit length almost everywhere.
+    ///
+    /// By default this takes central differences, four extra calls to `sdf`; shapes that can
+    /// work it out directly override it.
+    fn gradient(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        numeric::estimate_gradient(self, point)
+    }
+
+    /// The outward unit normal at `point`, the direction of the gradient. Zero where the
+    /// gradient vanishes, as at the center of a circle.
+    fn normal(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        let gradient = self.gradient(point);
+        let length = gradient.length();
+
+        if length > 0.0 {
+            gradient / length
+        } else {
+            gradient
+        }
+    }
+
+    /// The nearest point on the shape's outline to `point`, inside or outside.
+    ///
+    /// By default this steps against the normal by the signed distance until that vanishes:
+    /// a single step for an exact distance, a few more for a bound.
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        numeric::project_onto_outline(self, point)
+    }
+
+    /// Where a ray from `origin` along `direction` first crosses the shape's outline within
+    /// `max_distance`, measured along the direction scaled to unit length. A ray starting
+    /// inside the shape hits where it leaves it.
+    ///
+    /// By default this sphere traces the signed distance from where the ray enters the shape's
+    /// bounding box, with a minimum step so that rays grazing the outline still get past it.
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        numeric::sphere_trace(self, origin, &unit_direction(direction), max_distance)
+    }
+
+    /// The center of the shape's area.
+    ///
+    /// By default this is integrated over the coverage of a sample grid on the shape's bounding
+    /// box, like the estimated area; shapes that can work it out exactly override it.
+    fn centroid(&self) -> Vector2D<f64> {
+        numeric::estimate_area_integrals(self).centroid()
+    }
+
+    /// The second moments of the shape's area about axes through its centroid, parallel to x
+    /// and y. Integrated numerically by default, like the centroid.
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        numeric::estimate_area_integrals(self).second_moment_of_area()
+    }
+
+    /// The second moment of area about the axis through the centroid out of the plane.
+    fn polar_moment_of_area(&self) -> f64 {
+        self.second_moment_of_area().polar()
+    }
+
+    /// The moment of inertia about the centroid of a flat body of uniform `density` (mass per
+    /// unit area) in the shape of this one.
+    fn moment_of_inertia(&self, density: f64) -> f64 {
+        density * self.polar_moment_of_area()
+    }
+}
+
+/// Lets the boxed shapes handed out by `ShapeFactory` go wherever a `Shape` is expected.
+impl<S: Shape + ?Sized> Shape for Box<S> {
+    fn area(&self) -> f64 {
+        (**self).area()
+    }
+
+    fn perimeter(&self) -> f64 {
+        (**self).perimeter()
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        (**self).sdf(point)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        (**self).bounding_box()
+    }
+
+    fn oriented_bounding_box(&self) -> OrientedBoundingBox {
+        (**self).oriented_bounding_box()
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        (**self).bounding_circle()
+    }
+
+    fn largest_inscribed_circle(&self) -> Circle {
+        (**self).largest_inscribed_circle()
+    }
+
+    fn gradient(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        (**self).gradient(point)
+    }
+
+    fn normal(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        (**self).normal(point)
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        (**self).closest_point(point)
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        (**self).ray_intersect(origin, direction, max_distance)
+    }
+
+    fn centroid(&self) -> Vector2D<f64> {
+        (**self).centroid()
+    }
+
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        (**self).second_moment_of_area()
+    }
+
+    fn polar_moment_of_area(&self) -> f64 {
+        (**self).polar_moment_of_area()
+    }
+
+    fn moment_of_inertia(&self, density: f64) -> f64 {
+        (**self).moment_of_inertia(density)
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -34,6 +252,52 @@ impl ShapeFactory {
         Box::new(Circle::new(center, radius))
     }
 
+    pub fn new_arc(
+        center: Vector2D<f64>,
+        radius: f64,
+        thickness: f64,
+        start_angle_in_degrees: f64,
+        sweep_angle_in_degrees: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Arc::new(
+            center,
+            radius,
+            thickness,
+            start_angle_in_degrees,
+            sweep_angle_in_degrees,
+        ))
+    }
+
+    pub fn new_ring(center: Vector2D<f64>, inner_radius: f64, outer_radius: f64) -> Box<dyn Shape> {
+        Box::new(Ring::new(center, inner_radius, outer_radius))
+    }
+
+    pub fn new_pie(
+        center: Vector2D<f64>,
+        radius: f64,
+        start_angle_in_degrees: f64,
+        sweep_angle_in_degrees: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Pie::new(
+            center,
+            radius,
+            start_angle_in_degrees,
+            sweep_angle_in_degrees,
+        ))
+    }
+
+    pub fn new_ellipse(center: Vector2D<f64>, radii: Vector2D<f64>) -> Box<dyn Shape> {
+        Box::new(Ellipse::new(center, radii, 0.0))
+    }
+
+    pub fn new_ellipse_oriented(
+        center: Vector2D<f64>,
+        radii: Vector2D<f64>,
+        rotation_angle_in_degrees: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Ellipse::new(center, radii, rotation_angle_in_degrees))
+    }
+
     pub fn new_hexagon_vertical(center: Vector2D<f64>, circumradius: f64) -> Box<dyn Shape> {
         Box::new(Hexagon::new(
             center,
@@ -50,6 +314,32 @@ impl ShapeFactory {
         ))
     }
 
+    pub fn new_polygon(vertices: Vec<Vector2D<f64>>) -> Box<dyn Shape> {
+        Box::new(Polygon::new(vertices))
+    }
+
+    pub fn new_regular_polygon(
+        center: Vector2D<f64>,
+        sides: usize,
+        circumradius: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(RegularPolygon::new(center, sides, circumradius, 0.0))
+    }
+
+    pub fn new_regular_polygon_oriented(
+        center: Vector2D<f64>,
+        sides: usize,
+        circumradius: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(RegularPolygon::new(
+            center,
+            sides,
+            circumradius,
+            rotation_angle_in_degrees,
+        ))
+    }
+
     pub fn new_rectangle(center: Vector2D<f64>, dimensions: Vector2D<f64>) -> Box<dyn Shape> {
         Box::new(Rectangle::new(center, dimensions, 0.0, Default::default()))
     }
@@ -88,6 +378,363 @@ impl ShapeFactory {
             round_factors,
         ))
     }
+
+    pub fn new_rhombus(center: Vector2D<f64>, diagonals: Vector2D<f64>) -> Box<dyn Shape> {
+        Box::new(Rhombus::new(center, diagonals, 0.0))
+    }
+
+    pub fn new_rhombus_oriented(
+        center: Vector2D<f64>,
+        diagonals: Vector2D<f64>,
+        rotation_angle_in_degrees: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Rhombus::new(center, diagonals, rotation_angle_in_degrees))
+    }
+
+    pub fn new_trapezoid(
+        center: Vector2D<f64>,
+        bottom_width: f64,
+        top_width: f64,
+        height: f64,
+        top_offset: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Trapezoid::new(
+            center,
+            bottom_width,
+            top_width,
+            height,
+            top_offset,
+            0.0,
+        ))
+    }
+
+    pub fn new_trapezoid_oriented(
+        center: Vector2D<f64>,
+        bottom_width: f64,
+        top_width: f64,
+        height: f64,
+        top_offset: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Trapezoid::new(
+            center,
+            bottom_width,
+            top_width,
+            height,
+            top_offset,
+            rotation_angle_in_degrees,
+        ))
+    }
+
+    pub fn new_trapezoid_isosceles(
+        center: Vector2D<f64>,
+        bottom_width: f64,
+        top_width: f64,
+        height: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Trapezoid::new_isosceles(
+            center,
+            bottom_width,
+            top_width,
+            height,
+            0.0,
+        ))
+    }
+
+    pub fn new_trapezoid_isosceles_oriented(
+        center: Vector2D<f64>,
+        bottom_width: f64,
+        top_width: f64,
+        height: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Trapezoid::new_isosceles(
+            center,
+            bottom_width,
+            top_width,
+            height,
+            rotation_angle_in_degrees,
+        ))
+    }
+
+    pub fn new_parallelogram(
+        center: Vector2D<f64>,
+        width: f64,
+        height: f64,
+        skew: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Parallelogram::new(center, width, height, skew, 0.0))
+    }
+
+    pub fn new_parallelogram_oriented(
+        center: Vector2D<f64>,
+        width: f64,
+        height: f64,
+        skew: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Parallelogram::new(
+            center,
+            width,
+            height,
+            skew,
+            rotation_angle_in_degrees,
+        ))
+    }
+
+    pub fn new_segment(start: Vector2D<f64>, end: Vector2D<f64>) -> Box<dyn Shape> {
+        Box::new(Segment::new(start, end))
+    }
+
+    pub fn new_capsule(start: Vector2D<f64>, end: Vector2D<f64>, radius: f64) -> Box<dyn Shape> {
+        Box::new(Capsule::new(start, end, radius, radius))
+    }
+
+    pub fn new_capsule_uneven(
+        start: Vector2D<f64>,
+        end: Vector2D<f64>,
+        start_radius: f64,
+        end_radius: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Capsule::new(start, end, start_radius, end_radius))
+    }
+
+    pub fn new_polyline(points: Vec<Vector2D<f64>>, thickness: f64) -> Box<dyn Shape> {
+        Box::new(Polyline::new(points, thickness))
+    }
+
+    pub fn new_quadratic_bezier(
+        start: Vector2D<f64>,
+        control: Vector2D<f64>,
+        end: Vector2D<f64>,
+        thickness: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(QuadraticBezier::new(start, control, end, thickness))
+    }
+
+    pub fn new_cubic_bezier(
+        start: Vector2D<f64>,
+        control1: Vector2D<f64>,
+        control2: Vector2D<f64>,
+        end: Vector2D<f64>,
+        thickness: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(CubicBezier::new(start, control1, control2, end, thickness))
+    }
+
+    pub fn new_bezier_path_stroke(curves: Vec<BezierCurve>, thickness: f64) -> Box<dyn Shape> {
+        Box::new(BezierPath::new_stroke(curves, thickness))
+    }
+
+    pub fn new_bezier_path_filled(curves: Vec<BezierCurve>) -> Box<dyn Shape> {
+        Box::new(BezierPath::new_filled(curves))
+    }
+
+    pub fn new_star(
+        center: Vector2D<f64>,
+        points: usize,
+        outer_radius: f64,
+        inner_radius: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Star::new(center, points, outer_radius, inner_radius, 0.0))
+    }
+
+    pub fn new_cross(center: Vector2D<f64>, arm_length: f64, arm_width: f64) -> Box<dyn Shape> {
+        Box::new(Cross::new(center, arm_length, arm_width, 0.0))
+    }
+
+    pub fn new_heart(center: Vector2D<f64>, size: f64) -> Box<dyn Shape> {
+        Box::new(Heart::new(center, size, 0.0))
+    }
+
+    pub fn new_moon(
+        center: Vector2D<f64>,
+        radius: f64,
+        cut_radius: f64,
+        cut_offset: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Moon::new(center, radius, cut_radius, cut_offset, 0.0))
+    }
+
+    pub fn new_vesica(center: Vector2D<f64>, radius: f64, distance: f64) -> Box<dyn Shape> {
+        Box::new(Vesica::new(center, radius, distance, 0.0))
+    }
+
+    pub fn new_egg(center: Vector2D<f64>, radius: f64, top_radius: f64) -> Box<dyn Shape> {
+        Box::new(Egg::new(center, radius, top_radius, 0.0))
+    }
+
+    pub fn new_rounded_x(center: Vector2D<f64>, width: f64, radius: f64) -> Box<dyn Shape> {
+        Box::new(RoundedX::new(center, width, radius, 0.0))
+    }
+
+    pub fn new_union(a: Box<dyn Shape>, b: Box<dyn Shape>) -> Box<dyn Shape> {
+        Box::new(Union::new(a, b))
+    }
+
+    pub fn new_intersection(a: Box<dyn Shape>, b: Box<dyn Shape>) -> Box<dyn Shape> {
+        Box::new(Intersection::new(a, b))
+    }
+
+    pub fn new_subtraction(shape: Box<dyn Shape>, subtracted: Box<dyn Shape>) -> Box<dyn Shape> {
+        Box::new(Subtraction::new(shape, subtracted))
+    }
+
+    pub fn new_xor(a: Box<dyn Shape>, b: Box<dyn Shape>) -> Box<dyn Shape> {
+        Box::new(Xor::new(a, b))
+    }
+
+    pub fn new_smooth_union(
+        a: Box<dyn Shape>,
+        b: Box<dyn Shape>,
+        blend_radius: f64,
+        kernel: BlendKernel,
+    ) -> Box<dyn Shape> {
+        Box::new(SmoothUnion::new(a, b, blend_radius, kernel))
+    }
+
+    pub fn new_smooth_intersection(
+        a: Box<dyn Shape>,
+        b: Box<dyn Shape>,
+        blend_radius: f64,
+        kernel: BlendKernel,
+    ) -> Box<dyn Shape> {
+        Box::new(SmoothIntersection::new(a, b, blend_radius, kernel))
+    }
+
+    pub fn new_smooth_subtraction(
+        shape: Box<dyn Shape>,
+        subtracted: Box<dyn Shape>,
+        blend_radius: f64,
+        kernel: BlendKernel,
+    ) -> Box<dyn Shape> {
+        Box::new(SmoothSubtraction::new(
+            shape,
+            subtracted,
+            blend_radius,
+            kernel,
+        ))
+    }
+
+    pub fn new_transformed(shape: Box<dyn Shape>, transform: Affine2D) -> Box<dyn Shape> {
+        Box::new(Transformed::new(shape, transform))
+    }
+
+    pub fn new_rounded(shape: Box<dyn Shape>, radius: f64) -> Box<dyn Shape> {
+        Box::new(Rounded::new(shape, radius))
+    }
+
+    pub fn new_onion(shape: Box<dyn Shape>, thickness: f64) -> Box<dyn Shape> {
+        Box::new(Onion::new(shape, thickness))
+    }
+
+    pub fn new_elongated(
+        shape: Box<dyn Shape>,
+        center: Vector2D<f64>,
+        elongation: Vector2D<f64>,
+    ) -> Box<dyn Shape> {
+        Box::new(Elongated::new(shape, center, elongation))
+    }
+
+    pub fn new_mirrored(
+        shape: Box<dyn Shape>,
+        axis_point: Vector2D<f64>,
+        axis_direction: Vector2D<f64>,
+    ) -> Box<dyn Shape> {
+        Box::new(Mirrored::new(shape, axis_point, axis_direction))
+    }
+
+    pub fn new_symmetric(
+        shape: Box<dyn Shape>,
+        axis_point: Vector2D<f64>,
+        axis_direction: Vector2D<f64>,
+    ) -> Box<dyn Shape> {
+        Box::new(Symmetric::new(shape, axis_point, axis_direction))
+    }
+
+    pub fn new_repeat(shape: Box<dyn Shape>, spacing: Vector2D<f64>) -> Box<dyn Shape> {
+        Box::new(Repeat::new(shape, spacing))
+    }
+
+    pub fn new_repeat_limited(
+        shape: Box<dyn Shape>,
+        spacing: Vector2D<f64>,
+        columns: usize,
+        rows: usize,
+    ) -> Box<dyn Shape> {
+        Box::new(LimitedRepeat::new(shape, spacing, columns, rows))
+    }
+
+    pub fn new_repeat_polar(
+        shape: Box<dyn Shape>,
+        center: Vector2D<f64>,
+        count: usize,
+    ) -> Box<dyn Shape> {
+        Box::new(PolarRepeat::new(shape, center, count))
+    }
+
+    pub fn new_repeat_hexagonal(shape: Box<dyn Shape>, spacing: f64) -> Box<dyn Shape> {
+        Box::new(HexagonalRepeat::new(shape, spacing))
+    }
+
+    pub fn new_morph(
+        from: Box<dyn Shape>,
+        to: Box<dyn Shape>,
+        t: f64,
+        easing: Easing,
+    ) -> Box<dyn Shape> {
+        Box::new(Morph::new(from, to, t, easing))
+    }
+
+    pub fn new_displaced(
+        shape: Box<dyn Shape>,
+        displacement: impl Fn(&Vector2D<f64>) -> f64 + 'static,
+        lipschitz_constant: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(Displaced::new(shape, displacement, lipschitz_constant))
+    }
+
+    pub fn new_displaced_noise(shape: Box<dyn Shape>, noise: GradientNoise) -> Box<dyn Shape> {
+        Box::new(Displaced::with_noise(shape, noise))
+    }
+
+    pub fn new_triangle(a: Vector2D<f64>, b: Vector2D<f64>, c: Vector2D<f64>) -> Box<dyn Shape> {
+        Box::new(Triangle::new(a, b, c))
+    }
+
+    pub fn new_triangle_isosceles(center: Vector2D<f64>, base: f64, height: f64) -> Box<dyn Shape> {
+        Box::new(IsoscelesTriangle::new(center, base, height, 0.0))
+    }
+
+    pub fn new_triangle_isosceles_oriented(
+        center: Vector2D<f64>,
+        base: f64,
+        height: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(IsoscelesTriangle::new(
+            center,
+            base,
+            height,
+            rotation_angle_in_degrees,
+        ))
+    }
+
+    pub fn new_triangle_equilateral(center: Vector2D<f64>, side_length: f64) -> Box<dyn Shape> {
+        Box::new(EquilateralTriangle::new(center, side_length, 0.0))
+    }
+
+    pub fn new_triangle_equilateral_oriented(
+        center: Vector2D<f64>,
+        side_length: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Box<dyn Shape> {
+        Box::new(EquilateralTriangle::new(
+            center,
+            side_length,
+            rotation_angle_in_degrees,
+        ))
+    }
 }
 
 fn abs_vector(v: &Vector2D<f64>) -> Vector2D<f64> {
@@ -106,6 +753,47 @@ fn dot_product(v1: &Vector2D<f64>, v2: &Vector2D<f64>) -> f64 {
     v1.x * v2.x + v1.y * v2.y
 }
 
+fn cross_product(v1: &Vector2D<f64>, v2: &Vector2D<f64>) -> f64 {
+    v1.x * v2.y - v1.y * v2.x
+}
+
+fn closest_point_on_segment(
+    p: &Vector2D<f64>,
+    a: &Vector2D<f64>,
+    b: &Vector2D<f64>,
+) -> Vector2D<f64> {
+    let ab = *b - *a;
+    let length_squared = dot_product(&ab, &ab);
+
+    if length_squared == 0.0 {
+        return *a;
+    }
+
+    let t = (dot_product(&(*p - *a), &ab) / length_squared).clamp(0.0, 1.0);
+
+    *a + ab * t
+}
+
+/// The nearest point to `p` on any of the `edges`.
+fn closest_point_on_edges(
+    p: &Vector2D<f64>,
+    edges: impl IntoIterator<Item = (Vector2D<f64>, Vector2D<f64>)>,
+) -> Vector2D<f64> {
+    edges
+        .into_iter()
+        .map(|(a, b)| closest_point_on_segment(p, &a, &b))
+        .fold((*p, f64::MAX), |nearest, closest| {
+            let distance_squared = (*p - closest).length_squared();
+
+            if distance_squared < nearest.1 {
+                (closest, distance_squared)
+            } else {
+                nearest
+            }
+        })
+        .0
+}
+
 fn rotate_vector_by_degrees(v: &Vector2D<f64>, degrees: f64) -> Vector2D<f64> {
     let radians = degrees.to_radians();
 
@@ -119,11 +807,29 @@ fn rotate_vector_by_radians(v: &Vector2D<f64>, radians: f64) -> Vector2D<f64> {
     Vector2D::new(v.x * cosine - v.y * sine, v.x * sine + v.y * cosine)
 }
 
-fn rotate_vector_by_30_degrees(v: &Vector2D<f64>) -> Vector2D<f64> {
-    Vector2D::new(
-        v.x * 0.86602540378 - v.y * 0.5,
-        v.x * 0.5 + v.y * 0.86602540378,
-    )
+/// `point` in the frame of a shape centered on `center` and turned by `rotation_angle_in_degrees`.
+fn to_local(
+    point: &Vector2D<f64>,
+    center: Vector2D<f64>,
+    rotation_angle_in_degrees: f64,
+) -> Vector2D<f64> {
+    // translate to center the shape at origin
+    let translated = *point - center;
+
+    if rotation_angle_in_degrees != 0.0 {
+        rotate_vector_by_degrees(&translated, rotation_angle_in_degrees)
+    } else {
+        translated
+    }
+}
+
+/// `vertices` given in a shape's own frame, put back where the shape is.
+fn to_world<const N: usize>(
+    vertices: [Vector2D<f64>; N],
+    center: Vector2D<f64>,
+    rotation_angle_in_degrees: f64,
+) -> [Vector2D<f64>; N] {
+    vertices.map(|v| rotate_vector_by_degrees(&v, -rotation_angle_in_degrees) + center)
 }
 
 fn min_f64(v1: f64, v2: f64) -> f64 {
@@ -147,7 +853,7 @@ pub fn get_sdf_grid<T: Shape>(t: &T, width: usize, height: usize) -> Grid2D {
 
     for row in 0..height {
         for column in 0..width {
-            let p: Vector2D<f64> = Vector2D::new(column as f64, (height as usize - 1 - row) as f64);
+            let p: Vector2D<f64> = Vector2D::new(column as f64, (height - 1 - row) as f64);
             let sdf = t.sdf(&p);
             grid.set_value(column, row, sdf);
         }
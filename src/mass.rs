@@ -0,0 +1,322 @@
+use crate::{cross_product, rotate_vector_by_degrees};
+use std::f64::consts::PI;
+use std::ops::{Add, Sub};
+use vector2d::Vector2D;
+
+/// Second moments of area about axes through a shape's centroid, parallel to x and y: `ixx` is
+/// the integral of y squared over the area, `iyy` that of x squared, and the product of area
+/// `ixy` that of x times y.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SecondMomentOfArea {
+    ixx: f64,
+    iyy: f64,
+    ixy: f64,
+}
+
+impl SecondMomentOfArea {
+    pub fn new(ixx: f64, iyy: f64, ixy: f64) -> Self {
+        SecondMomentOfArea { ixx, iyy, ixy }
+    }
+
+    pub fn ixx(&self) -> f64 {
+        self.ixx
+    }
+
+    pub fn iyy(&self) -> f64 {
+        self.iyy
+    }
+
+    pub fn ixy(&self) -> f64 {
+        self.ixy
+    }
+
+    /// The polar moment of area, about the axis through the centroid out of the plane.
+    pub fn polar(&self) -> f64 {
+        self.ixx + self.iyy
+    }
+
+    /// The moments of the same area turned about its centroid: clockwise for positive angles,
+    /// like the shapes' rotation_angle_in_degrees.
+    pub fn rotated_by_degrees(&self, degrees: f64) -> Self {
+        self.transformed(
+            &rotate_vector_by_degrees(&Vector2D::new(1.0, 0.0), -degrees),
+            &rotate_vector_by_degrees(&Vector2D::new(0.0, 1.0), -degrees),
+        )
+    }
+
+    /// The moments of the area mapped by the linear map that takes x and y to `x_axis` and
+    /// `y_axis`, which also scales the area by its determinant.
+    pub(crate) fn transformed(&self, x_axis: &Vector2D<f64>, y_axis: &Vector2D<f64>) -> Self {
+        // x x, x y and y y integrals change like a covariance, L C L^T
+        let (a, b, c, d) = (x_axis.x, y_axis.x, x_axis.y, y_axis.y);
+        let (xx, xy, yy) = (self.iyy, self.ixy, self.ixx);
+        let scale = cross_product(x_axis, y_axis).abs();
+
+        SecondMomentOfArea::new(
+            scale * (c * c * xx + 2.0 * c * d * xy + d * d * yy),
+            scale * (a * a * xx + 2.0 * a * b * xy + b * b * yy),
+            scale * (a * c * xx + (a * d + b * c) * xy + b * d * yy),
+        )
+    }
+}
+
+/// The integrals of 1, x, y, x squared, y squared and x y over an area, about the origin.
+/// Adding and taking away pieces builds up those of a compound area.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct AreaIntegrals {
+    area: f64,
+    first: Vector2D<f64>, // of x and y
+    x_squared: f64,
+    y_squared: f64,
+    xy: f64,
+}
+
+impl AreaIntegrals {
+    pub(crate) fn new(
+        area: f64,
+        first: Vector2D<f64>,
+        x_squared: f64,
+        y_squared: f64,
+        xy: f64,
+    ) -> Self {
+        AreaIntegrals {
+            area,
+            first,
+            x_squared,
+            y_squared,
+            xy,
+        }
+    }
+
+    /// An axis aligned rectangle.
+    pub(crate) fn rectangle(center: Vector2D<f64>, dimensions: Vector2D<f64>) -> Self {
+        let area = dimensions.x * dimensions.y;
+
+        AreaIntegrals::new(
+            area,
+            Vector2D::new(0.0, 0.0),
+            area * dimensions.x * dimensions.x / 12.0,
+            area * dimensions.y * dimensions.y / 12.0,
+            0.0,
+        )
+        .translated(&center)
+    }
+
+    /// The quarter of a disc whose x and y lie on the sides given by the signs of `quadrant`.
+    pub(crate) fn quarter_disc(
+        center: Vector2D<f64>,
+        radius: f64,
+        quadrant: Vector2D<f64>,
+    ) -> Self {
+        let (sx, sy) = (quadrant.x.signum(), quadrant.y.signum());
+        let r_cubed = radius * radius * radius;
+
+        AreaIntegrals::new(
+            PI * radius * radius / 4.0,
+            Vector2D::new(sx, sy) * (r_cubed / 3.0),
+            PI * r_cubed * radius / 16.0,
+            PI * r_cubed * radius / 16.0,
+            sx * sy * r_cubed * radius / 8.0,
+        )
+        .translated(&center)
+    }
+
+    /// A simple polygon, by Green's theorem along its edges; either winding gives the same.
+    pub(crate) fn polygon(vertices: &[Vector2D<f64>]) -> Self {
+        // about the first vertex, to keep the products small
+        let origin = vertices[0];
+        let mut integrals = AreaIntegrals::new(0.0, Vector2D::new(0.0, 0.0), 0.0, 0.0, 0.0);
+
+        for i in 0..vertices.len() {
+            let p = vertices[i] - origin;
+            let q = vertices[(i + 1) % vertices.len()] - origin;
+            let cross = cross_product(&p, &q);
+
+            integrals = integrals
+                + AreaIntegrals::new(
+                    cross / 2.0,
+                    (p + q) * (cross / 6.0),
+                    (p.x * p.x + p.x * q.x + q.x * q.x) * cross / 12.0,
+                    (p.y * p.y + p.y * q.y + q.y * q.y) * cross / 12.0,
+                    (p.x * q.y + 2.0 * p.x * p.y + 2.0 * q.x * q.y + q.x * p.y) * cross / 24.0,
+                );
+        }
+
+        if integrals.area < 0.0 {
+            integrals = AreaIntegrals::new(0.0, Vector2D::new(0.0, 0.0), 0.0, 0.0, 0.0) - integrals;
+        }
+
+        integrals.translated(&origin)
+    }
+
+    /// The same area moved by `offset`.
+    pub(crate) fn translated(&self, offset: &Vector2D<f64>) -> Self {
+        let (o, first, area) = (*offset, self.first, self.area);
+
+        AreaIntegrals::new(
+            area,
+            first + o * area,
+            self.x_squared + 2.0 * o.x * first.x + o.x * o.x * area,
+            self.y_squared + 2.0 * o.y * first.y + o.y * o.y * area,
+            self.xy + o.x * first.y + o.y * first.x + o.x * o.y * area,
+        )
+    }
+
+    /// The integrals weighted by `factor`, as for an area only partly covered.
+    pub(crate) fn scaled(&self, factor: f64) -> Self {
+        AreaIntegrals::new(
+            self.area * factor,
+            self.first * factor,
+            self.x_squared * factor,
+            self.y_squared * factor,
+            self.xy * factor,
+        )
+    }
+
+    pub(crate) fn centroid(&self) -> Vector2D<f64> {
+        self.first / self.area
+    }
+
+    /// About axes through the centroid.
+    pub(crate) fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        let centroid = self.centroid();
+
+        SecondMomentOfArea::new(
+            self.y_squared - self.area * centroid.y * centroid.y,
+            self.x_squared - self.area * centroid.x * centroid.x,
+            self.xy - self.area * centroid.x * centroid.y,
+        )
+    }
+}
+
+impl Add for AreaIntegrals {
+    type Output = AreaIntegrals;
+
+    fn add(self, other: AreaIntegrals) -> AreaIntegrals {
+        AreaIntegrals::new(
+            self.area + other.area,
+            self.first + other.first,
+            self.x_squared + other.x_squared,
+            self.y_squared + other.y_squared,
+            self.xy + other.xy,
+        )
+    }
+}
+
+impl Sub for AreaIntegrals {
+    type Output = AreaIntegrals;
+
+    fn sub(self, other: AreaIntegrals) -> AreaIntegrals {
+        AreaIntegrals::new(
+            self.area - other.area,
+            self.first - other.first,
+            self.x_squared - other.x_squared,
+            self.y_squared - other.y_squared,
+            self.xy - other.xy,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mass::{AreaIntegrals, SecondMomentOfArea};
+    use vector2d::Vector2D;
+
+    fn assert_moments(moments: SecondMomentOfArea, expected: SecondMomentOfArea) {
+        let scale = expected.polar().abs().max(1.0);
+
+        assert!(
+            (moments.ixx() - expected.ixx()).abs() / scale < 1e-12
+                && (moments.iyy() - expected.iyy()).abs() / scale < 1e-12
+                && (moments.ixy() - expected.ixy()).abs() / scale < 1e-12,
+            "{moments:?} should be {expected:?}"
+        );
+    }
+
+    #[test]
+    fn create_second_moment_of_area() {
+        let moments = SecondMomentOfArea::new(2.0, 3.0, -1.0);
+
+        assert_eq!(
+            format!("The moments are: {moments:?}"),
+            "The moments are: SecondMomentOfArea { ixx: 2.0, iyy: 3.0, ixy: -1.0 }"
+        );
+
+        assert_eq!(moments.ixx(), 2.0);
+        assert_eq!(moments.iyy(), 3.0);
+        assert_eq!(moments.ixy(), -1.0);
+        assert_eq!(moments.polar(), 5.0);
+    }
+
+    #[test]
+    fn rotated_second_moment_of_area() {
+        // a 6 by 2 rectangle: turned a quarter, its long side runs along y
+        let flat = SecondMomentOfArea::new(12.0 * 8.0 / 12.0, 12.0 * 216.0 / 12.0, 0.0);
+        assert_moments(
+            flat.rotated_by_degrees(90.0),
+            SecondMomentOfArea::new(flat.iyy(), flat.ixx(), 0.0),
+        );
+
+        // turned an eighth clockwise, more of it lies where x and y have opposite signs
+        let turned = flat.rotated_by_degrees(45.0);
+        assert!((turned.polar() - flat.polar()).abs() < 1e-12);
+        assert!((turned.ixy() + (flat.iyy() - flat.ixx()) / 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn area_integrals() {
+        let rectangle = AreaIntegrals::rectangle(Vector2D::new(3.0, 1.0), Vector2D::new(6.0, 2.0));
+        let polygon = AreaIntegrals::polygon(&[
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(6.0, 0.0),
+            Vector2D::new(6.0, 2.0),
+            Vector2D::new(0.0, 2.0),
+        ]);
+        assert_eq!(rectangle.area, 12.0);
+        assert_eq!(rectangle.centroid(), Vector2D::new(3.0, 1.0));
+        assert!((polygon.area - 12.0).abs() < 1e-12);
+        assert!((polygon.centroid() - Vector2D::new(3.0, 1.0)).length() < 1e-12);
+        assert_moments(
+            polygon.second_moment_of_area(),
+            SecondMomentOfArea::new(4.0, 36.0, 0.0),
+        );
+
+        // clockwise gives the same
+        let clockwise = AreaIntegrals::polygon(&[
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(0.0, 2.0),
+            Vector2D::new(6.0, 2.0),
+            Vector2D::new(6.0, 0.0),
+        ]);
+        assert!((clockwise.area - 12.0).abs() < 1e-12);
+
+        // four quarters make a disc
+        let center = Vector2D::new(1.0, -2.0);
+        let disc = [(1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0), (1.0, -1.0)]
+            .map(|(x, y)| AreaIntegrals::quarter_disc(center, 2.0, Vector2D::new(x, y)))
+            .into_iter()
+            .reduce(|a, b| a + b)
+            .unwrap();
+        assert!((disc.centroid() - center).length() < 1e-12);
+        assert_moments(
+            disc.second_moment_of_area(),
+            SecondMomentOfArea::new(4.0 * std::f64::consts::PI, 4.0 * std::f64::consts::PI, 0.0),
+        );
+
+        // a right triangle's product of area about its centroid is -b^2 h^2 / 72
+        let triangle = AreaIntegrals::polygon(&[
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(6.0, 0.0),
+            Vector2D::new(0.0, 3.0),
+        ]);
+        assert!((triangle.centroid() - Vector2D::new(2.0, 1.0)).length() < 1e-12);
+        assert_moments(
+            triangle.second_moment_of_area(),
+            SecondMomentOfArea::new(6.0 * 27.0 / 36.0, 216.0 * 3.0 / 36.0, -36.0 * 9.0 / 72.0),
+        );
+        assert_moments(
+            (rectangle - triangle + triangle).second_moment_of_area(),
+            rectangle.second_moment_of_area(),
+        );
+    }
+}
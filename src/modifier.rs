@@ -0,0 +1,450 @@
+use crate::bounds::BoundingBox;
+use crate::circle::Circle;
+use crate::numeric::{estimate_area, estimate_perimeter};
+use crate::{dot_product, Shape};
+use vector2d::Vector2D;
+
+/// Any shape grown outwards by `radius`, which rounds off all of its corners.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rounded<S: Shape> {
+    shape: S,
+    radius: f64,
+}
+
+impl<S: Shape> Rounded<S> {
+    pub fn new(shape: S, radius: f64) -> Self {
+        if radius < 0.0 {
+            panic!("Rounding radius must not be negative.");
+        }
+
+        Rounded { shape, radius }
+    }
+
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+}
+
+impl<S: Shape> Shape for Rounded<S> {
+    fn area(&self) -> f64 {
+        estimate_area(self)
+    }
+
+    fn perimeter(&self) -> f64 {
+        estimate_perimeter(self)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        self.shape.sdf(point) - self.radius
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        self.shape.bounding_box().expanded(self.radius)
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        let circle = self.shape.bounding_circle();
+
+        Circle::new(circle.center(), circle.radius() + self.radius)
+    }
+
+    fn largest_inscribed_circle(&self) -> Circle {
+        let circle = self.shape.largest_inscribed_circle();
+
+        Circle::new(circle.center(), circle.radius() + self.radius)
+    }
+
+    fn gradient(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        self.shape.gradient(point)
+    }
+}
+
+/// The outline of any shape drawn as a band of `thickness`, centered on the outline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Onion<S: Shape> {
+    shape: S,
+    thickness: f64,
+}
+
+impl<S: Shape> Onion<S> {
+    pub fn new(shape: S, thickness: f64) -> Self {
+        if thickness <= 0.0 {
+            panic!("Onion thickness must be greater than zero.");
+        }
+
+        Onion { shape, thickness }
+    }
+
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    pub fn thickness(&self) -> f64 {
+        self.thickness
+    }
+}
+
+impl<S: Shape> Shape for Onion<S> {
+    fn area(&self) -> f64 {
+        estimate_area(self)
+    }
+
+    fn perimeter(&self) -> f64 {
+        estimate_perimeter(self)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        self.shape.sdf(point).abs() - self.thickness * 0.5
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        self.shape.bounding_box().expanded(self.thickness * 0.5)
+    }
+}
+
+/// Any shape cut along the horizontal and vertical lines through `center`, with its halves
+/// pulled apart by `elongation` and the gaps bridged. A circle becomes a capsule or a
+/// rounded rectangle.
+///
+/// The distance is exact outside; inside the bridged band it is a bound.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Elongated<S: Shape> {
+    shape: S,
+    center: Vector2D<f64>,
+    elongation: Vector2D<f64>, // total stretch along x and y
+}
+
+impl<S: Shape> Elongated<S> {
+    pub fn new(shape: S, center: Vector2D<f64>, elongation: Vector2D<f64>) -> Self {
+        if elongation.x < 0.0 || elongation.y < 0.0 {
+            panic!("Elongation must not be negative.");
+        }
+
+        Elongated {
+            shape,
+            center,
+            elongation,
+        }
+    }
+
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn elongation(&self) -> Vector2D<f64> {
+        self.elongation
+    }
+}
+
+impl<S: Shape> Shape for Elongated<S> {
+    fn area(&self) -> f64 {
+        estimate_area(self)
+    }
+
+    fn perimeter(&self) -> f64 {
+        estimate_perimeter(self)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let half = self.elongation * 0.5;
+        let translated = *point - self.center;
+        let squeezed = Vector2D::new(
+            translated.x - translated.x.clamp(-half.x, half.x),
+            translated.y - translated.y.clamp(-half.y, half.y),
+        );
+
+        self.shape.sdf(&(squeezed + self.center))
+    }
+}
+
+/// The mirror image of any shape across the line through `axis_point` along
+/// `axis_direction`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mirrored<S: Shape> {
+    shape: S,
+    axis_point: Vector2D<f64>,
+    axis_direction: Vector2D<f64>,
+}
+
+impl<S: Shape> Mirrored<S> {
+    pub fn new(shape: S, axis_point: Vector2D<f64>, axis_direction: Vector2D<f64>) -> Self {
+        if axis_direction.length_squared() == 0.0 {
+            panic!("Mirror axis direction must not be zero.");
+        }
+
+        Mirrored {
+            shape,
+            axis_point,
+            axis_direction: axis_direction.normalise(),
+        }
+    }
+
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    pub fn axis_point(&self) -> Vector2D<f64> {
+        self.axis_point
+    }
+
+    pub fn axis_direction(&self) -> Vector2D<f64> {
+        self.axis_direction
+    }
+}
+
+impl<S: Shape> Shape for Mirrored<S> {
+    fn area(&self) -> f64 {
+        self.shape.area()
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.shape.perimeter()
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let (along, across) = axis_coordinates(point, self.axis_point, self.axis_direction);
+
+        self.shape.sdf(&from_axis_coordinates(
+            along,
+            -across,
+            self.axis_point,
+            self.axis_direction,
+        ))
+    }
+}
+
+/// Any shape made symmetric across the line through `axis_point` along `axis_direction`:
+/// whatever lies to the left of the line (looking along it) is kept and mirrored onto the
+/// right, whatever lay on the right is dropped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Symmetric<S: Shape> {
+    shape: S,
+    axis_point: Vector2D<f64>,
+    axis_direction: Vector2D<f64>,
+}
+
+impl<S: Shape> Symmetric<S> {
+    pub fn new(shape: S, axis_point: Vector2D<f64>, axis_direction: Vector2D<f64>) -> Self {
+        if axis_direction.length_squared() == 0.0 {
+            panic!("Symmetry axis direction must not be zero.");
+        }
+
+        Symmetric {
+            shape,
+            axis_point,
+            axis_direction: axis_direction.normalise(),
+        }
+    }
+
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    pub fn axis_point(&self) -> Vector2D<f64> {
+        self.axis_point
+    }
+
+    pub fn axis_direction(&self) -> Vector2D<f64> {
+        self.axis_direction
+    }
+}
+
+impl<S: Shape> Shape for Symmetric<S> {
+    fn area(&self) -> f64 {
+        estimate_area(self)
+    }
+
+    fn perimeter(&self) -> f64 {
+        estimate_perimeter(self)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let (along, across) = axis_coordinates(point, self.axis_point, self.axis_direction);
+
+        self.shape.sdf(&from_axis_coordinates(
+            along,
+            across.abs(),
+            self.axis_point,
+            self.axis_direction,
+        ))
+    }
+}
+
+/// Distance of `point` along the axis from `axis_point`, and across it (positive to the left).
+fn axis_coordinates(
+    point: &Vector2D<f64>,
+    axis_point: Vector2D<f64>,
+    axis_direction: Vector2D<f64>,
+) -> (f64, f64) {
+    let translated = *point - axis_point;
+    let normal = Vector2D::new(-axis_direction.y, axis_direction.x);
+
+    (
+        dot_product(&translated, &axis_direction),
+        dot_product(&translated, &normal),
+    )
+}
+
+fn from_axis_coordinates(
+    along: f64,
+    across: f64,
+    axis_point: Vector2D<f64>,
+    axis_direction: Vector2D<f64>,
+) -> Vector2D<f64> {
+    let normal = Vector2D::new(-axis_direction.y, axis_direction.x);
+
+    axis_point + axis_direction * along + normal * across
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::modifier::{Elongated, Mirrored, Onion, Rounded, Symmetric};
+    use crate::rectangle::Rectangle;
+    use crate::triangle::Triangle;
+    use crate::{get_sdf, Hexagon, HexagonOrientation, Shape, ShapeFactory};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    fn square() -> Rectangle {
+        Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(2.0, 2.0),
+            0.0,
+            Default::default(),
+        )
+    }
+
+    fn hexagon() -> Hexagon {
+        Hexagon::new(
+            Vector2D::new(0.0, 0.0),
+            10.0,
+            HexagonOrientation::Horizontal,
+        )
+    }
+
+    #[test]
+    fn create_rounded() {
+        let rounded = Rounded::new(square(), 1.0);
+
+        assert_eq!(
+            format!("The shape is: {rounded:?}"),
+            "The shape is: Rounded { shape: Rectangle { center: Vector2D { x: 0.0, y: 0.0 }, dimensions: Vector2D { x: 2.0, y: 2.0 }, rotation_angle_in_degrees: 0.0, round_factors: RoundFactors { top_left: 0.0, top_right: 0.0, bottom_left: 0.0, bottom_right: 0.0 } }, radius: 1.0 }"
+        );
+
+        assert_eq!(rounded.radius(), 1.0);
+        // a square grown by r: its own area, a strip of width r along each side and a quarter
+        // disc at each corner
+        assert!((rounded.area() - (4.0 + 8.0 + PI)).abs() < 0.01);
+        assert!((rounded.perimeter() - (8.0 + 2.0 * PI)).abs() < 0.01);
+    }
+
+    #[test]
+    fn rounded_sdf() {
+        let rounded = Rounded::new(hexagon(), 2.0);
+
+        assert_eq!(
+            get_sdf(&rounded, &Vector2D::new(0.0, 0.0)),
+            -10.660254037844387
+        );
+        // a vertex of the hexagon now sits inside a circular corner
+        assert!(rounded.sdf(&Vector2D::new(0.0, 12.0)).abs() < 1e-12);
+        assert!(rounded == Rounded::new(hexagon(), 2.0));
+        assert!(rounded != Rounded::new(hexagon(), 1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "must not be negative")]
+    fn rounded_needs_a_positive_radius() {
+        Rounded::new(square(), -1.0);
+    }
+
+    #[test]
+    fn onion_sdf() {
+        let onion = Onion::new(ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0), 2.0);
+
+        assert_eq!(onion.thickness(), 2.0);
+        assert_eq!(get_sdf(&onion, &Vector2D::new(0.0, 0.0)), 9.0);
+        assert_eq!(onion.sdf(&Vector2D::new(10.0, 0.0)), -1.0);
+        assert_eq!(onion.sdf(&Vector2D::new(0.0, 13.0)), 2.0);
+
+        // the band runs from radius 9 to radius 11
+        let expected_area = PI * (121.0 - 81.0);
+        assert!((onion.area() - expected_area).abs() / expected_area < 1e-3);
+        assert!((onion.perimeter() - 40.0 * PI).abs() / (40.0 * PI) < 1e-3);
+
+        // onions can be layered
+        let twice = Onion::new(onion, 0.5);
+        assert_eq!(twice.sdf(&Vector2D::new(0.0, 9.0)), -0.25);
+        assert_eq!(twice.sdf(&Vector2D::new(0.0, 10.0)), 0.75);
+    }
+
+    #[test]
+    fn elongated_sdf() {
+        let circle = ShapeFactory::new_circle(Vector2D::new(5.0, 5.0), 1.0);
+        let capsule = Elongated::new(circle, Vector2D::new(5.0, 5.0), Vector2D::new(4.0, 0.0));
+
+        assert_eq!(capsule.center(), Vector2D::new(5.0, 5.0));
+        assert_eq!(capsule.elongation(), Vector2D::new(4.0, 0.0));
+        assert_eq!(get_sdf(&capsule, &Vector2D::new(5.0, 5.0)), -1.0);
+        assert_eq!(capsule.sdf(&Vector2D::new(8.0, 5.0)), 0.0);
+        assert_eq!(capsule.sdf(&Vector2D::new(10.0, 5.0)), 2.0);
+        assert_eq!(capsule.sdf(&Vector2D::new(6.0, 7.0)), 1.0);
+
+        let expected_area = PI + 8.0;
+        assert!((capsule.area() - expected_area).abs() / expected_area < 1e-3);
+        assert!((capsule.perimeter() - (2.0 * PI + 8.0)).abs() < 0.01);
+    }
+
+    #[test]
+    fn mirrored_sdf() {
+        let triangle = Triangle::new(
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(3.0, 0.0),
+            Vector2D::new(1.0, 2.0),
+        );
+        let mirrored = Mirrored::new(triangle, Vector2D::new(0.0, 0.0), Vector2D::new(0.0, 2.0));
+
+        assert_eq!(mirrored.axis_direction(), Vector2D::new(0.0, 1.0));
+        assert_eq!(mirrored.area(), 2.0);
+        assert_eq!(
+            get_sdf(&mirrored, &Vector2D::new(-2.0, 0.5)),
+            triangle.sdf(&Vector2D::new(2.0, 0.5))
+        );
+        assert!(mirrored.sdf(&Vector2D::new(2.0, 0.5)) > 0.0);
+    }
+
+    #[test]
+    fn symmetric_sdf() {
+        // a square straddling the y axis, folded onto its left half
+        let rectangle = Rectangle::new(
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(4.0, 2.0),
+            0.0,
+            Default::default(),
+        );
+        let symmetric = Symmetric::new(rectangle, Vector2D::new(0.0, 0.0), Vector2D::new(0.0, 1.0));
+
+        assert_eq!(symmetric.axis_point(), Vector2D::new(0.0, 0.0));
+        assert_eq!(get_sdf(&symmetric, &Vector2D::new(-1.0, 0.0)), 0.0);
+        assert_eq!(symmetric.sdf(&Vector2D::new(1.0, 0.0)), 0.0);
+        assert_eq!(symmetric.sdf(&Vector2D::new(2.0, 0.0)), 1.0);
+        assert_eq!(symmetric.sdf(&Vector2D::new(0.0, 0.0)), -1.0);
+        assert!((symmetric.area() - 4.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn modifiers_through_the_factory() {
+        let hexagon = ShapeFactory::new_hexagon_horizontal(Vector2D::new(0.0, 0.0), 10.0);
+        let shape = ShapeFactory::new_onion(ShapeFactory::new_rounded(hexagon, 2.0), 1.0);
+
+        assert!((shape.sdf(&Vector2D::new(0.0, 12.0)) + 0.5).abs() < 1e-12);
+    }
+}
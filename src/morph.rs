@@ -0,0 +1,318 @@
+use crate::numeric::{estimate_area, estimate_perimeter};
+use crate::Shape;
+use vector2d::Vector2D;
+
+/// How the morph parameter is mapped before the two distances are blended.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Easing {
+    #[default]
+    Linear,
+    /// Starts slowly and speeds up (quadratic).
+    EaseIn,
+    /// Starts quickly and slows down (quadratic).
+    EaseOut,
+    /// Slow at both ends (smoothstep).
+    EaseInOut,
+}
+
+impl Easing {
+    /// Maps `t`, clamped to 0..=1, onto 0..=1.
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::EaseInOut => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+/// What happens once an animation has run for its duration.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Playback {
+    /// Stops at the end.
+    #[default]
+    Once,
+    /// Jumps back to the start and runs again.
+    Loop,
+    /// Runs backwards to the start, then forwards again.
+    PingPong,
+}
+
+/// Turns elapsed time into a morph parameter.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Animation {
+    duration_in_seconds: f64,
+    playback: Playback,
+}
+
+impl Animation {
+    pub fn new(duration_in_seconds: f64, playback: Playback) -> Self {
+        if duration_in_seconds <= 0.0 {
+            panic!("Animation duration must be greater than zero.");
+        }
+
+        Animation {
+            duration_in_seconds,
+            playback,
+        }
+    }
+
+    pub fn duration_in_seconds(&self) -> f64 {
+        self.duration_in_seconds
+    }
+
+    pub fn playback(&self) -> Playback {
+        self.playback
+    }
+
+    /// The parameter (0..=1) after `elapsed_seconds`; times before the start give 0.
+    pub fn progress(&self, elapsed_seconds: f64) -> f64 {
+        let cycles = elapsed_seconds.max(0.0) / self.duration_in_seconds;
+
+        match self.playback {
+            Playback::Once => cycles.min(1.0),
+            Playback::Loop => cycles.fract(),
+            Playback::PingPong => {
+                let phase = cycles % 2.0;
+
+                if phase > 1.0 {
+                    2.0 - phase
+                } else {
+                    phase
+                }
+            }
+        }
+    }
+}
+
+/// A shape part way between two others: its signed distance is `from`'s blended into `to`'s
+/// by the eased parameter `t`, 0 being `from` and 1 being `to`.
+///
+/// Blending two distances gives a bound rather than an exact distance part way through.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Morph<A: Shape, B: Shape> {
+    from: A,
+    to: B,
+    t: f64,
+    easing: Easing,
+}
+
+impl<A: Shape, B: Shape> Morph<A, B> {
+    /// `t` is clamped to 0..=1.
+    pub fn new(from: A, to: B, t: f64, easing: Easing) -> Self {
+        Morph {
+            from,
+            to,
+            t: t.clamp(0.0, 1.0),
+            easing,
+        }
+    }
+
+    pub fn from(&self) -> &A {
+        &self.from
+    }
+
+    pub fn to(&self) -> &B {
+        &self.to
+    }
+
+    pub fn t(&self) -> f64 {
+        self.t
+    }
+
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// The weight given to `to`'s distance.
+    pub fn eased_t(&self) -> f64 {
+        self.easing.apply(self.t)
+    }
+
+    /// `t` is clamped to 0..=1.
+    pub fn set_t(&mut self, t: f64) {
+        self.t = t.clamp(0.0, 1.0);
+    }
+
+    /// Moves `t` to where `animation` is after `elapsed_seconds`.
+    pub fn animate(&mut self, animation: &Animation, elapsed_seconds: f64) {
+        self.set_t(animation.progress(elapsed_seconds));
+    }
+}
+
+impl<A: Shape, B: Shape> Shape for Morph<A, B> {
+    fn area(&self) -> f64 {
+        let t = self.eased_t();
+
+        if t == 0.0 {
+            self.from.area()
+        } else if t == 1.0 {
+            self.to.area()
+        } else {
+            estimate_area(self)
+        }
+    }
+
+    fn perimeter(&self) -> f64 {
+        let t = self.eased_t();
+
+        if t == 0.0 {
+            self.from.perimeter()
+        } else if t == 1.0 {
+            self.to.perimeter()
+        } else {
+            estimate_perimeter(self)
+        }
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let t = self.eased_t();
+
+        self.from.sdf(point) * (1.0 - t) + self.to.sdf(point) * t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::circle::Circle;
+    use crate::morph::{Animation, Easing, Morph, Playback};
+    use crate::rectangle::{Rectangle, RoundFactors};
+    use crate::{get_sdf, Shape, ShapeFactory};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    fn circle() -> Circle {
+        Circle::new(Vector2D::new(0.0, 0.0), 10.0)
+    }
+
+    fn rounded_rectangle() -> Rectangle {
+        Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(40.0, 20.0),
+            0.0,
+            RoundFactors::new(5.0, 5.0, 5.0, 5.0),
+        )
+    }
+
+    #[test]
+    fn easing() {
+        assert_eq!(Easing::default(), Easing::Linear);
+
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+            assert_eq!(easing.apply(-1.0), 0.0);
+            assert_eq!(easing.apply(2.0), 1.0);
+        }
+
+        assert_eq!(Easing::Linear.apply(0.25), 0.25);
+        assert_eq!(Easing::EaseIn.apply(0.25), 0.0625);
+        assert_eq!(Easing::EaseOut.apply(0.25), 0.4375);
+        assert_eq!(Easing::EaseInOut.apply(0.25), 0.15625);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+    }
+
+    #[test]
+    fn animation_progress() {
+        let once = Animation::new(2.0, Playback::Once);
+        assert_eq!(once.progress(-1.0), 0.0);
+        assert_eq!(once.progress(1.0), 0.5);
+        assert_eq!(once.progress(5.0), 1.0);
+
+        let looping = Animation::new(2.0, Playback::Loop);
+        assert_eq!(looping.progress(1.0), 0.5);
+        assert_eq!(looping.progress(5.0), 0.5);
+
+        let ping_pong = Animation::new(2.0, Playback::PingPong);
+        assert_eq!(ping_pong.progress(1.0), 0.5);
+        assert_eq!(ping_pong.progress(2.0), 1.0);
+        assert_eq!(ping_pong.progress(3.5), 0.25);
+        assert_eq!(ping_pong.progress(4.0), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "duration must be greater than zero")]
+    fn animation_needs_a_duration() {
+        Animation::new(0.0, Playback::Loop);
+    }
+
+    #[test]
+    fn create_morph() {
+        let morph = Morph::new(circle(), rounded_rectangle(), 0.0, Easing::Linear);
+
+        assert_eq!(
+            format!("The morph is: {:?}", Morph::new(circle(), circle(), 0.5, Easing::EaseIn)),
+            "The morph is: Morph { from: Circle { center: Vector2D { x: 0.0, y: 0.0 }, radius: 10.0 }, to: Circle { center: Vector2D { x: 0.0, y: 0.0 }, radius: 10.0 }, t: 0.5, easing: EaseIn }"
+        );
+
+        assert_eq!(morph.t(), 0.0);
+        assert_eq!(morph.easing(), Easing::Linear);
+        assert_eq!(morph.from().radius(), 10.0);
+        assert_eq!(morph.to().width(), 40.0);
+        assert_eq!(morph.area(), 100.0 * PI);
+        assert_eq!(morph.perimeter(), 20.0 * PI);
+
+        let morph = Morph::new(circle(), rounded_rectangle(), 1.5, Easing::Linear);
+        assert_eq!(morph.t(), 1.0);
+        assert_eq!(morph.area(), rounded_rectangle().area());
+    }
+
+    #[test]
+    fn morph_sdf() {
+        let mut morph = Morph::new(circle(), rounded_rectangle(), 0.0, Easing::Linear);
+        let p = Vector2D::new(20.0, 0.0);
+
+        assert_eq!(get_sdf(&morph, &p), 10.0);
+
+        morph.set_t(1.0);
+        assert_eq!(morph.sdf(&p), 0.0);
+
+        morph.set_t(0.5);
+        assert_eq!(morph.sdf(&p), 5.0);
+
+        // half way between two circles of radius 10 and 20 is one of radius 15
+        let circles = Morph::new(
+            circle(),
+            Circle::new(Vector2D::new(0.0, 0.0), 20.0),
+            0.5,
+            Easing::Linear,
+        );
+        assert_eq!(circles.sdf(&Vector2D::new(15.0, 0.0)), 0.0);
+        assert!((circles.area() - 225.0 * PI).abs() / (225.0 * PI) < 1e-3);
+        assert!((circles.perimeter() - 30.0 * PI).abs() / (30.0 * PI) < 1e-3);
+    }
+
+    #[test]
+    fn morph_animation() {
+        let mut morph = Morph::new(circle(), rounded_rectangle(), 0.0, Easing::EaseIn);
+        let animation = Animation::new(2.0, Playback::PingPong);
+
+        morph.animate(&animation, 1.0);
+        assert_eq!(morph.t(), 0.5);
+        assert_eq!(morph.eased_t(), 0.25);
+        assert_eq!(morph.sdf(&Vector2D::new(20.0, 0.0)), 7.5);
+
+        morph.animate(&animation, 4.0);
+        assert_eq!(morph.t(), 0.0);
+    }
+
+    #[test]
+    fn morph_through_the_factory() {
+        let morph = ShapeFactory::new_morph(
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0),
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 20.0),
+            0.5,
+            Easing::Linear,
+        );
+
+        assert_eq!(morph.sdf(&Vector2D::new(0.0, 0.0)), -15.0);
+    }
+}
@@ -0,0 +1,503 @@
+use crate::circle::Circle;
+use crate::convex::{gjk_epa, support_bounds, ConvexShape};
+use crate::{rotate_vector_by_degrees, Shape};
+use vector2d::Vector2D;
+
+/// How a shape moves over time: at a constant velocity, while turning at a constant rate about
+/// a pivot that moves along with it. Positive angular velocities turn clockwise, like the
+/// shapes' rotation_angle_in_degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Motion {
+    velocity: Vector2D<f64>,
+    angular_velocity_in_degrees: f64, // per unit of time
+    pivot: Vector2D<f64>,             // where it is at time zero
+}
+
+impl Motion {
+    pub fn new(
+        velocity: Vector2D<f64>,
+        angular_velocity_in_degrees: f64,
+        pivot: Vector2D<f64>,
+    ) -> Self {
+        Motion {
+            velocity,
+            angular_velocity_in_degrees,
+            pivot,
+        }
+    }
+
+    /// Moving without turning.
+    pub fn linear(velocity: Vector2D<f64>) -> Self {
+        Motion::new(velocity, 0.0, Vector2D::new(0.0, 0.0))
+    }
+
+    /// Not moving at all.
+    pub fn stationary() -> Self {
+        Motion::linear(Vector2D::new(0.0, 0.0))
+    }
+
+    pub fn velocity(&self) -> Vector2D<f64> {
+        self.velocity
+    }
+
+    pub fn angular_velocity_in_degrees(&self) -> f64 {
+        self.angular_velocity_in_degrees
+    }
+
+    pub fn pivot(&self) -> Vector2D<f64> {
+        self.pivot
+    }
+
+    /// Where the point of the shape that starts at `point` is at `time`.
+    pub fn point_at(&self, point: &Vector2D<f64>, time: f64) -> Vector2D<f64> {
+        let turned = rotate_vector_by_degrees(
+            &(*point - self.pivot),
+            -self.angular_velocity_in_degrees * time,
+        );
+
+        self.pivot + self.velocity * time + turned
+    }
+
+    /// Where the point of the shape that is at `point` at `time` started out.
+    pub fn start_point(&self, point: &Vector2D<f64>, time: f64) -> Vector2D<f64> {
+        let moved_pivot = self.pivot + self.velocity * time;
+
+        self.pivot
+            + rotate_vector_by_degrees(
+                &(*point - moved_pivot),
+                self.angular_velocity_in_degrees * time,
+            )
+    }
+
+    /// `direction`, as it has been turned by `time`.
+    pub fn direction_at(&self, direction: &Vector2D<f64>, time: f64) -> Vector2D<f64> {
+        rotate_vector_by_degrees(direction, -self.angular_velocity_in_degrees * time)
+    }
+
+    /// The fastest any point of the shape within `circle` moves.
+    fn top_speed(&self, circle: &Circle) -> f64 {
+        if self.angular_velocity_in_degrees == 0.0 {
+            return self.velocity.length();
+        }
+
+        let reach = (circle.center() - self.pivot).length() + circle.radius();
+
+        self.velocity.length() + self.angular_velocity_in_degrees.to_radians().abs() * reach
+    }
+
+    /// A circle around the shape within `circle` that moves with the shape but stays inside
+    /// however it turns: centered on the pivot, if the shape turns at all.
+    fn swept_bounds(&self, circle: &Circle) -> Circle {
+        if self.angular_velocity_in_degrees == 0.0 {
+            return Circle::new(circle.center(), circle.radius());
+        }
+
+        Circle::new(
+            self.pivot,
+            (circle.center() - self.pivot).length() + circle.radius(),
+        )
+    }
+}
+
+/// When two moving shapes first touch.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Impact {
+    time: f64,
+    normal: Vector2D<f64>, // unit, from the first shape towards the second
+}
+
+impl Impact {
+    pub fn new(time: f64, normal: Vector2D<f64>) -> Self {
+        Impact { time, normal }
+    }
+
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// The contact normal at the time of impact, from the first shape towards the second.
+    pub fn normal(&self) -> Vector2D<f64> {
+        self.normal
+    }
+}
+
+/// When a moving circle first touches a moving `shape` within `max_time`, or `None` if it
+/// does not. Circles already touching it hit at time zero.
+///
+/// Found by conservative advancement: the gap is the shape's signed distance at the circle's
+/// center less its radius, and no two points close in on each other faster than the shapes'
+/// top speeds together, so time can safely advance by the gap over that speed. However fast
+/// the circle goes, it never steps past the shape, and only needs the distance to be a bound.
+/// While the circles the shapes sweep out by turning are apart, time also advances by their
+/// gap over how fast the pivots close in, so turning alone doesn't slow it down.
+pub fn circle_time_of_impact<S: Shape + ?Sized>(
+    circle: &Circle,
+    circle_motion: &Motion,
+    shape: &S,
+    shape_motion: &Motion,
+    max_time: f64,
+) -> Option<Impact> {
+    let bounds = shape.bounding_circle();
+    let speed = circle_motion.top_speed(circle) + shape_motion.top_speed(&bounds);
+    let tolerance = 1e-9 * (1.0 + circle.radius() + bounds.radius());
+
+    let center_at =
+        |time: f64| shape_motion.start_point(&circle_motion.point_at(&circle.center(), time), time);
+    let (swept_gap, swept_speed) = swept_gap(circle, circle_motion, &bounds, shape_motion);
+    let time = advance(
+        |time| shape.sdf(&center_at(time)) - circle.radius(),
+        speed,
+        swept_gap,
+        swept_speed,
+        max_time,
+        tolerance,
+    )?;
+
+    // the shape's normal points away from it, towards the circle
+    let normal = shape_motion.direction_at(&shape.normal(&center_at(time)), time);
+
+    Some(Impact::new(time, -normal))
+}
+
+/// When two moving convex shapes first touch within `max_time`, or `None` if they do not.
+/// Shapes already touching hit at time zero.
+///
+/// Found by conservative advancement, like `circle_time_of_impact`, on the gap that GJK finds
+/// between the shapes where they are at each step.
+pub fn time_of_impact<A: ConvexShape + ?Sized, B: ConvexShape + ?Sized>(
+    a: &A,
+    motion_a: &Motion,
+    b: &B,
+    motion_b: &Motion,
+    max_time: f64,
+) -> Option<Impact> {
+    let (bounds_a, bounds_b) = (support_bounds(a), support_bounds(b));
+    let circle_a = Circle::new(bounds_a.center(), bounds_a.dimensions().length() * 0.5);
+    let circle_b = Circle::new(bounds_b.center(), bounds_b.dimensions().length() * 0.5);
+    let speed = motion_a.top_speed(&circle_a) + motion_b.top_speed(&circle_b);
+    let size = circle_a.radius() + circle_b.radius();
+
+    let contact_at = |time: f64| {
+        let moved_a = motion_a.point_at(&circle_a.center(), time);
+        let moved_b = motion_b.point_at(&circle_b.center(), time);
+
+        gjk_epa(
+            |direction| {
+                moved_support(a, motion_a, time, direction)
+                    - moved_support(b, motion_b, time, &-*direction)
+            },
+            size + (moved_b - moved_a).length(),
+        )
+    };
+
+    // the circles around the boxes are looser than need be for turning about their middles
+    let (swept_gap, swept_speed) = swept_gap(
+        &a.bounding_circle(),
+        motion_a,
+        &b.bounding_circle(),
+        motion_b,
+    );
+    let time = advance(
+        |time| contact_at(time).separation(),
+        speed,
+        swept_gap,
+        swept_speed,
+        max_time,
+        1e-9 * (1.0 + size),
+    )?;
+
+    Some(Impact::new(time, contact_at(time).normal()))
+}
+
+/// The furthest point of a convex shape in `direction` once `motion` has taken it to `time`:
+/// where its furthest point in the direction turned back to the start has moved.
+fn moved_support<S: ConvexShape + ?Sized>(
+    shape: &S,
+    motion: &Motion,
+    time: f64,
+    direction: &Vector2D<f64>,
+) -> Vector2D<f64> {
+    let start_direction = motion.direction_at(direction, -time);
+
+    motion.point_at(&shape.support(&start_direction), time)
+}
+
+/// The gap between the circles two moving shapes sweep out by turning, and the fastest it
+/// can shrink: only the pivots moving bring those circles closer.
+fn swept_gap(
+    a: &Circle,
+    motion_a: &Motion,
+    b: &Circle,
+    motion_b: &Motion,
+) -> (impl Fn(f64) -> f64, f64) {
+    let (swept_a, swept_b) = (motion_a.swept_bounds(a), motion_b.swept_bounds(b));
+    let (motion_a, motion_b) = (*motion_a, *motion_b);
+
+    let gap = move |time: f64| {
+        let moved_a = motion_a.point_at(&swept_a.center(), time);
+        let moved_b = motion_b.point_at(&swept_b.center(), time);
+
+        (moved_b - moved_a).length() - swept_a.radius() - swept_b.radius()
+    };
+
+    (gap, (motion_a.velocity() - motion_b.velocity()).length())
+}
+
+/// Conservative advancement: the first time within `max_time` at which `gap` is down to
+/// `tolerance`, given that it never shrinks faster than `speed`, and that it is no less than
+/// `swept_gap`, which never shrinks faster than `swept_speed`. Each step is as long as either
+/// of them allows.
+///
+/// Running out of steps, when the outlines slide along each other within a hair of touching,
+/// is reported as a miss.
+fn advance(
+    gap: impl Fn(f64) -> f64,
+    speed: f64,
+    swept_gap: impl Fn(f64) -> f64,
+    swept_speed: f64,
+    max_time: f64,
+    tolerance: f64,
+) -> Option<f64> {
+    const MAX_STEPS: usize = 10_000;
+
+    let mut time = 0.0;
+    for _ in 0..MAX_STEPS {
+        let distance = gap(time);
+        if distance <= tolerance {
+            return Some(time);
+        }
+
+        // a gap that can't shrink gives an endless step, and a closed swept gap none at all
+        let step = (distance / speed).max(swept_gap(time).max(0.0) / swept_speed);
+        time += step;
+        if time > max_time {
+            return None;
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::circle::Circle;
+    use crate::motion::{circle_time_of_impact, time_of_impact, Motion};
+    use crate::rectangle::{Rectangle, RoundFactors};
+    use crate::{Shape, ShapeFactory};
+    use vector2d::Vector2D;
+
+    fn wall(angle: f64) -> Rectangle {
+        // thin, and tall before it is turned
+        Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(0.2, 40.0),
+            angle,
+            RoundFactors::new(0.0, 0.0, 0.0, 0.0),
+        )
+    }
+
+    #[test]
+    fn create_motion() {
+        let motion = Motion::new(Vector2D::new(1.0, 0.0), 90.0, Vector2D::new(0.0, 1.0));
+
+        assert_eq!(
+            format!("The motion is: {motion:?}"),
+            "The motion is: Motion { velocity: Vector2D { x: 1.0, y: 0.0 }, angular_velocity_in_degrees: 90.0, pivot: Vector2D { x: 0.0, y: 1.0 } }"
+        );
+
+        assert_eq!(motion.velocity(), Vector2D::new(1.0, 0.0));
+        assert_eq!(motion.angular_velocity_in_degrees(), 90.0);
+        assert_eq!(motion.pivot(), Vector2D::new(0.0, 1.0));
+        assert_eq!(
+            Motion::stationary(),
+            Motion::linear(Vector2D::new(0.0, 0.0))
+        );
+
+        // a quarter turn clockwise about the pivot while moving one to the right
+        let point = Vector2D::new(0.0, 2.0);
+        let moved = motion.point_at(&point, 1.0);
+        assert!((moved - Vector2D::new(2.0, 1.0)).length() < 1e-12);
+        assert!((motion.start_point(&moved, 1.0) - point).length() < 1e-12);
+        assert!(
+            (motion.direction_at(&Vector2D::new(0.0, 1.0), 1.0) - Vector2D::new(1.0, 0.0)).length()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn fast_circle_does_not_tunnel_through_a_thin_wall() {
+        let bullet = Circle::new(Vector2D::new(-50.0, 0.0), 0.5);
+        let motion = Motion::linear(Vector2D::new(1000.0, 0.0));
+        let wall = wall(0.0);
+
+        // stepping in time it is on one side, then the other
+        for time in [0.04, 0.06] {
+            let center = motion.point_at(&bullet.center(), time);
+            assert!(wall.sdf(&center) > bullet.radius());
+        }
+
+        let impact =
+            circle_time_of_impact(&bullet, &motion, &wall, &Motion::stationary(), 1.0).unwrap();
+        assert!((impact.time() - 0.0494).abs() < 1e-9);
+        assert!((impact.normal() - Vector2D::new(1.0, 0.0)).length() < 1e-12);
+
+        // too soon, or going the other way
+        assert_eq!(
+            circle_time_of_impact(&bullet, &motion, &wall, &Motion::stationary(), 0.04),
+            None
+        );
+        assert_eq!(
+            circle_time_of_impact(
+                &bullet,
+                &Motion::linear(Vector2D::new(-1000.0, 0.0)),
+                &wall,
+                &Motion::stationary(),
+                1.0
+            ),
+            None
+        );
+
+        // touching from the start
+        let touching = Circle::new(Vector2D::new(0.5, 0.0), 0.5);
+        let impact =
+            circle_time_of_impact(&touching, &motion, &wall, &Motion::stationary(), 1.0).unwrap();
+        assert_eq!(impact.time(), 0.0);
+    }
+
+    #[test]
+    fn circle_hits_a_turned_wall() {
+        let bullet = Circle::new(Vector2D::new(-50.0, 5.0), 0.5);
+        let motion = Motion::linear(Vector2D::new(800.0, -100.0));
+        let wall = wall(30.0);
+
+        let impact =
+            circle_time_of_impact(&bullet, &motion, &wall, &Motion::stationary(), 1.0).unwrap();
+
+        let center = motion.point_at(&bullet.center(), impact.time());
+        assert!((wall.sdf(&center) - bullet.radius()).abs() < 1e-6);
+        assert!((impact.normal() + wall.normal(&center)).length() < 1e-12);
+        for i in 0..100 {
+            let earlier = impact.time() * i as f64 / 100.0;
+            let center = motion.point_at(&bullet.center(), earlier);
+            assert!(wall.sdf(&center) > bullet.radius());
+        }
+    }
+
+    #[test]
+    fn circle_hits_a_spinning_bar() {
+        // a bar along x turning a quarter per unit of time about its center, and a circle
+        // resting above its end: the end sweeps up into it
+        let bar = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(20.0, 1.0),
+            0.0,
+            RoundFactors::new(0.0, 0.0, 0.0, 0.0),
+        );
+        let spin = Motion::new(Vector2D::new(0.0, 0.0), -90.0, Vector2D::new(0.0, 0.0));
+        let ball = Circle::new(Vector2D::new(6.0, 6.0), 1.0);
+
+        let impact = circle_time_of_impact(&ball, &Motion::stationary(), &bar, &spin, 2.0);
+        let time = impact.unwrap().time();
+        assert!(time > 0.0 && time < 0.5);
+
+        let center = spin.start_point(&ball.center(), time);
+        assert!((bar.sdf(&center) - ball.radius()).abs() < 1e-6);
+
+        // the boxed shapes from the factory can be the target too
+        let boxed = ShapeFactory::new_rectangle(Vector2D::new(0.0, 0.0), Vector2D::new(20.0, 1.0));
+        let impact = circle_time_of_impact(&ball, &Motion::stationary(), &boxed, &spin, 2.0);
+        assert!((impact.unwrap().time() - time).abs() < 1e-9);
+    }
+
+    #[test]
+    fn convex_shapes_time_of_impact() {
+        let box_a = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(2.0, 2.0),
+            0.0,
+            RoundFactors::new(0.0, 0.0, 0.0, 0.0),
+        );
+        let box_b = Rectangle::new(
+            Vector2D::new(10.0, 0.0),
+            Vector2D::new(2.0, 2.0),
+            45.0,
+            RoundFactors::new(0.0, 0.0, 0.0, 0.0),
+        );
+
+        // head on, the diamond's corner meets the side
+        let impact = time_of_impact(
+            &box_a,
+            &Motion::linear(Vector2D::new(4.0, 0.0)),
+            &box_b,
+            &Motion::linear(Vector2D::new(-4.0, 0.0)),
+            10.0,
+        )
+        .unwrap();
+        let gap = 10.0 - 1.0 - 2.0_f64.sqrt();
+        assert!((impact.time() - gap / 8.0).abs() < 1e-9);
+        assert!((impact.normal() - Vector2D::new(1.0, 0.0)).length() < 1e-6);
+
+        // clipping the diamond's side on the way past
+        let clipped = time_of_impact(
+            &box_a,
+            &Motion::linear(Vector2D::new(4.0, 1.0)),
+            &box_b,
+            &Motion::stationary(),
+            10.0,
+        );
+        let time = clipped.unwrap().time();
+        assert!(time > 2.19 && time < 2.2);
+
+        // passing by each other
+        let missed = time_of_impact(
+            &box_a,
+            &Motion::linear(Vector2D::new(4.0, 4.0)),
+            &box_b,
+            &Motion::stationary(),
+            10.0,
+        );
+        assert_eq!(missed, None);
+
+        // a circle against a box spinning about its own center
+        let circle = Circle::new(Vector2D::new(0.0, 4.0), 1.0);
+        let spinning = Motion::new(Vector2D::new(0.0, 0.0), 90.0, Vector2D::new(0.0, 0.0));
+        let long = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(10.0, 1.0),
+            0.0,
+            RoundFactors::new(0.0, 0.0, 0.0, 0.0),
+        );
+        let impact = time_of_impact(&long, &spinning, &circle, &Motion::stationary(), 1.0);
+        let exact = circle_time_of_impact(&circle, &Motion::stationary(), &long, &spinning, 1.0);
+        assert!((impact.unwrap().time() - exact.unwrap().time()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn circle_hits_a_fast_spinning_wheel() {
+        // the wheel turning under the circle brings no point of it any closer, however high
+        // that makes the top speed
+        let ball = Circle::new(Vector2D::new(102.0, 0.0), 1.0);
+        let rolling = Motion::linear(Vector2D::new(-1.0, 0.0));
+        let wheel = Circle::new(Vector2D::new(0.0, 0.0), 100.0);
+        let spin = Motion::new(Vector2D::new(0.0, 0.0), 3600.0, Vector2D::new(0.0, 0.0));
+
+        for motion in [Motion::stationary(), spin] {
+            let impact = circle_time_of_impact(&ball, &rolling, &wheel, &motion, 2.0).unwrap();
+            assert!((impact.time() - 1.0).abs() < 1e-9);
+            assert!((impact.normal() - Vector2D::new(-1.0, 0.0)).length() < 1e-6);
+
+            let impact = time_of_impact(&ball, &rolling, &wheel, &motion, 2.0).unwrap();
+            assert!((impact.time() - 1.0).abs() < 1e-9);
+            assert!((impact.normal() - Vector2D::new(-1.0, 0.0)).length() < 1e-6);
+        }
+
+        // and a circle just clear of it never touches
+        let clear = Circle::new(Vector2D::new(101.5, 0.0), 1.0);
+        let still = Motion::stationary();
+        assert_eq!(
+            circle_time_of_impact(&clear, &still, &wheel, &spin, 2.0),
+            None
+        );
+        assert_eq!(time_of_impact(&clear, &still, &wheel, &spin, 2.0), None);
+    }
+}
@@ -0,0 +1,482 @@
+use crate::bounds::BoundingBox;
+use crate::circle::Circle;
+use crate::mass::AreaIntegrals;
+use crate::ray::{bounds_interval, hit_at, RayHit};
+use crate::Shape;
+use vector2d::Vector2D;
+
+/// Number of samples along the longer side of the bounds.
+const RESOLUTION: usize = 512;
+
+/// Axis aligned bounds around everything `shape` covers, estimated by probing the signed
+/// distance from far away along the axes. Only the probing error is added on, so the result
+/// is as tight as the distance is exact far from the shape.
+pub(crate) fn estimate_bounds<S: Shape + ?Sized>(shape: &S) -> BoundingBox {
+    let directions = [
+        Vector2D::new(1.0, 0.0),
+        Vector2D::new(0.0, 1.0),
+        Vector2D::new(-1.0, 0.0),
+        Vector2D::new(0.0, -1.0),
+    ];
+
+    // seen from distance R, a point p of the shape is at sqrt((R - p.d)^2 + e^2) where e is
+    // its offset across the direction d, so R - sdf under-estimates the extent by about
+    // e^2 / 2R; probing from a thousand times the extent keeps that to a fraction of a percent
+    let mut extent = shape.sdf(&Vector2D::new(0.0, 0.0)).abs() + 1.0;
+    let mut supports = [0.0; 4];
+    for _ in 0..3 {
+        let distance = 1000.0 * extent;
+        for (support, direction) in supports.iter_mut().zip(directions.iter()) {
+            *support = distance - shape.sdf(&(*direction * distance));
+        }
+
+        let largest = supports.iter().fold(0.0_f64, |a, b| a.max(b.abs()));
+        if largest <= extent {
+            break;
+        }
+        extent = largest;
+    }
+
+    let min = Vector2D::new(-supports[2], -supports[3]);
+    let max = Vector2D::new(supports[0], supports[1]);
+
+    BoundingBox::from_points([min, max]).expanded(extent / 1000.0)
+}
+
+/// Area covered by `shape`, from the anti-aliased coverage of a sample grid over its bounds.
+pub(crate) fn estimate_area<S: Shape + ?Sized>(shape: &S) -> f64 {
+    let bounds = shape.bounding_box();
+    if !bounds.is_finite() {
+        return f64::INFINITY;
+    }
+
+    let grid = SampleGrid::new(shape, bounds);
+
+    grid.values
+        .iter()
+        .map(|value| (0.5 - value / grid.step).clamp(0.0, 1.0))
+        .sum::<f64>()
+        * grid.step
+        * grid.step
+}
+
+/// Integrals over the area covered by `shape` of 1, x, y and their squares and product, from the
+/// same coverage as `estimate_area`, each sample standing for a small square of its cell.
+/// Shapes that go on for ever get an infinite area and moments that are not a number.
+pub(crate) fn estimate_area_integrals<S: Shape + ?Sized>(shape: &S) -> AreaIntegrals {
+    let bounds = shape.bounding_box();
+    if !bounds.is_finite() {
+        return AreaIntegrals::new(
+            f64::INFINITY,
+            Vector2D::new(f64::NAN, f64::NAN),
+            f64::NAN,
+            f64::NAN,
+            f64::NAN,
+        );
+    }
+
+    let grid = SampleGrid::new(shape, bounds);
+    // about the middle of the grid, to keep the squares small
+    let middle = bounds.center();
+    let cell = Vector2D::new(grid.step, grid.step);
+    let mut integrals = AreaIntegrals::new(0.0, Vector2D::new(0.0, 0.0), 0.0, 0.0, 0.0);
+
+    for row in 0..grid.rows {
+        for column in 0..grid.columns {
+            let coverage = (0.5 - grid.value(column, row) / grid.step).clamp(0.0, 1.0);
+            if coverage > 0.0 {
+                let square = AreaIntegrals::rectangle(grid.point(column, row) - middle, cell);
+                integrals = integrals + square.scaled(coverage);
+            }
+        }
+    }
+
+    integrals.translated(&middle)
+}
+
+/// Length of the outline of `shape`, traced with marching squares over a sample grid.
+pub(crate) fn estimate_perimeter<S: Shape + ?Sized>(shape: &S) -> f64 {
+    let bounds = shape.bounding_box();
+    if !bounds.is_finite() {
+        return f64::INFINITY;
+    }
+
+    let grid = SampleGrid::new(shape, bounds);
+    let mut perimeter = 0.0;
+
+    for row in 0..grid.rows - 1 {
+        for column in 0..grid.columns - 1 {
+            let corners = [
+                (Vector2D::new(0.0, 0.0), grid.value(column, row)),
+                (Vector2D::new(1.0, 0.0), grid.value(column + 1, row)),
+                (Vector2D::new(1.0, 1.0), grid.value(column + 1, row + 1)),
+                (Vector2D::new(0.0, 1.0), grid.value(column, row + 1)),
+            ];
+
+            // where the outline crosses the cell's edges, in cell units
+            let mut crossings = Vec::with_capacity(4);
+            for i in 0..4 {
+                let (a, value_a) = corners[i];
+                let (b, value_b) = corners[(i + 1) % 4];
+
+                if (value_a < 0.0) != (value_b < 0.0) {
+                    let t = value_a / (value_a - value_b);
+                    crossings.push(a + (b - a) * t);
+                }
+            }
+
+            // two crossings make one segment, four (a saddle) make two
+            for pair in crossings.chunks_exact(2) {
+                perimeter += (pair[1] - pair[0]).length() * grid.step;
+            }
+        }
+    }
+
+    perimeter
+}
+
+/// The smallest circle through the points where the outline of `shape` crosses the edges
+/// between neighbouring samples.
+pub(crate) fn estimate_bounding_circle<S: Shape + ?Sized>(shape: &S) -> Circle {
+    let bounds = shape.bounding_box();
+    if !bounds.is_finite() {
+        return Circle::new(Vector2D::new(0.0, 0.0), f64::INFINITY);
+    }
+
+    let grid = SampleGrid::new(shape, bounds);
+    let mut crossings = Vec::new();
+
+    for row in 0..grid.rows {
+        for column in 0..grid.columns {
+            let value = grid.value(column, row);
+            let mut neighbours = Vec::with_capacity(2);
+            if column + 1 < grid.columns {
+                neighbours.push((Vector2D::new(1.0, 0.0), grid.value(column + 1, row)));
+            }
+            if row + 1 < grid.rows {
+                neighbours.push((Vector2D::new(0.0, 1.0), grid.value(column, row + 1)));
+            }
+
+            for (direction, next) in neighbours {
+                if (value < 0.0) != (next < 0.0) {
+                    let t = value / (value - next);
+                    crossings.push(grid.point(column, row) + direction * (t * grid.step));
+                }
+            }
+        }
+    }
+
+    if crossings.is_empty() {
+        // nothing covered, or all of the sampled area
+        return Circle::new(bounds.center(), 0.0);
+    }
+
+    Circle::enclosing(&crossings)
+}
+
+/// The circle around the lowest signed distance of `shape`: the lowest sample, refined by
+/// a compass search that only ever moves deeper.
+pub(crate) fn estimate_inscribed_circle<S: Shape + ?Sized>(shape: &S) -> Circle {
+    let bounds = shape.bounding_box();
+    let (mut center, mut step) = if bounds.is_finite() {
+        let grid = SampleGrid::new(shape, bounds);
+        let (index, _) =
+            grid.values
+                .iter()
+                .enumerate()
+                .fold((0, f64::MAX), |lowest, (index, &value)| {
+                    if value < lowest.1 {
+                        (index, value)
+                    } else {
+                        lowest
+                    }
+                });
+
+        (
+            grid.point(index % grid.columns, index / grid.columns),
+            grid.step,
+        )
+    } else {
+        // endless shapes repeat, so search around the origin
+        (Vector2D::new(0.0, 0.0), 1.0)
+    };
+
+    let directions = (0..8).map(|i| {
+        let angle = i as f64 * std::f64::consts::FRAC_PI_4;
+        Vector2D::new(angle.cos(), angle.sin())
+    });
+    let smallest_step = step * 1e-6;
+    let mut depth = shape.sdf(&center);
+
+    while step > smallest_step {
+        let deeper = directions
+            .clone()
+            .map(|direction| center + direction * step)
+            .map(|candidate| (candidate, shape.sdf(&candidate)))
+            .fold((center, depth), |deepest, candidate| {
+                if candidate.1 < deepest.1 {
+                    candidate
+                } else {
+                    deepest
+                }
+            });
+
+        if deeper.1 < depth {
+            (center, depth) = deeper;
+        } else {
+            step *= 0.5;
+        }
+    }
+
+    Circle::new(center, (-depth).max(0.0))
+}
+
+/// The gradient of the signed distance of `shape` at `point`, from central differences.
+pub(crate) fn estimate_gradient<S: Shape + ?Sized>(
+    shape: &S,
+    point: &Vector2D<f64>,
+) -> Vector2D<f64> {
+    // small against the shape, large against the rounding of the coordinates
+    let h = 1e-6 * (1.0 + point.x.abs().max(point.y.abs()));
+    let dx = Vector2D::new(h, 0.0);
+    let dy = Vector2D::new(0.0, h);
+
+    Vector2D::new(
+        shape.sdf(&(*point + dx)) - shape.sdf(&(*point - dx)),
+        shape.sdf(&(*point + dy)) - shape.sdf(&(*point - dy)),
+    ) / (2.0 * h)
+}
+
+/// Steps from `point` against the normal of `shape` by its signed distance until the distance
+/// vanishes. Where the normal vanishes too, as on a circle's center, there is no single
+/// nearest point and the search stops.
+pub(crate) fn project_onto_outline<S: Shape + ?Sized>(
+    shape: &S,
+    point: &Vector2D<f64>,
+) -> Vector2D<f64> {
+    let mut projected = *point;
+
+    for _ in 0..64 {
+        let distance = shape.sdf(&projected);
+        let tolerance = 1e-12 * (1.0 + projected.x.abs().max(projected.y.abs()));
+        if distance.abs() <= tolerance {
+            break;
+        }
+
+        let normal = shape.normal(&projected);
+        if normal.length_squared() == 0.0 {
+            break;
+        }
+
+        projected -= normal * distance;
+    }
+
+    projected
+}
+
+/// The first crossing of the outline of `shape` by the ray, found by stepping along it by the
+/// signed distance, which never steps past the outline. Each step is at least a tiny fraction
+/// of the shape's size, so rays that pass just by the outline do not stall next to it; a step
+/// that does cross the outline is narrowed down by bisection.
+pub(crate) fn sphere_trace<S: Shape + ?Sized>(
+    shape: &S,
+    origin: &Vector2D<f64>,
+    direction: &Vector2D<f64>,
+    max_distance: f64,
+) -> Option<RayHit> {
+    const MAX_STEPS: usize = 100_000;
+
+    let bounds = shape.bounding_box();
+    let size = if bounds.is_finite() {
+        bounds.width().max(bounds.height())
+    } else {
+        0.0
+    };
+    let scale = 1.0 + size + origin.x.abs().max(origin.y.abs());
+    let tolerance = 1e-12 * scale;
+    let smallest_step = 1e-6 * scale;
+
+    let (start, end) = bounds_interval(&bounds.expanded(tolerance), origin, direction)?;
+    let end = end.min(max_distance);
+    if start > end {
+        return None;
+    }
+
+    let at = |t: f64| *origin + *direction * t;
+    let hit = |t: f64| Some(hit_at(shape, origin, direction, t));
+
+    let mut t = start;
+    let mut distance = shape.sdf(&at(t));
+    let inside = distance < 0.0;
+
+    for _ in 0..MAX_STEPS {
+        if distance.abs() <= tolerance {
+            return hit(t);
+        }
+
+        let next = (t + distance.abs().max(smallest_step)).min(end);
+        let next_distance = shape.sdf(&at(next));
+
+        if (next_distance < 0.0) != inside {
+            // stepped across: bisect down to the crossing
+            let (mut before, mut after) = (t, next);
+            while after - before > tolerance {
+                let middle = (before + after) * 0.5;
+                if middle == before || middle == after {
+                    break; // as close as floating point gets this far out
+                }
+
+                if (shape.sdf(&at(middle)) < 0.0) == inside {
+                    before = middle;
+                } else {
+                    after = middle;
+                }
+            }
+
+            return hit(after);
+        }
+
+        if next >= end {
+            return (next_distance.abs() <= tolerance)
+                .then(|| hit(next))
+                .flatten();
+        }
+
+        (t, distance) = (next, next_distance);
+    }
+
+    None
+}
+
+struct SampleGrid {
+    min: Vector2D<f64>,
+    values: Vec<f64>,
+    columns: usize,
+    rows: usize,
+    step: f64,
+}
+
+impl SampleGrid {
+    /// Samples cell centers over `bounds`, padded so that the outline is strictly inside.
+    fn new<S: Shape + ?Sized>(shape: &S, bounds: BoundingBox) -> Self {
+        let padding = 0.05 * bounds.width().max(bounds.height()) + 1e-9;
+        let bounds = bounds.expanded(padding);
+        let (min, size) = (bounds.min(), bounds.dimensions());
+        let step = size.x.max(size.y) / RESOLUTION as f64;
+        let columns = ((size.x / step).ceil() as usize).max(2);
+        let rows = ((size.y / step).ceil() as usize).max(2);
+
+        let mut values = Vec::with_capacity(columns * rows);
+        for row in 0..rows {
+            for column in 0..columns {
+                values.push(shape.sdf(&sample_point(min, step, column, row)));
+            }
+        }
+
+        SampleGrid {
+            min,
+            values,
+            columns,
+            rows,
+            step,
+        }
+    }
+
+    fn value(&self, column: usize, row: usize) -> f64 {
+        self.values[row * self.columns + column]
+    }
+
+    fn point(&self, column: usize, row: usize) -> Vector2D<f64> {
+        sample_point(self.min, self.step, column, row)
+    }
+}
+
+fn sample_point(min: Vector2D<f64>, step: f64, column: usize, row: usize) -> Vector2D<f64> {
+    min + Vector2D::new(column as f64 + 0.5, row as f64 + 0.5) * step
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::circle::Circle;
+    use crate::csg::{Intersection, Union};
+    use crate::displace::Displaced;
+    use crate::numeric::{
+        estimate_area, estimate_bounding_circle, estimate_bounds, estimate_inscribed_circle,
+        estimate_perimeter, project_onto_outline,
+    };
+    use crate::rectangle::Rectangle;
+    use crate::{Shape, ShapeFactory};
+    use vector2d::Vector2D;
+
+    #[test]
+    fn circle_estimates() {
+        let circle = Circle::new(Vector2D::new(30.0, -20.0), 10.0);
+
+        let bounds = estimate_bounds(&circle);
+        assert!(bounds.min().x <= 20.0 && bounds.min().x > 19.9);
+        assert!(bounds.max().y >= -10.0 && bounds.max().y < -9.9);
+
+        assert!((estimate_area(&circle) - circle.area()).abs() / circle.area() < 1e-3);
+        assert!(
+            (estimate_perimeter(&circle) - circle.perimeter()).abs() / circle.perimeter() < 1e-3
+        );
+    }
+
+    #[test]
+    fn rectangle_estimates() {
+        let rectangle = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(40.0, 10.0),
+            0.0,
+            Default::default(),
+        );
+
+        assert!((estimate_area(&rectangle) - 400.0).abs() < 0.5);
+        assert!((estimate_perimeter(&rectangle) - 100.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn composite_circle_estimates() {
+        let union = Union::new(
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0),
+            ShapeFactory::new_circle(Vector2D::new(10.0, 0.0), 10.0),
+        );
+
+        let circle = estimate_bounding_circle(&union);
+        assert!((circle.center() - Vector2D::new(5.0, 0.0)).length() < 0.05);
+        assert!((circle.radius() - 15.0).abs() < 0.05);
+
+        let lens = Intersection::new(
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0),
+            ShapeFactory::new_circle(Vector2D::new(10.0, 0.0), 10.0),
+        );
+
+        let circle = estimate_inscribed_circle(&lens);
+        assert!((circle.center() - Vector2D::new(5.0, 0.0)).length() < 0.05);
+        assert!(circle.radius() <= 5.0 && circle.radius() > 4.95);
+        assert!(lens.sdf(&circle.center()) <= -circle.radius());
+    }
+
+    #[test]
+    fn projection_onto_outline() {
+        let union = Union::new(
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0),
+            ShapeFactory::new_circle(Vector2D::new(10.0, 0.0), 10.0),
+        );
+
+        let closest = project_onto_outline(&union, &Vector2D::new(25.0, 0.0));
+        assert!((closest - Vector2D::new(20.0, 0.0)).length() < 1e-9);
+        let closest = union.closest_point(&Vector2D::new(-3.0, 4.0));
+        assert!((closest - Vector2D::new(-6.0, 8.0)).length() < 1e-9);
+
+        // a bound rather than a distance needs more steps, but gets there
+        let shrunk = Displaced::new(
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 10.0),
+            |p: &Vector2D<f64>| p.x * 0.1,
+            0.1,
+        );
+        let closest = shrunk.closest_point(&Vector2D::new(0.0, 20.0));
+        assert!(shrunk.sdf(&closest).abs() < 1e-9);
+        assert!(closest.x < 0.0);
+    }
+}
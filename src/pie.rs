@@ -0,0 +1,166 @@
+use crate::{dot_product, rotate_vector_by_degrees, Shape};
+use vector2d::Vector2D;
+
+/// A circular sector. Angles are measured counter-clockwise from the positive x axis; the
+/// sector runs from the start angle through the sweep angle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pie {
+    center: Vector2D<f64>,
+    radius: f64,
+    start_angle_in_degrees: f64,
+    sweep_angle_in_degrees: f64,
+}
+
+impl Pie {
+    pub fn new(
+        center: Vector2D<f64>,
+        radius: f64,
+        start_angle_in_degrees: f64,
+        sweep_angle_in_degrees: f64,
+    ) -> Self {
+        if !(0.0..=360.0).contains(&sweep_angle_in_degrees) {
+            panic!("Sweep angle must be between 0 and 360 degrees.");
+        }
+
+        Pie {
+            center,
+            radius,
+            start_angle_in_degrees,
+            sweep_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    pub fn start_angle_in_degrees(&self) -> f64 {
+        self.start_angle_in_degrees
+    }
+
+    pub fn sweep_angle_in_degrees(&self) -> f64 {
+        self.sweep_angle_in_degrees
+    }
+
+    pub fn end_angle_in_degrees(&self) -> f64 {
+        self.start_angle_in_degrees + self.sweep_angle_in_degrees
+    }
+
+    pub fn arc_length(&self) -> f64 {
+        self.radius * self.sweep_angle_in_degrees.to_radians()
+    }
+}
+
+impl Shape for Pie {
+    fn area(&self) -> f64 {
+        0.5 * self.radius.powf(2.0) * self.sweep_angle_in_degrees.to_radians()
+    }
+
+    fn perimeter(&self) -> f64 {
+        if self.sweep_angle_in_degrees == 360.0 {
+            self.arc_length()
+        } else {
+            self.arc_length() + 2.0 * self.radius
+        }
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        // a full turn is a disc, with no straight sides left to leave a seam along the start
+        if self.sweep_angle_in_degrees == 360.0 {
+            return (*point - self.center).length() - self.radius;
+        }
+
+        // translate to center the pie at origin and turn it so it is symmetric around +y
+        let bisector = self.start_angle_in_degrees + self.sweep_angle_in_degrees * 0.5;
+        let translated = rotate_vector_by_degrees(&(*point - self.center), 90.0 - bisector);
+        let p = Vector2D::new(translated.x.abs(), translated.y);
+
+        let half_sweep = (self.sweep_angle_in_degrees * 0.5).to_radians();
+        let (sine, cosine) = half_sweep.sin_cos();
+        let edge = Vector2D::new(sine, cosine);
+
+        // distance to the round side and to the straight side
+        let to_circle = p.length() - self.radius;
+        let to_edge = (p - edge * dot_product(&p, &edge).clamp(0.0, self.radius)).length();
+        let outside_edge = (cosine * p.x - sine * p.y).signum();
+
+        to_circle.max(to_edge * outside_edge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pie::Pie;
+    use crate::{get_area, get_sdf, Shape};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    #[test]
+    fn create_pie() {
+        let pie = Pie::new(Vector2D::new(10.0, 10.0), 10.0, 0.0, 90.0);
+
+        assert_eq!(
+            format!("The pie is: {pie:?}"),
+            "The pie is: Pie { center: Vector2D { x: 10.0, y: 10.0 }, radius: 10.0, start_angle_in_degrees: 0.0, sweep_angle_in_degrees: 90.0 }"
+        );
+
+        assert_eq!(pie.end_angle_in_degrees(), 90.0);
+        assert_eq!(pie.arc_length(), 5.0 * PI);
+        assert_eq!(get_area(&pie), 25.0 * PI);
+        assert_eq!(pie.perimeter(), 5.0 * PI + 20.0);
+
+        let full = Pie::new(Vector2D::new(10.0, 10.0), 10.0, 0.0, 360.0);
+        assert_eq!(full.area(), 100.0 * PI);
+        assert_eq!(full.perimeter(), 20.0 * PI);
+    }
+
+    #[test]
+    fn pie_equality() {
+        let pie1 = Pie::new(Vector2D::new(10.0, 10.0), 10.0, 0.0, 90.0);
+        let pie2 = Pie::new(Vector2D::new(10.0, 10.0), 10.0, 0.0, 90.0);
+        let pie3 = Pie::new(Vector2D::new(10.0, 10.0), 10.0, 0.0, 45.0);
+
+        assert!(pie1 == pie2);
+        assert!(pie1 != pie3);
+    }
+
+    #[test]
+    fn pie_sdf() {
+        // the quarter from +x to +y
+        let pie = Pie::new(Vector2D::new(0.0, 0.0), 10.0, 0.0, 90.0);
+
+        assert!(get_sdf(&pie, &Vector2D::new(0.0, 0.0)).abs() < 1e-12);
+        assert!((pie.sdf(&Vector2D::new(5.0, 2.0)) + 2.0).abs() < 1e-12);
+        assert!((pie.sdf(&Vector2D::new(2.0, 5.0)) + 2.0).abs() < 1e-12);
+        assert!((pie.sdf(&Vector2D::new(5.0, -3.0)) - 3.0).abs() < 1e-12);
+        assert!((pie.sdf(&Vector2D::new(-3.0, -4.0)) - 5.0).abs() < 1e-12);
+        assert!((pie.sdf(&Vector2D::new(0.0, 15.0)) - 5.0).abs() < 1e-12);
+        assert!((pie.sdf(&Vector2D::new(12.0, 12.0)) - (288.0_f64.sqrt() - 10.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn full_pie_sdf() {
+        let pie = Pie::new(Vector2D::new(0.0, 0.0), 1.0, 0.0, 360.0);
+
+        // on and either side of the start ray, where the straight sides met
+        assert_eq!(get_sdf(&pie, &Vector2D::new(0.5, 0.0)), -0.5);
+        assert!((pie.sdf(&Vector2D::new(0.5, 0.01)) + 0.5).abs() < 1e-3);
+        assert!((pie.sdf(&Vector2D::new(0.5, -0.01)) + 0.5).abs() < 1e-3);
+        assert_eq!(pie.sdf(&Vector2D::new(-2.0, 0.0)), 1.0);
+    }
+
+    #[test]
+    fn pie_major_sdf() {
+        // three quarters, leaving out the lower right quadrant
+        let pie = Pie::new(Vector2D::new(0.0, 0.0), 10.0, 0.0, 270.0);
+
+        assert!((pie.sdf(&Vector2D::new(-5.0, -5.0)) + (10.0 - 50.0_f64.sqrt())).abs() < 1e-12);
+        assert!((pie.sdf(&Vector2D::new(-1.0, -2.0)) + 1.0).abs() < 1e-12);
+        assert!((pie.sdf(&Vector2D::new(3.0, -4.0)) - 3.0).abs() < 1e-12);
+        assert!((pie.sdf(&Vector2D::new(1.0, -1.0)) - 1.0).abs() < 1e-12);
+    }
+}
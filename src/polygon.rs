@@ -0,0 +1,481 @@
+use crate::bounds::BoundingBox;
+use crate::circle::Circle;
+use crate::convex::{furthest_vertex, ConvexShape};
+use crate::mass::{AreaIntegrals, SecondMomentOfArea};
+use crate::ray::{edges_crossing, hit_at, unit_direction, RayHit};
+use crate::{closest_point_on_edges, closest_point_on_segment, cross_product, dot_product, Shape};
+use vector2d::Vector2D;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Vector2D<f64>>,
+}
+
+impl Polygon {
+    /// The vertices describe a simple (non self-intersecting) outline in either winding order;
+    /// the closing edge from the last vertex back to the first is implied.
+    pub fn new(vertices: Vec<Vector2D<f64>>) -> Self {
+        if vertices.len() < 3 {
+            panic!("Polygon must have at least three vertices.");
+        }
+
+        if vertices
+            .iter()
+            .any(|v| !v.x.is_finite() || !v.y.is_finite())
+        {
+            panic!("Polygon vertices must be finite.");
+        }
+
+        let polygon = Polygon { vertices };
+
+        if polygon.edges().any(|(a, b)| a == b) {
+            panic!("Polygon must not have zero length edges.");
+        }
+
+        if polygon.signed_area() == 0.0 {
+            panic!("Polygon must enclose a non-zero area.");
+        }
+
+        if !polygon.is_simple() {
+            panic!("Polygon edges must not intersect each other.");
+        }
+
+        polygon
+    }
+
+    pub fn vertices(&self) -> &[Vector2D<f64>] {
+        &self.vertices
+    }
+
+    pub fn centroid(&self) -> Vector2D<f64> {
+        let mut sum = Vector2D::new(0.0, 0.0);
+        for (a, b) in self.edges() {
+            sum += (a + b) * cross_product(&a, &b);
+        }
+
+        sum / (6.0 * self.signed_area())
+    }
+
+    pub fn is_counter_clockwise(&self) -> bool {
+        self.signed_area() > 0.0
+    }
+
+    /// The same polygon as a `ConvexPolygon`, or `None` if it has a dent.
+    pub fn as_convex(&self) -> Option<ConvexPolygon> {
+        self.is_convex().then(|| ConvexPolygon {
+            polygon: self.clone(),
+        })
+    }
+
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        let winding = self.signed_area().signum();
+
+        (0..n).all(|i| {
+            let a = self.vertices[i];
+            let b = self.vertices[(i + 1) % n];
+            let c = self.vertices[(i + 2) % n];
+
+            winding * cross_product(&(b - a), &(c - b)) >= 0.0
+        })
+    }
+
+    /// Number of times the outline winds around `point`; zero means outside.
+    pub fn winding_number(&self, point: &Vector2D<f64>) -> i32 {
+        let mut winding_number = 0;
+        for (a, b) in self.edges() {
+            let side = cross_product(&(b - a), &(*point - a));
+
+            if a.y <= point.y {
+                if b.y > point.y && side > 0.0 {
+                    winding_number += 1;
+                }
+            } else if b.y <= point.y && side < 0.0 {
+                winding_number -= 1;
+            }
+        }
+
+        winding_number
+    }
+
+    pub(crate) fn edges(&self) -> impl Iterator<Item = (Vector2D<f64>, Vector2D<f64>)> + '_ {
+        let n = self.vertices.len();
+
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    fn signed_area(&self) -> f64 {
+        // shoelace formula
+        self.edges()
+            .map(|(a, b)| cross_product(&a, &b))
+            .sum::<f64>()
+            * 0.5
+    }
+
+    fn is_simple(&self) -> bool {
+        let edges: Vec<_> = self.edges().collect();
+        let n = edges.len();
+
+        for i in 0..n {
+            // adjacent edges only share their common vertex unless they fold back onto each other
+            let (a, b) = edges[i];
+            let (_, c) = edges[(i + 1) % n];
+            if cross_product(&(b - a), &(c - b)) == 0.0 && dot_product(&(b - a), &(c - b)) < 0.0 {
+                return false;
+            }
+
+            for (j, &(c, d)) in edges.iter().enumerate().skip(i + 2) {
+                if i == 0 && j == n - 1 {
+                    continue;
+                }
+
+                if segments_intersect(&a, &b, &c, &d) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl Shape for Polygon {
+    fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| (b - a).length()).sum()
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let distance_squared = self
+            .edges()
+            .map(|(a, b)| (*point - closest_point_on_segment(point, &a, &b)).length_squared())
+            .fold(f64::MAX, f64::min);
+
+        if self.winding_number(point) != 0 {
+            -distance_squared.sqrt()
+        } else {
+            distance_squared.sqrt()
+        }
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(self.vertices.iter().copied())
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        Circle::enclosing(&self.vertices)
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        closest_point_on_edges(point, self.edges())
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        let direction = unit_direction(direction);
+        let t = edges_crossing(origin, &direction, max_distance, self.edges())?;
+
+        Some(hit_at(self, origin, &direction, t))
+    }
+
+    fn centroid(&self) -> Vector2D<f64> {
+        Polygon::centroid(self)
+    }
+
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        AreaIntegrals::polygon(self.vertices()).second_moment_of_area()
+    }
+}
+
+/// A polygon without dents, which can be used wherever a `ConvexShape` is needed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvexPolygon {
+    polygon: Polygon,
+}
+
+impl ConvexPolygon {
+    pub fn new(vertices: Vec<Vector2D<f64>>) -> Self {
+        match Polygon::new(vertices).as_convex() {
+            Some(convex) => convex,
+            None => panic!("Polygon must be convex."),
+        }
+    }
+
+    pub fn polygon(&self) -> &Polygon {
+        &self.polygon
+    }
+
+    pub fn vertices(&self) -> &[Vector2D<f64>] {
+        self.polygon.vertices()
+    }
+}
+
+impl Shape for ConvexPolygon {
+    fn area(&self) -> f64 {
+        self.polygon.area()
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.polygon.perimeter()
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        self.polygon.sdf(point)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        self.polygon.bounding_box()
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        self.polygon.bounding_circle()
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        self.polygon.closest_point(point)
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        self.polygon.ray_intersect(origin, direction, max_distance)
+    }
+
+    fn centroid(&self) -> Vector2D<f64> {
+        self.polygon.centroid()
+    }
+
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        self.polygon.second_moment_of_area()
+    }
+}
+
+impl ConvexShape for ConvexPolygon {
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        furthest_vertex(self.vertices().iter().copied(), direction)
+    }
+}
+
+fn segments_intersect(
+    a: &Vector2D<f64>,
+    b: &Vector2D<f64>,
+    c: &Vector2D<f64>,
+    d: &Vector2D<f64>,
+) -> bool {
+    let d1 = cross_product(&(*b - *a), &(*c - *a));
+    let d2 = cross_product(&(*b - *a), &(*d - *a));
+    let d3 = cross_product(&(*d - *c), &(*a - *c));
+    let d4 = cross_product(&(*d - *c), &(*b - *c));
+
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return true;
+    }
+
+    // touching or collinear overlap
+    let on_segment = |p: &Vector2D<f64>, q: &Vector2D<f64>, r: &Vector2D<f64>| {
+        closest_point_on_segment(r, p, q) == *r
+    };
+
+    (d1 == 0.0 && on_segment(a, b, c))
+        || (d2 == 0.0 && on_segment(a, b, d))
+        || (d3 == 0.0 && on_segment(c, d, a))
+        || (d4 == 0.0 && on_segment(c, d, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::convex::ConvexShape;
+    use crate::numeric::estimate_area_integrals;
+    use crate::polygon::{ConvexPolygon, Polygon};
+    use crate::{get_area, get_sdf, Shape};
+    use vector2d::Vector2D;
+
+    fn l_shape() -> Polygon {
+        Polygon::new(vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(4.0, 0.0),
+            Vector2D::new(4.0, 2.0),
+            Vector2D::new(2.0, 2.0),
+            Vector2D::new(2.0, 4.0),
+            Vector2D::new(0.0, 4.0),
+        ])
+    }
+
+    #[test]
+    fn create_polygon() {
+        let polygon = l_shape();
+
+        assert_eq!(polygon.vertices().len(), 6);
+        assert_eq!(get_area(&polygon), 12.0);
+        assert_eq!(polygon.perimeter(), 16.0);
+        assert_eq!(
+            polygon.centroid(),
+            Vector2D::new(1.6666666666666667, 1.6666666666666667)
+        );
+        assert!(polygon.is_counter_clockwise());
+        assert!(!polygon.is_convex());
+
+        let mut reversed = polygon.vertices().to_vec();
+        reversed.reverse();
+        let reversed = Polygon::new(reversed);
+
+        assert_eq!(reversed.area(), 12.0);
+        assert_eq!(reversed.centroid(), polygon.centroid());
+        assert!(!reversed.is_counter_clockwise());
+    }
+
+    #[test]
+    fn convex_polygon() {
+        let polygon = Polygon::new(vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(2.0, 0.0),
+            Vector2D::new(2.0, 2.0),
+            Vector2D::new(0.0, 2.0),
+        ]);
+
+        assert!(polygon.is_convex());
+        assert_eq!(polygon.centroid(), Vector2D::new(1.0, 1.0));
+
+        let convex = polygon.as_convex().unwrap();
+        assert_eq!(convex.polygon(), &polygon);
+        assert_eq!(convex.area(), 4.0);
+        assert_eq!(convex.sdf(&Vector2D::new(1.0, 3.0)), 1.0);
+        assert_eq!(
+            convex.support(&Vector2D::new(1.0, -0.1)),
+            Vector2D::new(2.0, 0.0)
+        );
+        assert_eq!(l_shape().as_convex(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be convex")]
+    fn convex_polygon_rejects_dents() {
+        ConvexPolygon::new(l_shape().vertices().to_vec());
+    }
+
+    #[test]
+    #[should_panic(expected = "at least three vertices")]
+    fn polygon_needs_three_vertices() {
+        Polygon::new(vec![Vector2D::new(0.0, 0.0), Vector2D::new(1.0, 0.0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero length edges")]
+    fn polygon_rejects_duplicate_vertices() {
+        Polygon::new(vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(0.0, 1.0),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero area")]
+    fn polygon_rejects_collinear_vertices() {
+        Polygon::new(vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(2.0, 0.0),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must not intersect")]
+    fn polygon_rejects_self_intersection() {
+        // lopsided bow tie
+        Polygon::new(vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(3.0, 2.0),
+            Vector2D::new(3.0, 0.0),
+            Vector2D::new(0.0, 3.0),
+        ]);
+    }
+
+    #[test]
+    fn polygon_equality() {
+        assert!(l_shape() == l_shape());
+        assert!(
+            l_shape()
+                != Polygon::new(vec![
+                    Vector2D::new(0.0, 0.0),
+                    Vector2D::new(1.0, 0.0),
+                    Vector2D::new(0.0, 1.0),
+                ])
+        );
+    }
+
+    #[test]
+    fn polygon_sdf() {
+        let polygon = l_shape();
+
+        assert_eq!(get_sdf(&polygon, &Vector2D::new(1.0, 1.0)), -1.0);
+        assert_eq!(polygon.sdf(&Vector2D::new(1.0, 3.0)), -1.0);
+        assert_eq!(polygon.sdf(&Vector2D::new(3.0, 1.0)), -1.0);
+        assert_eq!(polygon.sdf(&Vector2D::new(2.0, 1.0)), -1.0);
+
+        assert_eq!(polygon.sdf(&Vector2D::new(2.0, 0.0)), 0.0);
+        assert_eq!(polygon.sdf(&Vector2D::new(3.0, 3.0)), 1.0);
+        assert_eq!(polygon.sdf(&Vector2D::new(7.0, 6.0)), 5.0);
+        assert_eq!(polygon.sdf(&Vector2D::new(-1.0, 2.0)), 1.0);
+
+        assert_eq!(polygon.winding_number(&Vector2D::new(1.0, 1.0)), 1);
+        assert_eq!(polygon.winding_number(&Vector2D::new(3.0, 3.0)), 0);
+    }
+
+    #[test]
+    fn polygon_closest_point() {
+        let polygon = Polygon::new(vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(10.0, 0.0),
+            Vector2D::new(10.0, 10.0),
+            Vector2D::new(5.0, 4.0),
+            Vector2D::new(0.0, 10.0),
+        ]);
+
+        assert_eq!(
+            polygon.closest_point(&Vector2D::new(5.0, -3.0)),
+            Vector2D::new(5.0, 0.0)
+        );
+        assert_eq!(
+            polygon.closest_point(&Vector2D::new(12.0, 12.0)),
+            Vector2D::new(10.0, 10.0)
+        );
+        assert_eq!(
+            polygon.closest_point(&Vector2D::new(2.0, 1.0)),
+            Vector2D::new(2.0, 0.0)
+        );
+        assert_eq!(
+            polygon.closest_point(&Vector2D::new(5.0, 3.0)),
+            Vector2D::new(5.0, 4.0)
+        );
+    }
+
+    #[test]
+    fn polygon_mass_properties() {
+        let polygon = l_shape();
+        let moments = polygon.second_moment_of_area();
+
+        assert!((polygon.centroid() - Vector2D::new(5.0, 5.0) / 3.0).length() < 1e-12);
+        assert!((moments.ixx() - 44.0 / 3.0).abs() < 1e-12);
+        assert!((moments.iyy() - 44.0 / 3.0).abs() < 1e-12);
+        assert!((moments.ixy() + 16.0 / 3.0).abs() < 1e-12);
+
+        let estimate = estimate_area_integrals(&polygon);
+        let estimated = estimate.second_moment_of_area();
+        assert!((estimate.centroid() - polygon.centroid()).length() < 1e-3);
+        assert!((estimated.ixx() - moments.ixx()).abs() / moments.polar() < 1e-3);
+        assert!((estimated.iyy() - moments.iyy()).abs() / moments.polar() < 1e-3);
+        assert!((estimated.ixy() - moments.ixy()).abs() / moments.polar() < 1e-3);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{closest_point_on_segment, cross_product, dot_product, Shape};
+use std::f64::consts::PI;
+use vector2d::Vector2D;
+
+/// An open chain of segments stroked with round caps and round joins.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polyline {
+    points: Vec<Vector2D<f64>>,
+    thickness: f64, // full stroke width
+}
+
+impl Polyline {
+    pub fn new(points: Vec<Vector2D<f64>>, thickness: f64) -> Self {
+        if points.len() < 2 {
+            panic!("Polyline must have at least two points.");
+        }
+
+        Polyline { points, thickness }
+    }
+
+    pub fn points(&self) -> &[Vector2D<f64>] {
+        &self.points
+    }
+
+    pub fn thickness(&self) -> f64 {
+        self.thickness
+    }
+
+    pub fn length(&self) -> f64 {
+        self.points.windows(2).map(|w| (w[1] - w[0]).length()).sum()
+    }
+
+    /// Turning angle (0 for straight on, approaching PI for a hairpin) at each inner point.
+    fn turning_angles(&self) -> impl Iterator<Item = f64> + '_ {
+        self.points.windows(3).map(|w| {
+            let incoming = w[1] - w[0];
+            let outgoing = w[2] - w[1];
+
+            cross_product(&incoming, &outgoing)
+                .atan2(dot_product(&incoming, &outgoing))
+                .abs()
+        })
+    }
+}
+
+impl Shape for Polyline {
+    /// Exact as long as the stroke only overlaps itself at the joins, i.e. the inner corner of
+    /// every join stays within its two segments and non-adjacent segments keep apart.
+    fn area(&self) -> f64 {
+        let r = self.thickness * 0.5;
+        let joins: f64 = self
+            .turning_angles()
+            .map(|angle| 0.5 * angle - (0.5 * angle).tan())
+            .sum();
+
+        self.thickness * self.length() + PI * r * r + r * r * joins
+    }
+
+    /// Exact under the same conditions as the area.
+    fn perimeter(&self) -> f64 {
+        let r = self.thickness * 0.5;
+        let joins: f64 = self
+            .turning_angles()
+            .map(|angle| angle - 2.0 * (0.5 * angle).tan())
+            .sum();
+
+        2.0 * self.length() + 2.0 * PI * r + r * joins
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let distance = self
+            .points
+            .windows(2)
+            .map(|w| (*point - closest_point_on_segment(point, &w[0], &w[1])).length())
+            .fold(f64::MAX, f64::min);
+
+        distance - self.thickness * 0.5
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::polyline::Polyline;
+    use crate::{get_area, get_sdf, Shape};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    #[test]
+    fn create_polyline() {
+        let polyline = Polyline::new(vec![Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 0.0)], 2.0);
+
+        assert_eq!(polyline.points().len(), 2);
+        assert_eq!(polyline.thickness(), 2.0);
+        assert_eq!(polyline.length(), 10.0);
+
+        // a single stroke is a capsule
+        assert_eq!(get_area(&polyline), 20.0 + PI);
+        assert_eq!(polyline.perimeter(), 20.0 + 2.0 * PI);
+    }
+
+    #[test]
+    #[should_panic]
+    fn polyline_needs_two_points() {
+        Polyline::new(vec![Vector2D::new(0.0, 0.0)], 1.0);
+    }
+
+    #[test]
+    fn polyline_right_angle() {
+        let polyline = Polyline::new(
+            vec![
+                Vector2D::new(0.0, 0.0),
+                Vector2D::new(10.0, 0.0),
+                Vector2D::new(10.0, 10.0),
+            ],
+            2.0,
+        );
+
+        // two 10 x 2 strokes overlapping in a unit square, a quarter disc filling the outer
+        // corner and two half disc caps
+        assert!((polyline.area() - (40.0 - 1.0 + PI / 4.0 + PI)).abs() < 1e-12);
+        // outer side 11 + 11 minus the corner plus a quarter arc, inner side 9 + 9, two caps
+        assert!((polyline.perimeter() - (20.0 + PI / 2.0 + 18.0 + 2.0 * PI)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn polyline_sdf() {
+        let polyline = Polyline::new(
+            vec![
+                Vector2D::new(0.0, 0.0),
+                Vector2D::new(10.0, 0.0),
+                Vector2D::new(10.0, 10.0),
+            ],
+            2.0,
+        );
+
+        assert_eq!(get_sdf(&polyline, &Vector2D::new(5.0, 0.0)), -1.0);
+        assert_eq!(polyline.sdf(&Vector2D::new(10.0, 5.0)), -1.0);
+        assert_eq!(polyline.sdf(&Vector2D::new(5.0, 5.0)), 4.0);
+        assert_eq!(polyline.sdf(&Vector2D::new(13.0, -4.0)), 4.0);
+        assert_eq!(polyline.sdf(&Vector2D::new(-1.0, 0.0)), 0.0);
+    }
+}
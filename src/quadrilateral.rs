@@ -0,0 +1,722 @@
+use crate::bounds::{BoundingBox, OrientedBoundingBox};
+use crate::circle::Circle;
+use crate::convex::{furthest_vertex, ConvexShape};
+use crate::mass::{AreaIntegrals, SecondMomentOfArea};
+use crate::ray::{edges_crossing, hit_at, unit_direction, RayHit};
+use crate::{
+    closest_point_on_edges, closest_point_on_segment, cross_product, to_local, to_world, Shape,
+};
+use vector2d::Vector2D;
+
+/// A rhombus (diamond) with its diagonals along the x and y axes before rotation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rhombus {
+    center: Vector2D<f64>,
+    diagonals: Vector2D<f64>, // x: horizontal diagonal, y: vertical diagonal
+    rotation_angle_in_degrees: f64,
+}
+
+impl Rhombus {
+    pub fn new(
+        center: Vector2D<f64>,
+        diagonals: Vector2D<f64>,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        if diagonals.x <= 0.0 || diagonals.y <= 0.0 {
+            panic!("Rhombus diagonals must be greater than zero.");
+        }
+
+        Rhombus {
+            center,
+            diagonals,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn diagonals(&self) -> Vector2D<f64> {
+        self.diagonals
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+
+    pub fn side_length(&self) -> f64 {
+        (self.diagonals * 0.5).length()
+    }
+
+    /// Right, top, left and bottom corners (counter-clockwise).
+    pub fn vertices(&self) -> [Vector2D<f64>; 4] {
+        to_world(
+            self.local_vertices(),
+            self.center,
+            self.rotation_angle_in_degrees,
+        )
+    }
+
+    fn local_vertices(&self) -> [Vector2D<f64>; 4] {
+        let half = self.diagonals * 0.5;
+
+        [
+            Vector2D::new(half.x, 0.0),
+            Vector2D::new(0.0, half.y),
+            Vector2D::new(-half.x, 0.0),
+            Vector2D::new(0.0, -half.y),
+        ]
+    }
+}
+
+impl Shape for Rhombus {
+    fn area(&self) -> f64 {
+        self.diagonals.x * self.diagonals.y * 0.5
+    }
+
+    fn perimeter(&self) -> f64 {
+        4.0 * self.side_length()
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let translated = to_local(point, self.center, self.rotation_angle_in_degrees);
+
+        convex_quadrilateral_sdf(&self.local_vertices(), &translated)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(self.vertices())
+    }
+
+    fn oriented_bounding_box(&self) -> OrientedBoundingBox {
+        oriented_bounds(
+            &self.local_vertices(),
+            self.center,
+            self.rotation_angle_in_degrees,
+        )
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        Circle::enclosing(&self.vertices())
+    }
+
+    fn largest_inscribed_circle(&self) -> Circle {
+        // touches all four sides, at the height of a side triangle over the center
+        Circle::new(self.center, self.area() / (2.0 * self.side_length()))
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        closest_point_on_quadrilateral(point, &self.vertices())
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        quadrilateral_ray_intersect(self, &self.vertices(), origin, direction, max_distance)
+    }
+
+    fn centroid(&self) -> Vector2D<f64> {
+        AreaIntegrals::polygon(&self.vertices()).centroid()
+    }
+
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        AreaIntegrals::polygon(&self.vertices()).second_moment_of_area()
+    }
+}
+
+impl ConvexShape for Rhombus {
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        furthest_vertex(self.vertices(), direction)
+    }
+}
+
+/// A trapezoid with horizontal parallel sides before rotation. The center sits halfway up,
+/// with the bottom side centered under it and the top side shifted along x by `top_offset`;
+/// a zero offset gives an isosceles trapezoid.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Trapezoid {
+    center: Vector2D<f64>,
+    bottom_width: f64,
+    top_width: f64,
+    height: f64,
+    top_offset: f64,
+    rotation_angle_in_degrees: f64,
+}
+
+impl Trapezoid {
+    pub fn new(
+        center: Vector2D<f64>,
+        bottom_width: f64,
+        top_width: f64,
+        height: f64,
+        top_offset: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        if bottom_width < 0.0 || top_width < 0.0 || bottom_width + top_width == 0.0 {
+            panic!("Trapezoid widths must not be negative and at least one must be non-zero.");
+        }
+
+        if height <= 0.0 {
+            panic!("Trapezoid height must be greater than zero.");
+        }
+
+        Trapezoid {
+            center,
+            bottom_width,
+            top_width,
+            height,
+            top_offset,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn new_isosceles(
+        center: Vector2D<f64>,
+        bottom_width: f64,
+        top_width: f64,
+        height: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        Trapezoid::new(
+            center,
+            bottom_width,
+            top_width,
+            height,
+            0.0,
+            rotation_angle_in_degrees,
+        )
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn bottom_width(&self) -> f64 {
+        self.bottom_width
+    }
+
+    pub fn top_width(&self) -> f64 {
+        self.top_width
+    }
+
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    pub fn top_offset(&self) -> f64 {
+        self.top_offset
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+
+    pub fn is_isosceles(&self) -> bool {
+        self.top_offset == 0.0
+    }
+
+    /// The left and right legs (the non-parallel sides).
+    pub fn leg_lengths(&self) -> (f64, f64) {
+        let [bottom_right, top_right, top_left, bottom_left] = self.local_vertices();
+
+        (
+            (top_left - bottom_left).length(),
+            (top_right - bottom_right).length(),
+        )
+    }
+
+    /// Bottom right, top right, top left and bottom left corners (counter-clockwise).
+    pub fn vertices(&self) -> [Vector2D<f64>; 4] {
+        to_world(
+            self.local_vertices(),
+            self.center,
+            self.rotation_angle_in_degrees,
+        )
+    }
+
+    fn local_vertices(&self) -> [Vector2D<f64>; 4] {
+        let half_height = self.height * 0.5;
+        let half_bottom = self.bottom_width * 0.5;
+        let half_top = self.top_width * 0.5;
+
+        [
+            Vector2D::new(half_bottom, -half_height),
+            Vector2D::new(self.top_offset + half_top, half_height),
+            Vector2D::new(self.top_offset - half_top, half_height),
+            Vector2D::new(-half_bottom, -half_height),
+        ]
+    }
+}
+
+impl Shape for Trapezoid {
+    fn area(&self) -> f64 {
+        (self.bottom_width + self.top_width) * 0.5 * self.height
+    }
+
+    fn perimeter(&self) -> f64 {
+        let (left, right) = self.leg_lengths();
+
+        self.bottom_width + self.top_width + left + right
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let translated = to_local(point, self.center, self.rotation_angle_in_degrees);
+
+        convex_quadrilateral_sdf(&self.local_vertices(), &translated)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(self.vertices())
+    }
+
+    fn oriented_bounding_box(&self) -> OrientedBoundingBox {
+        oriented_bounds(
+            &self.local_vertices(),
+            self.center,
+            self.rotation_angle_in_degrees,
+        )
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        Circle::enclosing(&self.vertices())
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        closest_point_on_quadrilateral(point, &self.vertices())
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        quadrilateral_ray_intersect(self, &self.vertices(), origin, direction, max_distance)
+    }
+
+    fn centroid(&self) -> Vector2D<f64> {
+        AreaIntegrals::polygon(&self.vertices()).centroid()
+    }
+
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        AreaIntegrals::polygon(&self.vertices()).second_moment_of_area()
+    }
+}
+
+impl ConvexShape for Trapezoid {
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        furthest_vertex(self.vertices(), direction)
+    }
+}
+
+/// A parallelogram with horizontal top and bottom sides before rotation; the top side is
+/// shifted along x by `skew` relative to the bottom side.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Parallelogram {
+    center: Vector2D<f64>,
+    width: f64,
+    height: f64,
+    skew: f64,
+    rotation_angle_in_degrees: f64,
+}
+
+impl Parallelogram {
+    pub fn new(
+        center: Vector2D<f64>,
+        width: f64,
+        height: f64,
+        skew: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        if width <= 0.0 || height <= 0.0 {
+            panic!("Parallelogram width and height must be greater than zero.");
+        }
+
+        Parallelogram {
+            center,
+            width,
+            height,
+            skew,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    pub fn skew(&self) -> f64 {
+        self.skew
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+
+    pub fn side_length(&self) -> f64 {
+        // length of the slanted sides
+        Vector2D::new(self.skew, self.height).length()
+    }
+
+    /// Bottom right, top right, top left and bottom left corners (counter-clockwise).
+    pub fn vertices(&self) -> [Vector2D<f64>; 4] {
+        to_world(
+            self.local_vertices(),
+            self.center,
+            self.rotation_angle_in_degrees,
+        )
+    }
+
+    fn local_vertices(&self) -> [Vector2D<f64>; 4] {
+        let half_width = self.width * 0.5;
+        let half_height = self.height * 0.5;
+        let half_skew = self.skew * 0.5;
+
+        [
+            Vector2D::new(half_width - half_skew, -half_height),
+            Vector2D::new(half_width + half_skew, half_height),
+            Vector2D::new(-half_width + half_skew, half_height),
+            Vector2D::new(-half_width - half_skew, -half_height),
+        ]
+    }
+}
+
+impl Shape for Parallelogram {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.side_length())
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let translated = to_local(point, self.center, self.rotation_angle_in_degrees);
+
+        convex_quadrilateral_sdf(&self.local_vertices(), &translated)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(self.vertices())
+    }
+
+    fn oriented_bounding_box(&self) -> OrientedBoundingBox {
+        oriented_bounds(
+            &self.local_vertices(),
+            self.center,
+            self.rotation_angle_in_degrees,
+        )
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        Circle::enclosing(&self.vertices())
+    }
+
+    fn largest_inscribed_circle(&self) -> Circle {
+        // limited by the nearer of the two pairs of parallel sides
+        let slanted_distance = self.area() / self.side_length();
+
+        Circle::new(self.center, self.height.min(slanted_distance) * 0.5)
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        closest_point_on_quadrilateral(point, &self.vertices())
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        quadrilateral_ray_intersect(self, &self.vertices(), origin, direction, max_distance)
+    }
+
+    fn centroid(&self) -> Vector2D<f64> {
+        AreaIntegrals::polygon(&self.vertices()).centroid()
+    }
+
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        AreaIntegrals::polygon(&self.vertices()).second_moment_of_area()
+    }
+}
+
+impl ConvexShape for Parallelogram {
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        furthest_vertex(self.vertices(), direction)
+    }
+}
+
+/// Exact distance to a convex quadrilateral whose vertices are in counter-clockwise order.
+fn convex_quadrilateral_sdf(vertices: &[Vector2D<f64>; 4], point: &Vector2D<f64>) -> f64 {
+    let mut distance_squared = f64::MAX;
+    let mut inside = true;
+
+    for i in 0..4 {
+        let a = vertices[i];
+        let b = vertices[(i + 1) % 4];
+
+        if a == b {
+            // a trapezoid with a zero width side degenerates into a triangle
+            continue;
+        }
+
+        distance_squared = distance_squared
+            .min((*point - closest_point_on_segment(point, &a, &b)).length_squared());
+        inside &= cross_product(&(b - a), &(*point - a)) >= 0.0;
+    }
+
+    if inside {
+        -distance_squared.sqrt()
+    } else {
+        distance_squared.sqrt()
+    }
+}
+
+fn closest_point_on_quadrilateral(
+    point: &Vector2D<f64>,
+    vertices: &[Vector2D<f64>; 4],
+) -> Vector2D<f64> {
+    closest_point_on_edges(point, (0..4).map(|i| (vertices[i], vertices[(i + 1) % 4])))
+}
+
+fn quadrilateral_ray_intersect<S: Shape>(
+    shape: &S,
+    vertices: &[Vector2D<f64>; 4],
+    origin: &Vector2D<f64>,
+    direction: &Vector2D<f64>,
+    max_distance: f64,
+) -> Option<RayHit> {
+    let direction = unit_direction(direction);
+    let edges = (0..4).map(|i| (vertices[i], vertices[(i + 1) % 4]));
+    let t = edges_crossing(origin, &direction, max_distance, edges)?;
+
+    Some(hit_at(shape, origin, &direction, t))
+}
+
+/// The box around `local_vertices` in the shape's own frame, turned along with the shape.
+fn oriented_bounds(
+    local_vertices: &[Vector2D<f64>; 4],
+    center: Vector2D<f64>,
+    rotation_angle_in_degrees: f64,
+) -> OrientedBoundingBox {
+    let local = BoundingBox::from_points(*local_vertices);
+    let [local_center] = to_world([local.center()], center, rotation_angle_in_degrees);
+
+    OrientedBoundingBox::new(
+        local_center,
+        local.dimensions() * 0.5,
+        rotation_angle_in_degrees,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bounds::BoundingBox;
+    use crate::quadrilateral::{Parallelogram, Rhombus, Trapezoid};
+    use crate::{get_area, get_sdf, Shape};
+    use vector2d::Vector2D;
+
+    #[test]
+    fn create_rhombus() {
+        let rhombus = Rhombus::new(Vector2D::new(10.0, 10.0), Vector2D::new(8.0, 6.0), 0.0);
+
+        assert_eq!(
+            format!("The rhombus is: {rhombus:?}"),
+            "The rhombus is: Rhombus { center: Vector2D { x: 10.0, y: 10.0 }, diagonals: Vector2D { x: 8.0, y: 6.0 }, rotation_angle_in_degrees: 0.0 }"
+        );
+
+        assert_eq!(rhombus.side_length(), 5.0);
+        assert_eq!(get_area(&rhombus), 24.0);
+        assert_eq!(rhombus.perimeter(), 20.0);
+        assert_eq!(rhombus.vertices()[1], Vector2D::new(10.0, 13.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "greater than zero")]
+    fn rhombus_needs_positive_diagonals() {
+        Rhombus::new(Vector2D::new(0.0, 0.0), Vector2D::new(0.0, 6.0), 0.0);
+    }
+
+    #[test]
+    fn rhombus_equality() {
+        let rhombus1 = Rhombus::new(Vector2D::new(10.0, 10.0), Vector2D::new(8.0, 6.0), 0.0);
+        let rhombus2 = Rhombus::new(Vector2D::new(10.0, 10.0), Vector2D::new(8.0, 6.0), 0.0);
+        let rhombus3 = Rhombus::new(Vector2D::new(10.0, 10.0), Vector2D::new(8.0, 6.0), 45.0);
+
+        assert!(rhombus1 == rhombus2);
+        assert!(rhombus1 != rhombus3);
+    }
+
+    #[test]
+    fn rhombus_sdf() {
+        let rhombus = Rhombus::new(Vector2D::new(0.0, 0.0), Vector2D::new(8.0, 6.0), 0.0);
+
+        // the edge from (4, 0) to (0, 3) is 2.4 from the center
+        assert!((get_sdf(&rhombus, &Vector2D::new(0.0, 0.0)) + 2.4).abs() < 1e-12);
+        assert_eq!(rhombus.sdf(&Vector2D::new(4.0, 0.0)), 0.0);
+        assert_eq!(rhombus.sdf(&Vector2D::new(6.0, 0.0)), 2.0);
+        assert_eq!(rhombus.sdf(&Vector2D::new(0.0, -5.0)), 2.0);
+        assert!((rhombus.sdf(&Vector2D::new(4.0, 3.0)) - 2.4).abs() < 1e-12);
+
+        let rotated = Rhombus::new(Vector2D::new(0.0, 0.0), Vector2D::new(8.0, 6.0), 90.0);
+        assert!((rotated.sdf(&Vector2D::new(0.0, 6.0)) - 2.0).abs() < 1e-12);
+        assert!((rotated.sdf(&Vector2D::new(3.0, 0.0))).abs() < 1e-12);
+    }
+
+    #[test]
+    fn create_trapezoid() {
+        let trapezoid = Trapezoid::new_isosceles(Vector2D::new(0.0, 0.0), 10.0, 4.0, 4.0, 0.0);
+
+        assert_eq!(
+            format!("The trapezoid is: {trapezoid:?}"),
+            "The trapezoid is: Trapezoid { center: Vector2D { x: 0.0, y: 0.0 }, bottom_width: 10.0, top_width: 4.0, height: 4.0, top_offset: 0.0, rotation_angle_in_degrees: 0.0 }"
+        );
+
+        assert!(trapezoid.is_isosceles());
+        assert_eq!(trapezoid.leg_lengths(), (5.0, 5.0));
+        assert_eq!(get_area(&trapezoid), 28.0);
+        assert_eq!(trapezoid.perimeter(), 24.0);
+
+        let general = Trapezoid::new(Vector2D::new(0.0, 0.0), 10.0, 4.0, 8.0, 3.0, 0.0);
+
+        assert!(!general.is_isosceles());
+        assert_eq!(general.leg_lengths(), (10.0, 8.0));
+        assert_eq!(general.area(), 56.0);
+        assert_eq!(general.perimeter(), 32.0);
+        assert_eq!(general.vertices()[1], Vector2D::new(5.0, 4.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "height must be greater than zero")]
+    fn trapezoid_needs_height() {
+        Trapezoid::new_isosceles(Vector2D::new(0.0, 0.0), 10.0, 4.0, 0.0, 0.0);
+    }
+
+    #[test]
+    fn trapezoid_equality() {
+        let trapezoid1 = Trapezoid::new_isosceles(Vector2D::new(0.0, 0.0), 10.0, 4.0, 4.0, 0.0);
+        let trapezoid2 = Trapezoid::new(Vector2D::new(0.0, 0.0), 10.0, 4.0, 4.0, 0.0, 0.0);
+        let trapezoid3 = Trapezoid::new(Vector2D::new(0.0, 0.0), 10.0, 4.0, 4.0, 1.0, 0.0);
+
+        assert!(trapezoid1 == trapezoid2);
+        assert!(trapezoid1 != trapezoid3);
+    }
+
+    #[test]
+    fn trapezoid_sdf() {
+        let trapezoid = Trapezoid::new_isosceles(Vector2D::new(0.0, 0.0), 10.0, 4.0, 4.0, 0.0);
+
+        assert_eq!(get_sdf(&trapezoid, &Vector2D::new(0.0, 0.0)), -2.0);
+        assert_eq!(trapezoid.sdf(&Vector2D::new(0.0, -2.0)), 0.0);
+        assert_eq!(trapezoid.sdf(&Vector2D::new(0.0, 5.0)), 3.0);
+        assert_eq!(trapezoid.sdf(&Vector2D::new(8.0, -2.0)), 3.0);
+        // the legs run 3 across for every 4 up
+        assert!((trapezoid.sdf(&Vector2D::new(7.5, 2.0)) - 4.4).abs() < 1e-12);
+
+        let triangle = Trapezoid::new_isosceles(Vector2D::new(0.0, 0.0), 4.0, 0.0, 4.0, 0.0);
+        assert_eq!(triangle.area(), 8.0);
+        assert_eq!(triangle.sdf(&Vector2D::new(0.0, 5.0)), 3.0);
+
+        let rotated = Trapezoid::new_isosceles(Vector2D::new(0.0, 0.0), 10.0, 4.0, 4.0, 180.0);
+        assert!((rotated.sdf(&Vector2D::new(0.0, -5.0)) - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn create_parallelogram() {
+        let parallelogram = Parallelogram::new(Vector2D::new(0.0, 0.0), 6.0, 4.0, 3.0, 0.0);
+
+        assert_eq!(
+            format!("The parallelogram is: {parallelogram:?}"),
+            "The parallelogram is: Parallelogram { center: Vector2D { x: 0.0, y: 0.0 }, width: 6.0, height: 4.0, skew: 3.0, rotation_angle_in_degrees: 0.0 }"
+        );
+
+        assert_eq!(parallelogram.side_length(), 5.0);
+        assert_eq!(get_area(&parallelogram), 24.0);
+        assert_eq!(parallelogram.perimeter(), 22.0);
+        assert_eq!(parallelogram.vertices()[1], Vector2D::new(4.5, 2.0));
+    }
+
+    #[test]
+    fn parallelogram_equality() {
+        let parallelogram1 = Parallelogram::new(Vector2D::new(0.0, 0.0), 6.0, 4.0, 3.0, 0.0);
+        let parallelogram2 = Parallelogram::new(Vector2D::new(0.0, 0.0), 6.0, 4.0, 3.0, 0.0);
+        let parallelogram3 = Parallelogram::new(Vector2D::new(0.0, 0.0), 6.0, 4.0, -3.0, 0.0);
+
+        assert!(parallelogram1 == parallelogram2);
+        assert!(parallelogram1 != parallelogram3);
+    }
+
+    #[test]
+    fn parallelogram_sdf() {
+        let parallelogram = Parallelogram::new(Vector2D::new(0.0, 0.0), 6.0, 4.0, 3.0, 0.0);
+
+        assert_eq!(get_sdf(&parallelogram, &Vector2D::new(0.0, 0.0)), -2.0);
+        assert_eq!(parallelogram.sdf(&Vector2D::new(0.0, 2.0)), 0.0);
+        assert_eq!(parallelogram.sdf(&Vector2D::new(0.0, 5.0)), 3.0);
+        assert_eq!(parallelogram.sdf(&Vector2D::new(7.5, 2.0)), 3.0);
+        assert!(parallelogram.sdf(&Vector2D::new(-4.0, 1.5)) > 0.0);
+        assert!(parallelogram.sdf(&Vector2D::new(4.0, 1.5)) < 0.0);
+
+        let rectangle = Parallelogram::new(Vector2D::new(0.0, 0.0), 6.0, 4.0, 0.0, 90.0);
+        assert!((rectangle.sdf(&Vector2D::new(0.0, 3.0))).abs() < 1e-12);
+        assert!((rectangle.sdf(&Vector2D::new(2.0, 0.0))).abs() < 1e-12);
+    }
+
+    #[test]
+    fn quadrilateral_bounding_boxes() {
+        let rhombus = Rhombus::new(Vector2D::new(10.0, 10.0), Vector2D::new(8.0, 6.0), 90.0);
+        let bounds = rhombus.bounding_box();
+        assert!((bounds.width() - 6.0).abs() < 1e-12);
+        assert!((bounds.height() - 8.0).abs() < 1e-12);
+        let oriented = rhombus.oriented_bounding_box();
+        assert_eq!(oriented.half_extents(), Vector2D::new(4.0, 3.0));
+        assert_eq!(oriented.center(), Vector2D::new(10.0, 10.0));
+
+        // the top side sticks out to the right of the bottom one
+        let trapezoid = Trapezoid::new(Vector2D::new(0.0, 0.0), 10.0, 4.0, 6.0, 5.0, 0.0);
+        assert_eq!(
+            trapezoid.bounding_box(),
+            BoundingBox::new(Vector2D::new(-5.0, -3.0), Vector2D::new(7.0, 3.0))
+        );
+        assert_eq!(
+            trapezoid.oriented_bounding_box().center(),
+            Vector2D::new(1.0, 0.0)
+        );
+
+        let parallelogram = Parallelogram::new(Vector2D::new(0.0, 0.0), 10.0, 4.0, 2.0, 0.0);
+        assert_eq!(
+            parallelogram.bounding_box(),
+            BoundingBox::new(Vector2D::new(-6.0, -2.0), Vector2D::new(6.0, 2.0))
+        );
+    }
+
+    #[test]
+    fn quadrilateral_bounding_and_inscribed_circles() {
+        let rhombus = Rhombus::new(Vector2D::new(10.0, 10.0), Vector2D::new(8.0, 6.0), 30.0);
+        assert!((rhombus.bounding_circle().radius() - 4.0).abs() < 1e-12);
+        let inscribed = rhombus.largest_inscribed_circle();
+        assert_eq!(inscribed.radius(), 2.4);
+        assert!((rhombus.sdf(&inscribed.center()) + 2.4).abs() < 1e-12);
+
+        // the slanted sides are 4 * 10 / 5 = 8 apart, the horizontal ones 4
+        let parallelogram = Parallelogram::new(Vector2D::new(0.0, 0.0), 10.0, 4.0, 3.0, 0.0);
+        assert_eq!(parallelogram.largest_inscribed_circle().radius(), 2.0);
+        let steep = Parallelogram::new(Vector2D::new(0.0, 0.0), 3.0, 4.0, 3.0, 0.0);
+        let inscribed = steep.largest_inscribed_circle();
+        assert!((inscribed.radius() - 1.2).abs() < 1e-12);
+        assert!((steep.sdf(&inscribed.center()) + 1.2).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::bounds::BoundingBox;
+use crate::{cross_product, dot_product, Shape};
+use vector2d::Vector2D;
+
+/// Where a ray first crosses a shape's outline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit {
+    distance: f64,
+    point: Vector2D<f64>,
+    normal: Vector2D<f64>,
+}
+
+impl RayHit {
+    pub fn new(distance: f64, point: Vector2D<f64>, normal: Vector2D<f64>) -> Self {
+        RayHit {
+            distance,
+            point,
+            normal,
+        }
+    }
+
+    /// How far along the (unit) ray direction the hit is.
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    pub fn point(&self) -> Vector2D<f64> {
+        self.point
+    }
+
+    /// The shape's outward unit normal at the hit point.
+    pub fn normal(&self) -> Vector2D<f64> {
+        self.normal
+    }
+}
+
+/// The hit `t` along the ray (`direction` of unit length), with the shape's normal there.
+pub(crate) fn hit_at<S: Shape + ?Sized>(
+    shape: &S,
+    origin: &Vector2D<f64>,
+    direction: &Vector2D<f64>,
+    t: f64,
+) -> RayHit {
+    let point = *origin + *direction * t;
+
+    RayHit::new(t, point, shape.normal(&point))
+}
+
+/// The nearest of the crossings `ts` that is ahead of the origin and within `max_distance`.
+pub(crate) fn first_crossing(ts: impl IntoIterator<Item = f64>, max_distance: f64) -> Option<f64> {
+    ts.into_iter()
+        .filter(|t| (0.0..=max_distance).contains(t))
+        .fold(None, |first: Option<f64>, t| {
+            Some(first.map_or(t, |first| first.min(t)))
+        })
+}
+
+/// The first crossing of a ray (`direction` of unit length) with an outline made of `edges`.
+pub(crate) fn edges_crossing(
+    origin: &Vector2D<f64>,
+    direction: &Vector2D<f64>,
+    max_distance: f64,
+    edges: impl IntoIterator<Item = (Vector2D<f64>, Vector2D<f64>)>,
+) -> Option<f64> {
+    first_crossing(
+        edges
+            .into_iter()
+            .filter_map(|(a, b)| segment_crossing(origin, direction, &a, &b)),
+        max_distance,
+    )
+}
+
+/// `direction` scaled to unit length.
+pub(crate) fn unit_direction(direction: &Vector2D<f64>) -> Vector2D<f64> {
+    let length = direction.length();
+    if !(length > 0.0 && length.is_finite()) {
+        panic!("Ray direction must not be zero.");
+    }
+
+    *direction / length
+}
+
+/// Where along the ray (`direction` of unit length) it crosses the segment from `a` to `b`.
+/// A ray running along the segment does not cross it.
+pub(crate) fn segment_crossing(
+    origin: &Vector2D<f64>,
+    direction: &Vector2D<f64>,
+    a: &Vector2D<f64>,
+    b: &Vector2D<f64>,
+) -> Option<f64> {
+    let edge = *b - *a;
+    let denominator = cross_product(direction, &edge);
+    if denominator == 0.0 {
+        return None;
+    }
+
+    let to_a = *a - *origin;
+    let t = cross_product(&to_a, &edge) / denominator;
+    let s = cross_product(&to_a, direction) / denominator;
+
+    (t >= 0.0 && (0.0..=1.0).contains(&s)).then_some(t)
+}
+
+/// Where along the ray (`direction` of unit length) it crosses the circle, nearest first.
+/// A ray that only touches the circle crosses it twice in the same place.
+pub(crate) fn circle_crossings(
+    origin: &Vector2D<f64>,
+    direction: &Vector2D<f64>,
+    center: &Vector2D<f64>,
+    radius: f64,
+) -> Option<[f64; 2]> {
+    let m = *origin - *center;
+    let b = dot_product(&m, direction);
+    let discriminant = b * b - (m.length_squared() - radius * radius);
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let root = discriminant.sqrt();
+
+    Some([-b - root, -b + root])
+}
+
+/// The stretch of the ray (`direction` of unit length) inside `bounds`, clipped to start no
+/// earlier than the origin.
+pub(crate) fn bounds_interval(
+    bounds: &BoundingBox,
+    origin: &Vector2D<f64>,
+    direction: &Vector2D<f64>,
+) -> Option<(f64, f64)> {
+    let mut near: f64 = 0.0;
+    let mut far = f64::INFINITY;
+
+    for (o, d, min, max) in [
+        (origin.x, direction.x, bounds.min().x, bounds.max().x),
+        (origin.y, direction.y, bounds.min().y, bounds.max().y),
+    ] {
+        if d == 0.0 {
+            if o < min || o > max {
+                return None;
+            }
+        } else {
+            let (a, b) = ((min - o) / d, (max - o) / d);
+            near = near.max(a.min(b));
+            far = far.min(a.max(b));
+        }
+    }
+
+    (near <= far).then_some((near, far))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bounds::BoundingBox;
+    use crate::ray::{bounds_interval, circle_crossings, segment_crossing, unit_direction};
+    use vector2d::Vector2D;
+
+    #[test]
+    fn ray_crossings() {
+        let origin = Vector2D::new(0.0, 0.0);
+        let right = Vector2D::new(1.0, 0.0);
+
+        let a = Vector2D::new(5.0, -1.0);
+        let b = Vector2D::new(5.0, 1.0);
+        assert_eq!(segment_crossing(&origin, &right, &a, &b), Some(5.0));
+        assert_eq!(segment_crossing(&origin, &-right, &a, &b), None);
+        assert_eq!(
+            segment_crossing(&origin, &right, &a, &Vector2D::new(5.0, -0.5)),
+            None
+        );
+
+        assert_eq!(
+            circle_crossings(&origin, &right, &Vector2D::new(10.0, 0.0), 2.0),
+            Some([8.0, 12.0])
+        );
+        assert_eq!(
+            circle_crossings(&origin, &right, &Vector2D::new(10.0, 2.0), 2.0),
+            Some([10.0, 10.0])
+        );
+        assert_eq!(
+            circle_crossings(&origin, &right, &Vector2D::new(10.0, 3.0), 2.0),
+            None
+        );
+
+        let bounds = BoundingBox::new(Vector2D::new(2.0, -1.0), Vector2D::new(4.0, 1.0));
+        assert_eq!(bounds_interval(&bounds, &origin, &right), Some((2.0, 4.0)));
+        assert_eq!(bounds_interval(&bounds, &origin, &-right), None);
+        assert_eq!(
+            bounds_interval(&bounds, &Vector2D::new(3.0, 0.0), &right),
+            Some((0.0, 1.0))
+        );
+
+        assert_eq!(
+            unit_direction(&Vector2D::new(0.0, -4.0)),
+            Vector2D::new(0.0, -1.0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Ray direction must not be zero")]
+    fn ray_needs_a_direction() {
+        unit_direction(&Vector2D::new(0.0, 0.0));
+    }
+}
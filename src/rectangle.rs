@@ -1,5 +1,13 @@
+use crate::bounds::{BoundingBox, OrientedBoundingBox};
+use crate::circle::Circle;
+use crate::convex::{furthest_vertex, ConvexShape};
+use crate::mass::{AreaIntegrals, SecondMomentOfArea};
+use crate::ray::{
+    circle_crossings, first_crossing, hit_at, segment_crossing, unit_direction, RayHit,
+};
 use crate::{
-    abs_vector, length_vector, max_f64, max_vector, min_f64, rotate_vector_by_degrees, Shape,
+    abs_vector, length_vector, max_f64, max_vector, min_f64, numeric, rotate_vector_by_degrees,
+    Shape,
 };
 use std::f64::consts::PI;
 use vector2d::Vector2D;
@@ -115,6 +123,56 @@ impl Rectangle {
         self.rotate_point(v)
     }
 
+    /// The radius of all four corners, if they are rounded alike.
+    pub(crate) fn corner_radius(&self) -> Option<f64> {
+        let r = self.round_factors.top_left;
+        let RoundFactors {
+            top_right,
+            bottom_left,
+            bottom_right,
+            ..
+        } = self.round_factors;
+
+        ([top_right, bottom_left, bottom_right] == [r, r, r]).then_some(r)
+    }
+
+    /// A rounded rectangle is the hull of its four corner circles (points when not rounded):
+    /// their centers in the rectangle's own frame, and their radii.
+    fn corner_circles(&self) -> [(Vector2D<f64>, f64); 4] {
+        let half = self.dimensions() * 0.5;
+
+        [
+            (1.0, 1.0, self.round_factors.top_right),
+            (1.0, -1.0, self.round_factors.bottom_right),
+            (-1.0, 1.0, self.round_factors.top_left),
+            (-1.0, -1.0, self.round_factors.bottom_left),
+        ]
+        .map(|(x, y, r)| (Vector2D::new(x * (half.x - r), y * (half.y - r)), r))
+    }
+
+    /// The area integrals in the rectangle's own frame: the sharp rectangle with a square cut
+    /// from each rounded corner and a quarter of its corner circle put back.
+    fn area_integrals(&self) -> AreaIntegrals {
+        self.corner_circles()
+            .into_iter()
+            .filter(|(_, r)| *r > 0.0)
+            .fold(
+                AreaIntegrals::rectangle(Vector2D::new(0.0, 0.0), self.dimensions),
+                |integrals, (center, r)| {
+                    // a corner circle's center keeps its corner's signs, down to a signed zero
+                    // when the radius takes up a whole half side
+                    let quadrant =
+                        Vector2D::new(1.0_f64.copysign(center.x), 1.0_f64.copysign(center.y));
+                    let square = AreaIntegrals::rectangle(
+                        center + quadrant * (r * 0.5),
+                        Vector2D::new(r, r),
+                    );
+
+                    integrals - square + AreaIntegrals::quarter_disc(center, r, quadrant)
+                },
+            )
+    }
+
     fn rotate_point(&self, v: Vector2D<f64>) -> Vector2D<f64> {
         if self.rotation_angle_in_degrees == 0.0 {
             v
@@ -172,12 +230,177 @@ impl Shape for Rectangle {
 
         length_vector(&max_vector(&d, 0.0)) + min_f64(max_f64(d.x, d.y), 0.0) - r
     }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(self.corner_circles().into_iter().flat_map(|(local, r)| {
+            let center =
+                rotate_vector_by_degrees(&local, -self.rotation_angle_in_degrees) + self.center;
+
+            [center - Vector2D::new(r, r), center + Vector2D::new(r, r)]
+        }))
+    }
+
+    fn oriented_bounding_box(&self) -> OrientedBoundingBox {
+        OrientedBoundingBox::new(
+            self.center,
+            self.dimensions() * 0.5,
+            self.rotation_angle_in_degrees,
+        )
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        match self.corner_radius() {
+            Some(r) => {
+                // around the corner circles, all as far from the center
+                let corner = self.dimensions() * 0.5 - Vector2D::new(r, r);
+
+                Circle::new(self.center, corner.length() + r)
+            }
+            None => numeric::estimate_bounding_circle(self),
+        }
+    }
+
+    fn largest_inscribed_circle(&self) -> Circle {
+        // the corner circles are no larger than it, so it fits whatever the rounding
+        Circle::new(self.center, self.width().min(self.height()) * 0.5)
+    }
+
+    fn gradient(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        // the same regions as `sdf`: beyond a side or a corner the gradient points away from
+        // it, inside it points to the nearest side
+        let translated =
+            rotate_vector_by_degrees(&(*point - self.center), self.rotation_angle_in_degrees);
+
+        let r = match (translated.x >= 0.0, translated.y >= 0.0) {
+            (true, true) => self.round_factors.top_right,
+            (true, false) => self.round_factors.bottom_right,
+            (false, true) => self.round_factors.top_left,
+            (false, false) => self.round_factors.bottom_left,
+        };
+
+        let d = abs_vector(&translated) - self.dimensions() * 0.5 + Vector2D::new(r, r);
+        let outside = max_vector(&d, 0.0);
+        let gradient = if outside.x > 0.0 || outside.y > 0.0 {
+            outside / outside.length()
+        } else if d.x > d.y {
+            Vector2D::new(1.0, 0.0)
+        } else {
+            Vector2D::new(0.0, 1.0)
+        };
+
+        let signed = Vector2D::new(
+            gradient.x * translated.x.signum(),
+            gradient.y * translated.y.signum(),
+        );
+
+        rotate_vector_by_degrees(&signed, -self.rotation_angle_in_degrees)
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        // the distance is exact, so one step against the gradient lands on the outline
+        *point - self.gradient(point) * self.sdf(point)
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        // cross the straight parts of the sides and the corner arcs in the local frame, where
+        // distances along the ray are the same
+        let direction = unit_direction(direction);
+        let local_origin =
+            rotate_vector_by_degrees(&(*origin - self.center), self.rotation_angle_in_degrees);
+        let local_direction = rotate_vector_by_degrees(&direction, self.rotation_angle_in_degrees);
+
+        let RoundFactors {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+        } = self.round_factors;
+        let (x, y) = (self.width() * 0.5, self.height() * 0.5);
+        let sides = [
+            ((x, bottom_right - y), (x, y - top_right)),
+            ((top_left - x, y), (x - top_right, y)),
+            ((-x, bottom_left - y), (-x, y - top_left)),
+            ((bottom_left - x, -y), (x - bottom_right, -y)),
+        ];
+        let corners = [
+            (1.0, 1.0, top_right),
+            (1.0, -1.0, bottom_right),
+            (-1.0, 1.0, top_left),
+            (-1.0, -1.0, bottom_left),
+        ];
+
+        let side_crossings = sides.iter().filter_map(|&((ax, ay), (bx, by))| {
+            let (a, b) = (Vector2D::new(ax, ay), Vector2D::new(bx, by));
+
+            segment_crossing(&local_origin, &local_direction, &a, &b)
+        });
+        let corner_crossings =
+            corners
+                .iter()
+                .filter(|(_, _, r)| *r > 0.0)
+                .flat_map(|&(sx, sy, r)| {
+                    let center = Vector2D::new(sx * (x - r), sy * (y - r));
+                    let crossings = circle_crossings(&local_origin, &local_direction, &center, r);
+
+                    // only the quarter of the circle facing out of the corner is on the outline
+                    crossings.into_iter().flatten().filter(move |t| {
+                        let offset = local_origin + local_direction * *t - center;
+
+                        offset.x * sx >= 0.0 && offset.y * sy >= 0.0
+                    })
+                });
+
+        let t = first_crossing(side_crossings.chain(corner_crossings), max_distance)?;
+
+        Some(hit_at(self, origin, &direction, t))
+    }
+
+    /// Off the center when the corners are rounded unevenly.
+    fn centroid(&self) -> Vector2D<f64> {
+        let local = self.area_integrals().centroid();
+
+        rotate_vector_by_degrees(&local, -self.rotation_angle_in_degrees) + self.center
+    }
+
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        self.area_integrals()
+            .second_moment_of_area()
+            .rotated_by_degrees(self.rotation_angle_in_degrees)
+    }
+}
+
+impl ConvexShape for Rectangle {
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        // the furthest of the corner circles: with the corners rounded alike, that is a sharp
+        // rectangle grown by a circle
+        let local = rotate_vector_by_degrees(direction, self.rotation_angle_in_degrees);
+        let length = local.length();
+        let unit = if length > 0.0 { local / length } else { local };
+
+        let furthest = furthest_vertex(
+            self.corner_circles()
+                .map(|(center, radius)| center + unit * radius),
+            &local,
+        );
+
+        rotate_vector_by_degrees(&furthest, -self.rotation_angle_in_degrees) + self.center
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::bounds::{BoundingBox, OrientedBoundingBox};
+    use crate::circle::Circle;
+    use crate::mass::SecondMomentOfArea;
+    use crate::numeric::{estimate_area_integrals, estimate_gradient, sphere_trace};
     use crate::rectangle::Rectangle;
     use crate::{get_area, get_sdf_grid, RoundFactors, Shape};
+    use std::f64::consts::{FRAC_1_SQRT_2, PI};
     use vector2d::Vector2D;
 
     #[test]
@@ -306,8 +529,8 @@ mod tests {
             Default::default(),
         );
 
-        assert_eq!(rectangle1 == rectangle2, true);
-        assert_eq!(rectangle1 != rectangle3, true);
+        assert!(rectangle1 == rectangle2);
+        assert!(rectangle1 != rectangle3);
     }
 
     #[test]
@@ -414,16 +637,336 @@ mod tests {
 
         let sdf_grid = get_sdf_grid(&rectangle, 3, 3);
 
-        assert_eq!(sdf_grid.get_value(0, 0), 0.7071067811865476);
+        assert_eq!(sdf_grid.get_value(0, 0), FRAC_1_SQRT_2);
         assert_eq!(sdf_grid.get_value(1, 0), 0.5);
-        assert_eq!(sdf_grid.get_value(2, 0), 0.7071067811865476);
+        assert_eq!(sdf_grid.get_value(2, 0), FRAC_1_SQRT_2);
 
         assert_eq!(sdf_grid.get_value(0, 1), 0.5);
         assert_eq!(sdf_grid.get_value(1, 1), -0.5);
         assert_eq!(sdf_grid.get_value(2, 1), 0.5);
 
-        assert_eq!(sdf_grid.get_value(0, 2), 0.7071067811865476);
+        assert_eq!(sdf_grid.get_value(0, 2), FRAC_1_SQRT_2);
         assert_eq!(sdf_grid.get_value(1, 2), 0.5);
-        assert_eq!(sdf_grid.get_value(2, 2), 0.7071067811865476);
+        assert_eq!(sdf_grid.get_value(2, 2), FRAC_1_SQRT_2);
+    }
+
+    #[test]
+    fn rectangle_bounding_box() {
+        let rectangle = Rectangle::new(
+            Vector2D::new(10.0, 10.0),
+            Vector2D::new(40.0, 20.0),
+            0.0,
+            Default::default(),
+        );
+
+        assert_eq!(
+            rectangle.bounding_box(),
+            BoundingBox::new(Vector2D::new(-10.0, 0.0), Vector2D::new(30.0, 20.0))
+        );
+
+        // turned by 45 degrees each half side reaches (20 + 10) / sqrt(2) along both axes
+        let rectangle_oriented = Rectangle::new(
+            Vector2D::new(10.0, 10.0),
+            Vector2D::new(40.0, 20.0),
+            45.0,
+            Default::default(),
+        );
+        let bounds = rectangle_oriented.bounding_box();
+        assert!((bounds.width() - 60.0 * FRAC_1_SQRT_2).abs() < 1e-12);
+        assert!((bounds.height() - 60.0 * FRAC_1_SQRT_2).abs() < 1e-12);
+
+        let oriented = rectangle_oriented.oriented_bounding_box();
+        assert_eq!(
+            oriented,
+            OrientedBoundingBox::new(Vector2D::new(10.0, 10.0), Vector2D::new(20.0, 10.0), 45.0)
+        );
+        assert_eq!(oriented.area(), 800.0);
+    }
+
+    #[test]
+    fn rectangle_rounded_bounding_box() {
+        let rectangle_rounded = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(40.0, 20.0),
+            45.0,
+            RoundFactors::new(5.0, 5.0, 5.0, 5.0),
+        );
+
+        // the corner circles' centers reach (15 + 5) / sqrt(2), the circles 5 further
+        let bounds = rectangle_rounded.bounding_box();
+        let half_extent = 20.0 * FRAC_1_SQRT_2 + 5.0;
+        assert!((bounds.max().x - half_extent).abs() < 1e-12);
+        assert!((bounds.min().y + half_extent).abs() < 1e-12);
+
+        // tight: the rounded outline touches the box
+        let closest = (-2000..=2000)
+            .map(|i| rectangle_rounded.sdf(&Vector2D::new(half_extent, i as f64 * 0.01)))
+            .fold(f64::MAX, f64::min);
+        assert!((-1e-12..1e-3).contains(&closest));
+
+        // only rounding the corners that stick out shrinks the box
+        let rectangle_rounded_one_corner = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(40.0, 20.0),
+            0.0,
+            RoundFactors::new(0.0, 5.0, 0.0, 0.0),
+        );
+        assert_eq!(
+            rectangle_rounded_one_corner.bounding_box(),
+            BoundingBox::new(Vector2D::new(-20.0, -10.0), Vector2D::new(20.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn rectangle_bounding_and_inscribed_circles() {
+        let rectangle_oriented = Rectangle::new(
+            Vector2D::new(10.0, 10.0),
+            Vector2D::new(40.0, 30.0),
+            30.0,
+            Default::default(),
+        );
+
+        assert_eq!(
+            rectangle_oriented.bounding_circle(),
+            Circle::new(Vector2D::new(10.0, 10.0), 25.0)
+        );
+        assert_eq!(
+            rectangle_oriented.largest_inscribed_circle(),
+            Circle::new(Vector2D::new(10.0, 10.0), 15.0)
+        );
+
+        // rounding pulls the corners in
+        let rectangle_rounded = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(40.0, 30.0),
+            0.0,
+            RoundFactors::new(5.0, 5.0, 5.0, 5.0),
+        );
+        let radius = 325.0_f64.sqrt() + 5.0;
+        assert_eq!(rectangle_rounded.bounding_circle().radius(), radius);
+        assert_eq!(rectangle_rounded.largest_inscribed_circle().radius(), 15.0);
+
+        // with one corner rounded the three sharp ones still fix it
+        let rectangle_rounded_one_corner = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(40.0, 30.0),
+            0.0,
+            RoundFactors::new(0.0, 10.0, 0.0, 0.0),
+        );
+        let circle = rectangle_rounded_one_corner.bounding_circle();
+        assert!((circle.radius() - 25.0).abs() < 0.05);
+        assert!(circle.center().length() < 0.05);
+    }
+
+    #[test]
+    fn rectangle_gradient() {
+        let rectangle = Rectangle::new(
+            Vector2D::new(10.0, 10.0),
+            Vector2D::new(40.0, 20.0),
+            0.0,
+            RoundFactors::new(0.0, 5.0, 0.0, 0.0),
+        );
+
+        assert_eq!(
+            rectangle.gradient(&Vector2D::new(40.0, 5.0)),
+            Vector2D::new(1.0, 0.0)
+        );
+        assert_eq!(
+            rectangle.gradient(&Vector2D::new(15.0, 12.0)),
+            Vector2D::new(0.0, 1.0)
+        );
+        assert_eq!(
+            rectangle.gradient(&Vector2D::new(-13.0, -4.0)),
+            Vector2D::new(-0.6, -0.8)
+        );
+        // away from the center of the rounded corner
+        assert_eq!(
+            rectangle.gradient(&Vector2D::new(28.0, 19.0)),
+            Vector2D::new(0.6, 0.8)
+        );
+
+        // turned and rounded, it agrees with the distance's differences off the kinks
+        let rectangle_rounded = Rectangle::new(
+            Vector2D::new(10.0, 10.0),
+            Vector2D::new(40.0, 20.0),
+            30.0,
+            RoundFactors::new(2.0, 5.0, 0.0, 8.0),
+        );
+        for i in 0..400 {
+            let p = Vector2D::new((i % 20) as f64 * 3.1 - 20.0, (i / 20) as f64 * 2.3 - 13.0);
+            let estimate = estimate_gradient(&rectangle_rounded, &p);
+
+            if estimate.length() > 0.999 {
+                assert!((rectangle_rounded.gradient(&p) - estimate).length() < 1e-6);
+                assert!((rectangle_rounded.normal(&p).length() - 1.0).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn rectangle_closest_point() {
+        let rectangle = Rectangle::new(
+            Vector2D::new(10.0, 10.0),
+            Vector2D::new(40.0, 20.0),
+            0.0,
+            RoundFactors::new(0.0, 5.0, 0.0, 0.0),
+        );
+
+        assert_eq!(
+            rectangle.closest_point(&Vector2D::new(40.0, 5.0)),
+            Vector2D::new(30.0, 5.0)
+        );
+        assert_eq!(
+            rectangle.closest_point(&Vector2D::new(15.0, 12.0)),
+            Vector2D::new(15.0, 20.0)
+        );
+        assert_eq!(
+            rectangle.closest_point(&Vector2D::new(-13.0, -4.0)),
+            Vector2D::new(-10.0, 0.0)
+        );
+        assert_eq!(
+            rectangle.closest_point(&Vector2D::new(28.0, 19.0)),
+            Vector2D::new(28.0, 19.0)
+        );
+        assert_eq!(
+            rectangle.closest_point(&Vector2D::new(31.0, 23.0)),
+            Vector2D::new(28.0, 19.0)
+        );
+
+        let rectangle_rounded = Rectangle::new(
+            Vector2D::new(10.0, 10.0),
+            Vector2D::new(40.0, 20.0),
+            30.0,
+            RoundFactors::new(2.0, 5.0, 0.0, 8.0),
+        );
+        for i in 0..400 {
+            let p = Vector2D::new((i % 20) as f64 * 3.1 - 20.0, (i / 20) as f64 * 2.3 - 13.0);
+            let closest = rectangle_rounded.closest_point(&p);
+
+            assert!(rectangle_rounded.sdf(&closest).abs() < 1e-9);
+            assert!(((p - closest).length() - rectangle_rounded.sdf(&p).abs()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn rectangle_ray_intersect() {
+        let rectangle = Rectangle::new(
+            Vector2D::new(10.0, 10.0),
+            Vector2D::new(40.0, 20.0),
+            0.0,
+            RoundFactors::new(0.0, 5.0, 0.0, 0.0),
+        );
+
+        let hit = rectangle
+            .ray_intersect(&Vector2D::new(50.0, 5.0), &Vector2D::new(-2.0, 0.0), 100.0)
+            .unwrap();
+        assert_eq!(hit.distance(), 20.0);
+        assert_eq!(hit.point(), Vector2D::new(30.0, 5.0));
+        assert_eq!(hit.normal(), Vector2D::new(1.0, 0.0));
+
+        // along the diagonal through the center of the rounded corner
+        let hit = rectangle
+            .ray_intersect(
+                &Vector2D::new(34.0, 27.0),
+                &Vector2D::new(-3.0, -4.0),
+                100.0,
+            )
+            .unwrap();
+        assert!((hit.point() - Vector2D::new(28.0, 19.0)).length() < 1e-12);
+        assert!((hit.normal() - Vector2D::new(0.6, 0.8)).length() < 1e-12);
+
+        // where the corner is cut away there is nothing to hit
+        assert_eq!(
+            rectangle.ray_intersect(&Vector2D::new(29.5, 30.0), &Vector2D::new(0.0, -1.0), 8.0),
+            None
+        );
+
+        // turned and rounded, it agrees with sphere tracing
+        let rectangle_rounded = Rectangle::new(
+            Vector2D::new(10.0, 10.0),
+            Vector2D::new(40.0, 20.0),
+            30.0,
+            RoundFactors::new(2.0, 5.0, 0.0, 8.0),
+        );
+        for i in 0..360 {
+            let angle = (i as f64).to_radians();
+            let origin = Vector2D::new(10.0 + 40.0 * angle.cos(), 10.0 + 40.0 * angle.sin());
+            let direction = Vector2D::new(
+                10.0 + 15.0 * (angle * 7.0).cos(),
+                10.0 + 10.0 * (angle * 5.0).sin(),
+            ) - origin;
+
+            let exact = rectangle_rounded.ray_intersect(&origin, &direction, 100.0);
+            let traced = sphere_trace(&rectangle_rounded, &origin, &direction.normalise(), 100.0);
+            let (exact, traced) = (exact.unwrap(), traced.unwrap());
+            assert!((exact.distance() - traced.distance()).abs() < 1e-9);
+            assert!(rectangle_rounded.sdf(&exact.point()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn rectangle_mass_properties() {
+        let rectangle = Rectangle::new(
+            Vector2D::new(1.0, 2.0),
+            Vector2D::new(6.0, 2.0),
+            0.0,
+            RoundFactors::default(),
+        );
+        assert_eq!(rectangle.centroid(), Vector2D::new(1.0, 2.0));
+        assert_eq!(
+            rectangle.second_moment_of_area(),
+            SecondMomentOfArea::new(4.0, 36.0, 0.0)
+        );
+        assert_eq!(rectangle.moment_of_inertia(0.25), 10.0);
+
+        // turned a quarter, the long side runs along y
+        let turned = Rectangle::new(
+            Vector2D::new(1.0, 2.0),
+            Vector2D::new(6.0, 2.0),
+            90.0,
+            RoundFactors::default(),
+        );
+        let moments = turned.second_moment_of_area();
+        assert!((turned.centroid() - Vector2D::new(1.0, 2.0)).length() < 1e-12);
+        assert!((moments.ixx() - 36.0).abs() < 1e-12);
+        assert!((moments.iyy() - 4.0).abs() < 1e-12);
+        assert!(moments.ixy().abs() < 1e-12);
+
+        // uneven corners move the centroid off the center, and make the product of area matter
+        let rounded = Rectangle::new(
+            Vector2D::new(-3.0, 5.0),
+            Vector2D::new(40.0, 20.0),
+            30.0,
+            RoundFactors::new(8.0, 0.0, 2.0, 5.0),
+        );
+        assert!((rounded.centroid() - rounded.center()).length() > 0.1);
+
+        let estimate = estimate_area_integrals(&rounded);
+        assert!((estimate.centroid() - rounded.centroid()).length() < 1e-3);
+
+        let (exact, estimate) = (
+            rounded.second_moment_of_area(),
+            estimate.second_moment_of_area(),
+        );
+        let scale = exact.polar();
+        assert!((exact.ixx() - estimate.ixx()).abs() / scale < 1e-3);
+        assert!((exact.iyy() - estimate.iyy()).abs() / scale < 1e-3);
+        assert!((exact.ixy() - estimate.ixy()).abs() / scale < 1e-3);
+        assert!(exact.ixy().abs() / scale > 0.1);
+
+        // rounded all the way along the short sides, a stadium: a square and a disc
+        let stadium = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(20.0, 10.0),
+            0.0,
+            RoundFactors::new(5.0, 5.0, 5.0, 5.0),
+        );
+        let moments = stadium.second_moment_of_area();
+        let disc = PI * 5.0_f64.powi(4) / 4.0;
+        assert!(stadium.centroid().length() < 1e-12);
+        assert!((moments.ixx() - (1e4 / 12.0 + disc)).abs() < 1e-9);
+        assert!(moments.ixy().abs() < 1e-9);
+        let estimate = estimate_area_integrals(&stadium).second_moment_of_area();
+        assert!((estimate.iyy() - moments.iyy()).abs() / moments.polar() < 1e-3);
     }
 }
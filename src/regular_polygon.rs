@@ -0,0 +1,279 @@
+use crate::bounds::BoundingBox;
+use crate::circle::Circle;
+use crate::convex::{furthest_vertex, ConvexShape};
+use crate::mass::SecondMomentOfArea;
+use crate::ray::{edges_crossing, hit_at, unit_direction, RayHit};
+use crate::{dot_product, rotate_vector_by_degrees, Shape};
+use std::f64::consts::PI;
+use vector2d::Vector2D;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RegularPolygon {
+    center: Vector2D<f64>,
+    sides: usize,
+    circumradius: f64,
+    rotation_angle_in_degrees: f64,
+}
+
+impl RegularPolygon {
+    /// With no rotation one vertex points straight up (+y).
+    pub fn new(
+        center: Vector2D<f64>,
+        sides: usize,
+        circumradius: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        if sides < 3 {
+            panic!("Regular polygon must have at least three sides.");
+        }
+
+        RegularPolygon {
+            center,
+            sides,
+            circumradius,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn sides(&self) -> usize {
+        self.sides
+    }
+
+    pub fn circumradius(&self) -> f64 {
+        // R
+        self.circumradius
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+
+    pub fn inradius(&self) -> f64 {
+        // r
+        self.circumradius * self.half_central_angle().cos()
+    }
+
+    pub fn apothem(&self) -> f64 {
+        // a
+        self.inradius()
+    }
+
+    pub fn side_length(&self) -> f64 {
+        // t
+        2.0 * self.circumradius * self.half_central_angle().sin()
+    }
+
+    pub fn interior_angle_in_degrees(&self) -> f64 {
+        180.0 - 360.0 / self.sides as f64
+    }
+
+    pub fn vertices(&self) -> Vec<Vector2D<f64>> {
+        let central_angle = 2.0 * self.half_central_angle();
+
+        (0..self.sides)
+            .map(|i| {
+                let angle = central_angle * i as f64;
+                let v = Vector2D::new(
+                    -self.circumradius * angle.sin(),
+                    self.circumradius * angle.cos(),
+                );
+
+                rotate_vector_by_degrees(&v, -self.rotation_angle_in_degrees) + self.center
+            })
+            .collect()
+    }
+
+    fn half_central_angle(&self) -> f64 {
+        PI / self.sides as f64
+    }
+}
+
+impl Shape for RegularPolygon {
+    fn area(&self) -> f64 {
+        0.5 * self.sides as f64
+            * self.circumradius.powf(2.0)
+            * (2.0 * self.half_central_angle()).sin()
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.sides as f64 * self.side_length()
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        // translate to center the polygon at origin
+        let mut translated = *point - self.center;
+
+        if self.rotation_angle_in_degrees != 0.0 {
+            translated = rotate_vector_by_degrees(&translated, self.rotation_angle_in_degrees);
+        }
+
+        // fold the point into the sector of a single edge: x runs along the edge normal and
+        // y along the edge, measured from the edge's midpoint
+        let half_angle = self.half_central_angle();
+        let angle = translated
+            .x
+            .atan2(translated.y)
+            .rem_euclid(2.0 * half_angle)
+            - half_angle;
+        let length = translated.length();
+        let mut p = Vector2D::new(length * angle.cos(), length * angle.sin().abs());
+
+        // move the origin to the vertex at the end of the edge and clamp onto the edge
+        p -= Vector2D::new(half_angle.cos(), half_angle.sin()) * self.circumradius;
+        p.y += (-p.y).clamp(0.0, self.circumradius * half_angle.sin());
+
+        p.length() * p.x.signum()
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(self.vertices())
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        Circle::new(self.center, self.circumradius)
+    }
+
+    fn largest_inscribed_circle(&self) -> Circle {
+        Circle::new(self.center, self.inradius())
+    }
+
+    fn gradient(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        // the same folding as `sdf`, keeping the sector's axes to unfold the result with
+        let mut translated = *point - self.center;
+
+        if self.rotation_angle_in_degrees != 0.0 {
+            translated = rotate_vector_by_degrees(&translated, self.rotation_angle_in_degrees);
+        }
+
+        let half_angle = self.half_central_angle();
+        let angle = translated.x.atan2(translated.y);
+        let sector_angle = angle - (angle.rem_euclid(2.0 * half_angle) - half_angle);
+        let across = Vector2D::new(sector_angle.sin(), sector_angle.cos());
+        let along = Vector2D::new(sector_angle.cos(), -sector_angle.sin());
+        let side = dot_product(&translated, &along);
+        let folded = Vector2D::new(dot_product(&translated, &across), side.abs());
+
+        let mut p = folded - Vector2D::new(half_angle.cos(), half_angle.sin()) * self.circumradius;
+        p.y += (-p.y).clamp(0.0, self.circumradius * half_angle.sin());
+
+        let length = p.length();
+        let folded_gradient = if length > 0.0 {
+            p / length * p.x.signum()
+        } else {
+            Vector2D::new(1.0, 0.0)
+        };
+
+        let gradient = across * folded_gradient.x + along * (folded_gradient.y * side.signum());
+
+        rotate_vector_by_degrees(&gradient, -self.rotation_angle_in_degrees)
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        // the distance is exact, so one step against the gradient lands on the outline
+        *point - self.gradient(point) * self.sdf(point)
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        let direction = unit_direction(direction);
+        let vertices = self.vertices();
+        let edges = (0..self.sides).map(|i| (vertices[i], vertices[(i + 1) % self.sides]));
+        let t = edges_crossing(origin, &direction, max_distance, edges)?;
+
+        Some(hit_at(self, origin, &direction, t))
+    }
+
+    fn centroid(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    /// The same about every axis through the center, however the polygon is turned.
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        let polar = self.area()
+            * self.circumradius.powf(2.0)
+            * (2.0 + (2.0 * self.half_central_angle()).cos())
+            / 6.0;
+
+        SecondMomentOfArea::new(polar / 2.0, polar / 2.0, 0.0)
+    }
+}
+
+impl ConvexShape for RegularPolygon {
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        furthest_vertex(self.vertices(), direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::regular_polygon::RegularPolygon;
+    use crate::{get_area, get_sdf, Shape};
+    use vector2d::Vector2D;
+
+    #[test]
+    fn create_regular_polygon() {
+        let pentagon = RegularPolygon::new(Vector2D::new(10.0, 10.0), 5, 10.0, 0.0);
+
+        assert_eq!(
+            format!("The pentagon is: {pentagon:?}"),
+            "The pentagon is: RegularPolygon { center: Vector2D { x: 10.0, y: 10.0 }, sides: 5, circumradius: 10.0, rotation_angle_in_degrees: 0.0 }"
+        );
+
+        assert_eq!(pentagon.sides(), 5);
+        assert_eq!(pentagon.circumradius(), 10.0);
+        assert_eq!(pentagon.inradius(), 8.090169943749475);
+        assert_eq!(pentagon.apothem(), 8.090169943749475);
+        assert_eq!(pentagon.side_length(), 11.755705045849464);
+        assert_eq!(pentagon.interior_angle_in_degrees(), 108.0);
+
+        assert_eq!(get_area(&pentagon), 237.76412907378838);
+        assert_eq!(pentagon.perimeter(), 58.77852522924732);
+
+        let vertices = pentagon.vertices();
+        assert_eq!(vertices.len(), 5);
+        assert_eq!(vertices[0], Vector2D::new(10.0, 20.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn regular_polygon_needs_three_sides() {
+        RegularPolygon::new(Vector2D::new(0.0, 0.0), 2, 10.0, 0.0);
+    }
+
+    #[test]
+    fn regular_polygon_equality() {
+        let polygon1 = RegularPolygon::new(Vector2D::new(10.0, 10.0), 5, 10.0, 0.0);
+        let polygon2 = RegularPolygon::new(Vector2D::new(10.0, 10.0), 5, 10.0, 0.0);
+        let polygon3 = RegularPolygon::new(Vector2D::new(10.0, 10.0), 8, 10.0, 0.0);
+
+        assert!(polygon1 == polygon2);
+        assert!(polygon1 != polygon3);
+    }
+
+    #[test]
+    fn regular_polygon_sdf() {
+        let square = RegularPolygon::new(Vector2D::new(0.0, 0.0), 4, 2.0_f64.sqrt(), 45.0);
+
+        assert!((get_sdf(&square, &Vector2D::new(0.0, 0.0)) + 1.0).abs() < 1e-12); // center
+        assert!(square.sdf(&Vector2D::new(1.0, 0.5)).abs() < 1e-12);
+        assert!((square.sdf(&Vector2D::new(3.0, 0.0)) - 2.0).abs() < 1e-12);
+        assert!((square.sdf(&Vector2D::new(4.0, 5.0)) - 5.0).abs() < 1e-12);
+        assert!((square.sdf(&Vector2D::new(0.5, -0.25)) + 0.5).abs() < 1e-12);
+
+        let octagon = RegularPolygon::new(Vector2D::new(10.0, 10.0), 8, 10.0, 0.0);
+
+        assert_eq!(octagon.sdf(&Vector2D::new(10.0, 10.0)), -octagon.inradius());
+        for vertex in octagon.vertices() {
+            assert!(octagon.sdf(&vertex).abs() < 1e-12);
+        }
+        assert!((octagon.sdf(&Vector2D::new(10.0, 25.0)) - 5.0).abs() < 1e-12);
+    }
+}
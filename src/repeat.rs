@@ -0,0 +1,502 @@
+use crate::bounds::BoundingBox;
+use crate::circle::Circle;
+use crate::{rotate_vector_by_radians, Shape};
+use std::f64::consts::PI;
+use std::fmt;
+use vector2d::Vector2D;
+
+/// Endless copies of any shape on a rectangular grid; the shape as given is the copy in the
+/// cell around the origin and the others are offset from it by multiples of `spacing`.
+///
+/// Each point is measured against the nearest copies only, so the distance is exact as long
+/// as the shape doesn't reach past its neighbouring cells.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Repeat<S: Shape> {
+    shape: S,
+    spacing: Vector2D<f64>,
+}
+
+impl<S: Shape> Repeat<S> {
+    pub fn new(shape: S, spacing: Vector2D<f64>) -> Self {
+        if spacing.x <= 0.0 || spacing.y <= 0.0 {
+            panic!("Repeat spacing must be greater than zero.");
+        }
+
+        Repeat { shape, spacing }
+    }
+
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    pub fn spacing(&self) -> Vector2D<f64> {
+        self.spacing
+    }
+}
+
+impl<S: Shape> Shape for Repeat<S> {
+    fn area(&self) -> f64 {
+        f64::INFINITY
+    }
+
+    fn perimeter(&self) -> f64 {
+        f64::INFINITY
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        nearest_cells(point, self.spacing)
+            .iter()
+            .map(|cell| self.shape.sdf(&(*point - cell_offset(cell, self.spacing))))
+            .fold(f64::MAX, f64::min)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::infinite()
+    }
+
+    /// The wrapped shape's; copies that overlap may leave room for a larger one.
+    fn largest_inscribed_circle(&self) -> Circle {
+        self.shape.largest_inscribed_circle()
+    }
+}
+
+/// `columns` by `rows` copies of any shape on a rectangular grid; the shape as given is the
+/// bottom left copy and the others follow along +x and +y, `spacing` apart.
+///
+/// Area and perimeter add up the copies, so they assume the copies don't overlap.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LimitedRepeat<S: Shape> {
+    shape: S,
+    spacing: Vector2D<f64>,
+    columns: usize,
+    rows: usize,
+}
+
+impl<S: Shape> LimitedRepeat<S> {
+    pub fn new(shape: S, spacing: Vector2D<f64>, columns: usize, rows: usize) -> Self {
+        if spacing.x <= 0.0 || spacing.y <= 0.0 {
+            panic!("Repeat spacing must be greater than zero.");
+        }
+
+        if columns == 0 || rows == 0 {
+            panic!("Repeat must have at least one column and one row.");
+        }
+
+        LimitedRepeat {
+            shape,
+            spacing,
+            columns,
+            rows,
+        }
+    }
+
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    pub fn spacing(&self) -> Vector2D<f64> {
+        self.spacing
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn count(&self) -> usize {
+        self.columns * self.rows
+    }
+}
+
+impl<S: Shape> Shape for LimitedRepeat<S> {
+    fn area(&self) -> f64 {
+        self.shape.area() * self.count() as f64
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.shape.perimeter() * self.count() as f64
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let last = Vector2D::new((self.columns - 1) as f64, (self.rows - 1) as f64);
+
+        nearest_cells(point, self.spacing)
+            .iter()
+            .map(|cell| {
+                let cell = Vector2D::new(cell.x.clamp(0.0, last.x), cell.y.clamp(0.0, last.y));
+
+                self.shape.sdf(&(*point - cell_offset(&cell, self.spacing)))
+            })
+            .fold(f64::MAX, f64::min)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        let bounds = self.shape.bounding_box();
+        let last = Vector2D::new((self.columns - 1) as f64, (self.rows - 1) as f64);
+
+        BoundingBox::new(
+            bounds.min(),
+            bounds.max() + cell_offset(&last, self.spacing),
+        )
+    }
+
+    /// The wrapped shape's; copies that overlap may leave room for a larger one.
+    fn largest_inscribed_circle(&self) -> Circle {
+        self.shape.largest_inscribed_circle()
+    }
+}
+
+/// `count` copies of any shape spaced evenly around `center`; the shape as given is the first
+/// copy and the others follow counter-clockwise. The shape may sit at any angle about the
+/// center.
+///
+/// Area and perimeter add up the copies, so they assume the copies don't overlap.
+#[derive(Clone, Copy, PartialEq)]
+pub struct PolarRepeat<S: Shape> {
+    shape: S,
+    center: Vector2D<f64>,
+    count: usize,
+    angle: f64, // of the shape about the center, worked out once in new
+}
+
+/// Leaves out the angle, which follows from the shape.
+impl<S: Shape + fmt::Debug> fmt::Debug for PolarRepeat<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PolarRepeat")
+            .field("shape", &self.shape)
+            .field("center", &self.center)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+impl<S: Shape> PolarRepeat<S> {
+    pub fn new(shape: S, center: Vector2D<f64>, count: usize) -> Self {
+        if count == 0 {
+            panic!("Polar repeat must have at least one copy.");
+        }
+
+        // copies are picked by their angle, so measure from the middle of the shape
+        let bounds = shape.bounding_box();
+        let angle = if bounds.is_finite() {
+            let offset = bounds.center() - center;
+
+            offset.y.atan2(offset.x)
+        } else {
+            0.0
+        };
+
+        PolarRepeat {
+            shape,
+            center,
+            count,
+            angle,
+        }
+    }
+
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<S: Shape> Shape for PolarRepeat<S> {
+    fn area(&self) -> f64 {
+        self.shape.area() * self.count as f64
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.shape.perimeter() * self.count as f64
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let translated = *point - self.center;
+        let sector = 2.0 * PI / self.count as f64;
+
+        // turn the point back onto the first copy from the nearest copy and its neighbour
+        // on the point's side
+        let angle = translated.y.atan2(translated.x) - self.angle;
+        let nearest = (angle / sector).round();
+        let neighbour = nearest + (angle / sector - nearest).signum();
+
+        [nearest, neighbour]
+            .iter()
+            .map(|copy| {
+                let local = rotate_vector_by_radians(&translated, -copy * sector);
+
+                self.shape.sdf(&(local + self.center))
+            })
+            .fold(f64::MAX, f64::min)
+    }
+}
+
+/// Endless copies of any shape on a hexagonal (triangular) lattice, `spacing` apart in every
+/// direction; rows of copies run along x. The shape as given is the copy at the origin.
+/// Circles packed like this make a dot grid, suitably sized hexagons a honeycomb.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HexagonalRepeat<S: Shape> {
+    shape: S,
+    spacing: f64,
+}
+
+impl<S: Shape> HexagonalRepeat<S> {
+    pub fn new(shape: S, spacing: f64) -> Self {
+        if spacing <= 0.0 {
+            panic!("Repeat spacing must be greater than zero.");
+        }
+
+        HexagonalRepeat { shape, spacing }
+    }
+
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    pub fn spacing(&self) -> f64 {
+        self.spacing
+    }
+}
+
+impl<S: Shape> Shape for HexagonalRepeat<S> {
+    fn area(&self) -> f64 {
+        f64::INFINITY
+    }
+
+    fn perimeter(&self) -> f64 {
+        f64::INFINITY
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        // the lattice is two rectangular grids, the second shifted by half a cell
+        let spacing = Vector2D::new(self.spacing, self.spacing * 3.0_f64.sqrt());
+        let shift = spacing * 0.5;
+
+        let even = nearest_cells(point, spacing)
+            .iter()
+            .map(|cell| self.shape.sdf(&(*point - cell_offset(cell, spacing))))
+            .fold(f64::MAX, f64::min);
+
+        let shifted = *point - shift;
+        let odd = nearest_cells(&shifted, spacing)
+            .iter()
+            .map(|cell| self.shape.sdf(&(shifted - cell_offset(cell, spacing))))
+            .fold(f64::MAX, f64::min);
+
+        even.min(odd)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::infinite()
+    }
+
+    /// The wrapped shape's; copies that overlap may leave room for a larger one.
+    fn largest_inscribed_circle(&self) -> Circle {
+        self.shape.largest_inscribed_circle()
+    }
+}
+
+/// The grid cell `point` is in and its three neighbours nearest to the point.
+fn nearest_cells(point: &Vector2D<f64>, spacing: Vector2D<f64>) -> [Vector2D<f64>; 4] {
+    let cell = Vector2D::new((point.x / spacing.x).round(), (point.y / spacing.y).round());
+    let side = Vector2D::new(
+        (point.x / spacing.x - cell.x).signum(),
+        (point.y / spacing.y - cell.y).signum(),
+    );
+
+    [
+        cell,
+        cell + Vector2D::new(side.x, 0.0),
+        cell + Vector2D::new(0.0, side.y),
+        cell + side,
+    ]
+}
+
+fn cell_offset(cell: &Vector2D<f64>, spacing: Vector2D<f64>) -> Vector2D<f64> {
+    Vector2D::new(cell.x * spacing.x, cell.y * spacing.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bounds::BoundingBox;
+    use crate::circle::Circle;
+    use crate::repeat::{HexagonalRepeat, LimitedRepeat, PolarRepeat, Repeat};
+    use crate::{get_sdf, Hexagon, HexagonOrientation, Shape, ShapeFactory};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    fn dot() -> Circle {
+        Circle::new(Vector2D::new(0.0, 0.0), 1.0)
+    }
+
+    #[test]
+    fn create_repeat() {
+        let repeat = Repeat::new(dot(), Vector2D::new(5.0, 4.0));
+
+        assert_eq!(
+            format!("The repeat is: {repeat:?}"),
+            "The repeat is: Repeat { shape: Circle { center: Vector2D { x: 0.0, y: 0.0 }, radius: 1.0 }, spacing: Vector2D { x: 5.0, y: 4.0 } }"
+        );
+
+        assert_eq!(repeat.spacing(), Vector2D::new(5.0, 4.0));
+        assert_eq!(repeat.area(), f64::INFINITY);
+        assert_eq!(repeat.perimeter(), f64::INFINITY);
+        assert!(repeat == Repeat::new(dot(), Vector2D::new(5.0, 4.0)));
+        assert!(repeat != Repeat::new(dot(), Vector2D::new(5.0, 5.0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "spacing must be greater than zero")]
+    fn repeat_needs_spacing() {
+        Repeat::new(dot(), Vector2D::new(5.0, 0.0));
+    }
+
+    #[test]
+    fn repeat_sdf() {
+        let repeat = Repeat::new(dot(), Vector2D::new(5.0, 4.0));
+
+        assert_eq!(get_sdf(&repeat, &Vector2D::new(0.0, 0.0)), -1.0);
+        assert_eq!(repeat.sdf(&Vector2D::new(50.0, -40.0)), -1.0);
+        assert_eq!(repeat.sdf(&Vector2D::new(-10.0, 6.0)), 1.0);
+        assert_eq!(repeat.sdf(&Vector2D::new(2.5, 0.0)), 1.5);
+
+        // a shape off center in its cell is still measured against its nearest copy
+        let off_center = Repeat::new(
+            Circle::new(Vector2D::new(2.0, 0.0), 1.0),
+            Vector2D::new(5.0, 5.0),
+        );
+        assert_eq!(off_center.sdf(&Vector2D::new(-3.0, 0.0)), -1.0);
+        assert_eq!(off_center.sdf(&Vector2D::new(-2.0, 0.0)), 0.0);
+        assert_eq!(off_center.sdf(&Vector2D::new(4.5, 0.0)), 1.5);
+    }
+
+    #[test]
+    fn limited_repeat_sdf() {
+        let repeat = LimitedRepeat::new(dot(), Vector2D::new(5.0, 5.0), 3, 2);
+
+        assert_eq!(repeat.count(), 6);
+        assert_eq!(repeat.area(), 6.0 * PI);
+        assert_eq!(repeat.perimeter(), 12.0 * PI);
+
+        assert_eq!(get_sdf(&repeat, &Vector2D::new(10.0, 5.0)), -1.0);
+        assert_eq!(repeat.sdf(&Vector2D::new(15.0, 5.0)), 4.0);
+        assert_eq!(repeat.sdf(&Vector2D::new(-5.0, 0.0)), 4.0);
+        assert_eq!(repeat.sdf(&Vector2D::new(5.0, 10.0)), 4.0);
+        assert_eq!(repeat.sdf(&Vector2D::new(5.0, -10.0)), 9.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one column and one row")]
+    fn limited_repeat_needs_copies() {
+        LimitedRepeat::new(dot(), Vector2D::new(5.0, 5.0), 0, 2);
+    }
+
+    #[test]
+    fn polar_repeat_sdf() {
+        let petal = Circle::new(Vector2D::new(10.0, 0.0), 2.0);
+        let flower = PolarRepeat::new(petal, Vector2D::new(0.0, 0.0), 4);
+
+        assert_eq!(flower.count(), 4);
+        assert_eq!(flower.area(), 16.0 * PI);
+
+        assert_eq!(get_sdf(&flower, &Vector2D::new(10.0, 0.0)), -2.0);
+        assert!((flower.sdf(&Vector2D::new(0.0, 10.0)) + 2.0).abs() < 1e-12);
+        assert!((flower.sdf(&Vector2D::new(-10.0, 0.0)) + 2.0).abs() < 1e-12);
+        assert!((flower.sdf(&Vector2D::new(0.0, -13.0)) - 1.0).abs() < 1e-12);
+        assert_eq!(flower.sdf(&Vector2D::new(0.0, 0.0)), 8.0);
+
+        // around a center away from the origin
+        let moved = PolarRepeat::new(
+            Circle::new(Vector2D::new(15.0, 5.0), 2.0),
+            Vector2D::new(5.0, 5.0),
+            3,
+        );
+        let copy = Vector2D::new(
+            5.0 + 10.0 * (2.0 * PI / 3.0).cos(),
+            5.0 + 10.0 * (2.0 * PI / 3.0).sin(),
+        );
+        assert!((moved.sdf(&copy) + 2.0).abs() < 1e-12);
+
+        // a shape away from +x is measured against the copies either side of it
+        let off_axis = PolarRepeat::new(
+            Circle::new(
+                Vector2D::new(
+                    10.0 * 81_f64.to_radians().cos(),
+                    10.0 * 81_f64.to_radians().sin(),
+                ),
+                1.0,
+            ),
+            Vector2D::new(0.0, 0.0),
+            4,
+        );
+        let nearest = 10.0 * (2.0 - 2.0 * 9_f64.to_radians().cos()).sqrt() - 1.0;
+        assert!((off_axis.sdf(&Vector2D::new(0.0, 10.0)) - nearest).abs() < 1e-12);
+        assert!((off_axis.sdf(&Vector2D::new(-10.0, 0.0)) - nearest).abs() < 1e-12);
+        assert!(
+            (off_axis.sdf(&Vector2D::new(
+                10.0 * 171_f64.to_radians().cos(),
+                10.0 * 171_f64.to_radians().sin()
+            )) + 1.0)
+                .abs()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn hexagonal_repeat_sdf() {
+        let dots = HexagonalRepeat::new(dot(), 4.0);
+
+        assert_eq!(dots.spacing(), 4.0);
+        assert_eq!(get_sdf(&dots, &Vector2D::new(0.0, 0.0)), -1.0);
+        assert_eq!(dots.sdf(&Vector2D::new(8.0, 0.0)), -1.0);
+        // every other row is shifted by half the spacing
+        let next_row = Vector2D::new(2.0, 12.0_f64.sqrt());
+        assert!((dots.sdf(&next_row) + 1.0).abs() < 1e-12);
+        assert!((dots.sdf(&(next_row * 3.0)) + 1.0).abs() < 1e-12);
+        assert_eq!(dots.sdf(&Vector2D::new(2.0, 0.0)), 1.0);
+
+        // pointy topped hexagons with a gap of 1 between neighbours
+        let honeycomb = HexagonalRepeat::new(
+            Hexagon::new(
+                Vector2D::new(0.0, 0.0),
+                10.0,
+                HexagonOrientation::Horizontal,
+            ),
+            2.0 * 8.660254037844387 + 1.0,
+        );
+        let gap = Vector2D::new(honeycomb.spacing() * 0.5, 0.0);
+        assert!((honeycomb.sdf(&gap) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn repeat_through_the_factory() {
+        let dots = ShapeFactory::new_repeat(
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 1.0),
+            Vector2D::new(3.0, 3.0),
+        );
+
+        assert_eq!(dots.sdf(&Vector2D::new(30.0, 30.0)), -1.0);
+    }
+
+    #[test]
+    fn repeat_bounding_boxes() {
+        assert!(!Repeat::new(dot(), Vector2D::new(5.0, 5.0))
+            .bounding_box()
+            .is_finite());
+
+        let repeat = LimitedRepeat::new(dot(), Vector2D::new(5.0, 5.0), 3, 2);
+        assert_eq!(
+            repeat.bounding_box(),
+            BoundingBox::new(Vector2D::new(-1.0, -1.0), Vector2D::new(11.0, 6.0))
+        );
+    }
+}
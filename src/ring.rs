@@ -0,0 +1,154 @@
+use crate::circle::Circle;
+use crate::Shape;
+use std::f64::consts::PI;
+use vector2d::Vector2D;
+
+/// An annulus: the region between two concentric circles.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ring {
+    center: Vector2D<f64>,
+    inner_radius: f64,
+    outer_radius: f64,
+}
+
+impl Ring {
+    pub fn new(center: Vector2D<f64>, inner_radius: f64, outer_radius: f64) -> Self {
+        if inner_radius > outer_radius {
+            panic!("Ring inner radius must not be greater than its outer radius.");
+        }
+
+        Ring {
+            center,
+            inner_radius,
+            outer_radius,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn inner_radius(&self) -> f64 {
+        self.inner_radius
+    }
+
+    pub fn outer_radius(&self) -> f64 {
+        self.outer_radius
+    }
+
+    pub fn width(&self) -> f64 {
+        self.outer_radius - self.inner_radius
+    }
+}
+
+impl Shape for Ring {
+    fn area(&self) -> f64 {
+        PI * (self.outer_radius.powf(2.0) - self.inner_radius.powf(2.0))
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * (self.outer_radius + self.inner_radius)
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        // translate to center the ring at origin
+        let translated = *point - self.center;
+        let middle_radius = (self.inner_radius + self.outer_radius) * 0.5;
+
+        (translated.length() - middle_radius).abs() - self.width() * 0.5
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        Circle::new(self.center, self.outer_radius)
+    }
+
+    /// One of the circles that fill the band, to the right of the center.
+    fn largest_inscribed_circle(&self) -> Circle {
+        let middle_radius = (self.inner_radius + self.outer_radius) * 0.5;
+
+        Circle::new(
+            self.center + Vector2D::new(middle_radius, 0.0),
+            self.width() * 0.5,
+        )
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        let translated = *point - self.center;
+        let length = translated.length();
+        let direction = if length > 0.0 {
+            translated / length
+        } else {
+            Vector2D::new(1.0, 0.0)
+        };
+
+        let middle_radius = (self.inner_radius + self.outer_radius) * 0.5;
+        let radius = if length < middle_radius {
+            self.inner_radius
+        } else {
+            self.outer_radius
+        };
+
+        self.center + direction * radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ring::Ring;
+    use crate::{get_area, get_sdf, Shape};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    #[test]
+    fn create_ring() {
+        let ring = Ring::new(Vector2D::new(10.0, 10.0), 5.0, 10.0);
+
+        assert_eq!(
+            format!("The ring is: {ring:?}"),
+            "The ring is: Ring { center: Vector2D { x: 10.0, y: 10.0 }, inner_radius: 5.0, outer_radius: 10.0 }"
+        );
+
+        assert_eq!(ring.width(), 5.0);
+        assert_eq!(get_area(&ring), 75.0 * PI);
+        assert_eq!(ring.perimeter(), 30.0 * PI);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ring_radii_are_ordered() {
+        Ring::new(Vector2D::new(0.0, 0.0), 10.0, 5.0);
+    }
+
+    #[test]
+    fn ring_equality() {
+        let ring1 = Ring::new(Vector2D::new(10.0, 10.0), 5.0, 10.0);
+        let ring2 = Ring::new(Vector2D::new(10.0, 10.0), 5.0, 10.0);
+        let ring3 = Ring::new(Vector2D::new(10.0, 10.0), 4.0, 10.0);
+
+        assert!(ring1 == ring2);
+        assert!(ring1 != ring3);
+    }
+
+    #[test]
+    fn ring_sdf() {
+        let ring = Ring::new(Vector2D::new(10.0, 10.0), 5.0, 10.0);
+
+        assert_eq!(get_sdf(&ring, &Vector2D::new(10.0, 10.0)), 5.0); // center
+        assert_eq!(ring.sdf(&Vector2D::new(17.5, 10.0)), -2.5);
+        assert_eq!(ring.sdf(&Vector2D::new(10.0, 15.0)), 0.0);
+        assert_eq!(ring.sdf(&Vector2D::new(10.0, 0.0)), 0.0);
+        assert_eq!(ring.sdf(&Vector2D::new(-5.0, 10.0)), 5.0);
+        assert_eq!(ring.sdf(&Vector2D::new(12.0, 10.0)), 3.0);
+    }
+
+    #[test]
+    fn ring_bounding_and_inscribed_circles() {
+        let ring = Ring::new(Vector2D::new(0.0, 0.0), 6.0, 10.0);
+
+        assert_eq!(ring.bounding_circle().radius(), 10.0);
+
+        let inscribed = ring.largest_inscribed_circle();
+        assert_eq!(inscribed.radius(), 2.0);
+        assert_eq!(ring.sdf(&inscribed.center()), -2.0);
+    }
+}
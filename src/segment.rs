@@ -0,0 +1,93 @@
+use crate::{closest_point_on_segment, Shape};
+use vector2d::Vector2D;
+
+/// A line segment has no interior, so its signed distance is never negative. Its outline is
+/// traversed along both sides, making the perimeter twice its length.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Segment {
+    start: Vector2D<f64>,
+    end: Vector2D<f64>,
+}
+
+impl Segment {
+    pub fn new(start: Vector2D<f64>, end: Vector2D<f64>) -> Self {
+        Segment { start, end }
+    }
+
+    pub fn start(&self) -> Vector2D<f64> {
+        self.start
+    }
+
+    pub fn end(&self) -> Vector2D<f64> {
+        self.end
+    }
+
+    pub fn length(&self) -> f64 {
+        (self.end - self.start).length()
+    }
+
+    pub fn midpoint(&self) -> Vector2D<f64> {
+        (self.start + self.end) * 0.5
+    }
+}
+
+impl Shape for Segment {
+    fn area(&self) -> f64 {
+        0.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * self.length()
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        (*point - closest_point_on_segment(point, &self.start, &self.end)).length()
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        closest_point_on_segment(point, &self.start, &self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segment::Segment;
+    use crate::{get_area, get_sdf, Shape};
+    use vector2d::Vector2D;
+
+    #[test]
+    fn create_segment() {
+        let segment = Segment::new(Vector2D::new(0.0, 0.0), Vector2D::new(3.0, 4.0));
+
+        assert_eq!(
+            format!("The segment is: {segment:?}"),
+            "The segment is: Segment { start: Vector2D { x: 0.0, y: 0.0 }, end: Vector2D { x: 3.0, y: 4.0 } }"
+        );
+
+        assert_eq!(segment.length(), 5.0);
+        assert_eq!(segment.midpoint(), Vector2D::new(1.5, 2.0));
+        assert_eq!(get_area(&segment), 0.0);
+        assert_eq!(segment.perimeter(), 10.0);
+    }
+
+    #[test]
+    fn segment_equality() {
+        let segment1 = Segment::new(Vector2D::new(0.0, 0.0), Vector2D::new(3.0, 4.0));
+        let segment2 = Segment::new(Vector2D::new(0.0, 0.0), Vector2D::new(3.0, 4.0));
+        let segment3 = Segment::new(Vector2D::new(3.0, 4.0), Vector2D::new(0.0, 0.0));
+
+        assert!(segment1 == segment2);
+        assert!(segment1 != segment3);
+    }
+
+    #[test]
+    fn segment_sdf() {
+        let segment = Segment::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 0.0));
+
+        assert_eq!(get_sdf(&segment, &Vector2D::new(5.0, 0.0)), 0.0);
+        assert_eq!(segment.sdf(&Vector2D::new(5.0, 3.0)), 3.0);
+        assert_eq!(segment.sdf(&Vector2D::new(5.0, -3.0)), 3.0);
+        assert_eq!(segment.sdf(&Vector2D::new(-3.0, 4.0)), 5.0);
+        assert_eq!(segment.sdf(&Vector2D::new(13.0, -4.0)), 5.0);
+    }
+}
@@ -0,0 +1,632 @@
+use crate::bounds::BoundingBox;
+use crate::circle::Circle;
+use crate::mass::SecondMomentOfArea;
+use crate::numeric::{
+    estimate_bounding_circle, estimate_bounds, estimate_inscribed_circle, estimate_perimeter,
+    project_onto_outline,
+};
+use crate::ray::{hit_at, unit_direction, RayHit};
+use crate::{dot_product, rotate_vector_by_degrees, Shape};
+use std::fmt;
+use vector2d::Vector2D;
+
+/// A 2D affine transform: a linear part given by the images of the x and y axes, followed by
+/// a translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine2D {
+    x_axis: Vector2D<f64>,
+    y_axis: Vector2D<f64>,
+    translation: Vector2D<f64>,
+}
+
+impl Affine2D {
+    pub fn new(x_axis: Vector2D<f64>, y_axis: Vector2D<f64>, translation: Vector2D<f64>) -> Self {
+        Affine2D {
+            x_axis,
+            y_axis,
+            translation,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Affine2D::new(
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(0.0, 1.0),
+            Vector2D::new(0.0, 0.0),
+        )
+    }
+
+    pub fn translation(offset: Vector2D<f64>) -> Self {
+        Affine2D {
+            translation: offset,
+            ..Affine2D::identity()
+        }
+    }
+
+    /// Turns about the origin in the same sense as the shapes' `rotation_angle_in_degrees`
+    /// (clockwise for positive angles).
+    pub fn rotation_in_degrees(angle_in_degrees: f64) -> Self {
+        Affine2D::new(
+            rotate_vector_by_degrees(&Vector2D::new(1.0, 0.0), -angle_in_degrees),
+            rotate_vector_by_degrees(&Vector2D::new(0.0, 1.0), -angle_in_degrees),
+            Vector2D::new(0.0, 0.0),
+        )
+    }
+
+    pub fn scale(factor: f64) -> Self {
+        Affine2D::non_uniform_scale(Vector2D::new(factor, factor))
+    }
+
+    pub fn non_uniform_scale(factors: Vector2D<f64>) -> Self {
+        Affine2D::new(
+            Vector2D::new(factors.x, 0.0),
+            Vector2D::new(0.0, factors.y),
+            Vector2D::new(0.0, 0.0),
+        )
+    }
+
+    pub fn x_axis(&self) -> Vector2D<f64> {
+        self.x_axis
+    }
+
+    pub fn y_axis(&self) -> Vector2D<f64> {
+        self.y_axis
+    }
+
+    pub fn offset(&self) -> Vector2D<f64> {
+        self.translation
+    }
+
+    /// This transform followed by `next`.
+    pub fn then(&self, next: &Affine2D) -> Affine2D {
+        Affine2D::new(
+            next.transform_vector(&self.x_axis),
+            next.transform_vector(&self.y_axis),
+            next.transform_point(&self.translation),
+        )
+    }
+
+    pub fn transform_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        self.transform_vector(point) + self.translation
+    }
+
+    /// Applies only the linear part, as for directions and offsets.
+    pub fn transform_vector(&self, vector: &Vector2D<f64>) -> Vector2D<f64> {
+        self.x_axis * vector.x + self.y_axis * vector.y
+    }
+
+    pub fn determinant(&self) -> f64 {
+        self.x_axis.x * self.y_axis.y - self.y_axis.x * self.x_axis.y
+    }
+
+    pub fn inverse(&self) -> Option<Affine2D> {
+        let determinant = self.determinant();
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        let x_axis = Vector2D::new(self.y_axis.y, -self.x_axis.y) / determinant;
+        let y_axis = Vector2D::new(-self.y_axis.x, self.x_axis.x) / determinant;
+        let translation = -(x_axis * self.translation.x + y_axis * self.translation.y);
+
+        Some(Affine2D::new(x_axis, y_axis, translation))
+    }
+
+    /// The least and greatest factor by which the transform stretches any length (the
+    /// singular values of its linear part).
+    pub fn scale_bounds(&self) -> (f64, f64) {
+        let e = (self.x_axis.x + self.y_axis.y) * 0.5;
+        let f = (self.x_axis.x - self.y_axis.y) * 0.5;
+        let g = (self.x_axis.y + self.y_axis.x) * 0.5;
+        let h = (self.x_axis.y - self.y_axis.x) * 0.5;
+        let q = e.hypot(h);
+        let r = f.hypot(g);
+
+        ((q - r).abs(), q + r)
+    }
+
+    /// True when the transform scales all lengths equally (a rotation, reflection, uniform
+    /// scale and translation), so it preserves distances up to that one factor.
+    pub fn is_uniform(&self) -> bool {
+        let (least, greatest) = self.scale_bounds();
+
+        greatest - least <= 1e-12 * greatest
+    }
+}
+
+impl Default for Affine2D {
+    fn default() -> Self {
+        Affine2D::identity()
+    }
+}
+
+/// Any shape placed through an affine transform.
+///
+/// Translations, rotations, reflections and uniform scales keep the signed distance exact.
+/// A non-uniform scale or shear can't: the distance is then scaled by the least stretch of
+/// the transform, which never over-estimates the true distance (so it is safe to march
+/// along) but may under-estimate it by up to the ratio of the greatest to the least stretch.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Transformed<S: Shape> {
+    shape: S,
+    transform: Affine2D,
+    inverse: Affine2D, // worked out once in new
+}
+
+/// Leaves out the inverse, which follows from the transform.
+impl<S: Shape + fmt::Debug> fmt::Debug for Transformed<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transformed")
+            .field("shape", &self.shape)
+            .field("transform", &self.transform)
+            .finish()
+    }
+}
+
+impl<S: Shape> Transformed<S> {
+    pub fn new(shape: S, transform: Affine2D) -> Self {
+        let inverse = match transform.inverse() {
+            Some(inverse) => inverse,
+            None => panic!("Transform must be invertible."),
+        };
+
+        Transformed {
+            shape,
+            transform,
+            inverse,
+        }
+    }
+
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    pub fn transform(&self) -> Affine2D {
+        self.transform
+    }
+
+    /// `circle` from the wrapped shape's own space, for uniform transforms only.
+    fn transformed_circle(&self, circle: Circle) -> Circle {
+        Circle::new(
+            self.transform.transform_point(&circle.center()),
+            circle.radius() * self.transform.scale_bounds().1,
+        )
+    }
+
+    /// The point in the wrapped shape's own space that `point` corresponds to.
+    pub fn local_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        self.inverse.transform_point(point)
+    }
+}
+
+impl<S: Shape> Shape for Transformed<S> {
+    fn area(&self) -> f64 {
+        self.shape.area() * self.transform.determinant().abs()
+    }
+
+    fn perimeter(&self) -> f64 {
+        if self.transform.is_uniform() {
+            self.shape.perimeter() * self.transform.scale_bounds().1
+        } else {
+            estimate_perimeter(self)
+        }
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let (least, _) = self.transform.scale_bounds();
+
+        self.shape.sdf(&self.local_point(point)) * least
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        if self.transform.is_uniform() {
+            estimate_bounds(self)
+        } else {
+            // the distance is only a bound here, too loose to find the shape by probing from
+            // far away, so take the wrapped shape's box through the transform instead
+            let corners = self.shape.oriented_bounding_box().corners();
+
+            BoundingBox::from_points(corners.map(|corner| self.transform.transform_point(&corner)))
+        }
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        if self.transform.is_uniform() {
+            self.transformed_circle(self.shape.bounding_circle())
+        } else {
+            estimate_bounding_circle(self)
+        }
+    }
+
+    fn largest_inscribed_circle(&self) -> Circle {
+        if self.transform.is_uniform() {
+            self.transformed_circle(self.shape.largest_inscribed_circle())
+        } else {
+            estimate_inscribed_circle(self)
+        }
+    }
+
+    fn gradient(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        // by the chain rule, the wrapped gradient through the transpose of the inverse
+        let gradient = self.shape.gradient(&self.local_point(point));
+        let (least, _) = self.transform.scale_bounds();
+
+        Vector2D::new(
+            dot_product(&self.inverse.x_axis(), &gradient),
+            dot_product(&self.inverse.y_axis(), &gradient),
+        ) * least
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        if self.transform.is_uniform() {
+            // distances only scale, so the nearest point stays the nearest
+            let closest = self.shape.closest_point(&self.local_point(point));
+
+            self.transform.transform_point(&closest)
+        } else {
+            project_onto_outline(self, point)
+        }
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        // cast the ray through the wrapped shape's space, where it runs at a different speed
+        let direction = unit_direction(direction);
+        let local_direction = self.inverse.transform_vector(&direction);
+        let speed = local_direction.length();
+
+        let local_hit = self.shape.ray_intersect(
+            &self.local_point(origin),
+            &local_direction,
+            max_distance * speed,
+        )?;
+
+        Some(hit_at(
+            self,
+            origin,
+            &direction,
+            local_hit.distance() / speed,
+        ))
+    }
+
+    /// The wrapped shape's centroid carried through the transform, which keeps it the center
+    /// of the area.
+    fn centroid(&self) -> Vector2D<f64> {
+        self.transform.transform_point(&self.shape.centroid())
+    }
+
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        self.shape
+            .second_moment_of_area()
+            .transformed(&self.transform.x_axis(), &self.transform.y_axis())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::circle::Circle;
+    use crate::mass::AreaIntegrals;
+    use crate::numeric::estimate_gradient;
+    use crate::rectangle::Rectangle;
+    use crate::transform::{Affine2D, Transformed};
+    use crate::{get_area, get_sdf, Shape, ShapeFactory};
+    use std::f64::consts::PI;
+    use vector2d::Vector2D;
+
+    fn unit_circle() -> Circle {
+        Circle::new(Vector2D::new(0.0, 0.0), 1.0)
+    }
+
+    #[test]
+    fn affine_transforms() {
+        let p = Vector2D::new(2.0, 1.0);
+
+        assert_eq!(Affine2D::default(), Affine2D::identity());
+        assert_eq!(Affine2D::identity().transform_point(&p), p);
+        assert_eq!(
+            Affine2D::translation(Vector2D::new(3.0, -1.0)).transform_point(&p),
+            Vector2D::new(5.0, 0.0)
+        );
+        assert_eq!(
+            Affine2D::non_uniform_scale(Vector2D::new(2.0, 3.0)).transform_point(&p),
+            Vector2D::new(4.0, 3.0)
+        );
+
+        // positive angles turn clockwise, like the shapes' rotation_angle_in_degrees
+        let quarter_turn = Affine2D::rotation_in_degrees(90.0).transform_point(&p);
+        assert!((quarter_turn - Vector2D::new(1.0, -2.0)).length() < 1e-12);
+
+        let transform = Affine2D::scale(2.0).then(&Affine2D::translation(Vector2D::new(1.0, 1.0)));
+        assert_eq!(transform.transform_point(&p), Vector2D::new(5.0, 3.0));
+        assert_eq!(transform.transform_vector(&p), Vector2D::new(4.0, 2.0));
+        assert_eq!(transform.determinant(), 4.0);
+
+        let inverse = transform.inverse().unwrap();
+        assert_eq!(inverse.transform_point(&Vector2D::new(5.0, 3.0)), p);
+        assert_eq!(Affine2D::scale(0.0).inverse(), None);
+    }
+
+    #[test]
+    fn affine_scale_bounds() {
+        assert_eq!(Affine2D::scale(2.0).scale_bounds(), (2.0, 2.0));
+        assert_eq!(
+            Affine2D::non_uniform_scale(Vector2D::new(-3.0, 2.0)).scale_bounds(),
+            (2.0, 3.0)
+        );
+
+        let similarity = Affine2D::rotation_in_degrees(30.0).then(&Affine2D::scale(2.0));
+        assert!(similarity.is_uniform());
+        assert!((similarity.scale_bounds().1 - 2.0).abs() < 1e-12);
+
+        let shear = Affine2D::new(
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(1.0, 1.0),
+            Vector2D::new(0.0, 0.0),
+        );
+        assert!(!shear.is_uniform());
+        let (least, greatest) = shear.scale_bounds();
+        assert!((least * greatest - 1.0).abs() < 1e-12); // their product is the determinant
+    }
+
+    #[test]
+    fn create_transformed() {
+        let transformed = Transformed::new(
+            unit_circle(),
+            Affine2D::translation(Vector2D::new(5.0, 0.0)),
+        );
+
+        assert_eq!(
+            format!("The shape is: {transformed:?}"),
+            "The shape is: Transformed { shape: Circle { center: Vector2D { x: 0.0, y: 0.0 }, radius: 1.0 }, transform: Affine2D { x_axis: Vector2D { x: 1.0, y: 0.0 }, y_axis: Vector2D { x: 0.0, y: 1.0 }, translation: Vector2D { x: 5.0, y: 0.0 } } }"
+        );
+
+        assert_eq!(transformed.shape(), &unit_circle());
+        assert_eq!(get_area(&transformed), PI);
+        assert_eq!(transformed.perimeter(), 2.0 * PI);
+    }
+
+    #[test]
+    #[should_panic(expected = "invertible")]
+    fn transformed_needs_an_invertible_transform() {
+        Transformed::new(
+            unit_circle(),
+            Affine2D::non_uniform_scale(Vector2D::new(1.0, 0.0)),
+        );
+    }
+
+    #[test]
+    fn transformed_equality() {
+        let transformed1 = Transformed::new(unit_circle(), Affine2D::scale(2.0));
+        let transformed2 = Transformed::new(unit_circle(), Affine2D::scale(2.0));
+        let transformed3 = Transformed::new(unit_circle(), Affine2D::scale(3.0));
+
+        assert!(transformed1 == transformed2);
+        assert!(transformed1 != transformed3);
+    }
+
+    #[test]
+    fn transformed_sdf_uniform() {
+        let transform =
+            Affine2D::scale(10.0).then(&Affine2D::translation(Vector2D::new(10.0, 10.0)));
+        let transformed = Transformed::new(unit_circle(), transform);
+
+        assert_eq!(get_sdf(&transformed, &Vector2D::new(10.0, 10.0)), -10.0);
+        assert_eq!(transformed.sdf(&Vector2D::new(25.0, 10.0)), 5.0);
+        assert_eq!(transformed.area(), 100.0 * PI);
+        assert_eq!(transformed.perimeter(), 20.0 * PI);
+
+        // a rotated rectangle matches the one rotated by its own angle
+        let rectangle = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(4.0, 2.0),
+            0.0,
+            Default::default(),
+        );
+        let rotated = Rectangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(4.0, 2.0),
+            30.0,
+            Default::default(),
+        );
+        let transformed = Transformed::new(rectangle, Affine2D::rotation_in_degrees(30.0));
+        for p in [
+            Vector2D::new(3.0, 1.0),
+            Vector2D::new(-1.0, 2.0),
+            Vector2D::new(0.5, -0.5),
+        ] {
+            assert!((transformed.sdf(&p) - rotated.sdf(&p)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn transformed_sdf_non_uniform() {
+        // a circle stretched into an ellipse with radii 4 and 1
+        let ellipse = Transformed::new(
+            unit_circle(),
+            Affine2D::non_uniform_scale(Vector2D::new(4.0, 1.0)),
+        );
+
+        assert_eq!(ellipse.area(), 4.0 * PI);
+        assert!((ellipse.perimeter() - 17.156843550313767).abs() < 0.02);
+
+        // the outline stays exact, distances away from it are a lower bound
+        assert_eq!(ellipse.sdf(&Vector2D::new(4.0, 0.0)), 0.0);
+        assert_eq!(ellipse.sdf(&Vector2D::new(0.0, 1.0)), 0.0);
+        assert_eq!(ellipse.sdf(&Vector2D::new(0.0, 3.0)), 2.0);
+        let beyond_the_end = ellipse.sdf(&Vector2D::new(8.0, 0.0));
+        assert!(beyond_the_end > 0.0 && beyond_the_end <= 4.0);
+    }
+
+    #[test]
+    fn transformed_through_the_factory() {
+        let shape = ShapeFactory::new_transformed(
+            ShapeFactory::new_circle(Vector2D::new(0.0, 0.0), 1.0),
+            Affine2D::translation(Vector2D::new(1.0, 1.0)),
+        );
+
+        assert_eq!(shape.sdf(&Vector2D::new(1.0, 1.0)), -1.0);
+    }
+
+    #[test]
+    fn transformed_bounding_box() {
+        // stretched three times as wide, the circle's box follows exactly
+        let stretched = Transformed::new(
+            unit_circle(),
+            Affine2D::non_uniform_scale(Vector2D::new(3.0, 1.0))
+                .then(&Affine2D::translation(Vector2D::new(10.0, 0.0))),
+        );
+        let bounds = stretched.bounding_box();
+        assert!((bounds.min() - Vector2D::new(7.0, -1.0)).length() < 1e-12);
+        assert!((bounds.max() - Vector2D::new(13.0, 1.0)).length() < 1e-12);
+
+        // moved and turned, it is still found by probing
+        let moved = Transformed::new(
+            unit_circle(),
+            Affine2D::rotation_in_degrees(30.0)
+                .then(&Affine2D::translation(Vector2D::new(-5.0, 5.0))),
+        );
+        let bounds = moved.bounding_box();
+        assert!(bounds.contains(&Vector2D::new(-6.0, 5.0)));
+        assert!((bounds.width() - 2.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn transformed_bounding_and_inscribed_circles() {
+        let moved = Transformed::new(
+            unit_circle(),
+            Affine2D::scale(3.0).then(&Affine2D::translation(Vector2D::new(10.0, 0.0))),
+        );
+        assert_eq!(
+            moved.bounding_circle(),
+            Circle::new(Vector2D::new(10.0, 0.0), 3.0)
+        );
+        assert_eq!(moved.largest_inscribed_circle().radius(), 3.0);
+
+        // squashed, the circle around it spans the long axis, the one inside the short one
+        let squashed = Transformed::new(
+            unit_circle(),
+            Affine2D::non_uniform_scale(Vector2D::new(3.0, 1.0)),
+        );
+        assert!((squashed.bounding_circle().radius() - 3.0).abs() < 0.02);
+        let inscribed = squashed.largest_inscribed_circle();
+        assert!(inscribed.radius() <= 1.0 && inscribed.radius() > 0.98);
+    }
+
+    #[test]
+    fn transformed_gradient() {
+        let squashed = Transformed::new(
+            Rectangle::new(
+                Vector2D::new(0.0, 0.0),
+                Vector2D::new(2.0, 2.0),
+                0.0,
+                Default::default(),
+            ),
+            Affine2D::non_uniform_scale(Vector2D::new(3.0, 1.0))
+                .then(&Affine2D::rotation_in_degrees(20.0)),
+        );
+
+        for i in 0..100 {
+            let p = Vector2D::new((i % 10) as f64 * 0.9 - 4.0, (i / 10) as f64 * 0.7 - 3.0);
+            let estimate = estimate_gradient(&squashed, &p);
+
+            if estimate.length() > 0.2 {
+                let gradient = squashed.gradient(&p);
+                assert!((gradient - estimate).length() < 1e-6 * gradient.length().max(1.0));
+            }
+        }
+    }
+
+    #[test]
+    fn transformed_closest_point() {
+        let moved = Transformed::new(
+            unit_circle(),
+            Affine2D::scale(3.0).then(&Affine2D::translation(Vector2D::new(10.0, 0.0))),
+        );
+        let closest = moved.closest_point(&Vector2D::new(10.0, 10.0));
+        assert!((closest - Vector2D::new(10.0, 3.0)).length() < 1e-12);
+
+        let squashed = Transformed::new(
+            unit_circle(),
+            Affine2D::non_uniform_scale(Vector2D::new(3.0, 1.0)),
+        );
+        let closest = squashed.closest_point(&Vector2D::new(5.0, 0.0));
+        assert!((closest - Vector2D::new(3.0, 0.0)).length() < 1e-9);
+        let closest = squashed.closest_point(&Vector2D::new(2.0, 2.0));
+        assert!(squashed.sdf(&closest).abs() < 1e-9);
+    }
+
+    #[test]
+    fn transformed_ray_intersect() {
+        let squashed = Transformed::new(
+            unit_circle(),
+            Affine2D::non_uniform_scale(Vector2D::new(3.0, 1.0))
+                .then(&Affine2D::translation(Vector2D::new(10.0, 0.0))),
+        );
+
+        let hit = squashed
+            .ray_intersect(&Vector2D::new(0.0, 0.0), &Vector2D::new(1.0, 0.0), 100.0)
+            .unwrap();
+        assert!((hit.distance() - 7.0).abs() < 1e-12);
+        assert!((hit.normal() - Vector2D::new(-1.0, 0.0)).length() < 1e-12);
+
+        let hit = squashed
+            .ray_intersect(&Vector2D::new(10.0, 5.0), &Vector2D::new(0.0, -2.0), 100.0)
+            .unwrap();
+        assert!((hit.point() - Vector2D::new(10.0, 1.0)).length() < 1e-12);
+
+        assert_eq!(
+            squashed.ray_intersect(&Vector2D::new(0.0, 0.0), &Vector2D::new(1.0, 0.0), 6.5),
+            None
+        );
+    }
+
+    #[test]
+    fn transformed_mass_properties() {
+        // the unit circle stretched into an ellipse with radii 3 and 1
+        let ellipse = Transformed::new(
+            unit_circle(),
+            Affine2D::non_uniform_scale(Vector2D::new(3.0, 1.0))
+                .then(&Affine2D::translation(Vector2D::new(10.0, 0.0))),
+        );
+        let moments = ellipse.second_moment_of_area();
+        assert_eq!(ellipse.centroid(), Vector2D::new(10.0, 0.0));
+        assert!((moments.ixx() - 3.0 * PI / 4.0).abs() < 1e-12);
+        assert!((moments.iyy() - 27.0 * PI / 4.0).abs() < 1e-12);
+        assert_eq!(moments.ixy(), 0.0);
+
+        // a sheared square is a parallelogram
+        let square = Rectangle::new(
+            Vector2D::new(1.0, 1.0),
+            Vector2D::new(2.0, 2.0),
+            0.0,
+            Default::default(),
+        );
+        let shear = Affine2D::new(
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(1.0, 1.0),
+            Vector2D::new(5.0, 0.0),
+        );
+        let sheared = Transformed::new(square, shear);
+        let parallelogram = AreaIntegrals::polygon(
+            &[
+                Vector2D::new(0.0, 0.0),
+                Vector2D::new(2.0, 0.0),
+                Vector2D::new(2.0, 2.0),
+                Vector2D::new(0.0, 2.0),
+            ]
+            .map(|corner| shear.transform_point(&corner)),
+        );
+        let (moments, expected) = (
+            sheared.second_moment_of_area(),
+            parallelogram.second_moment_of_area(),
+        );
+        assert!((sheared.centroid() - parallelogram.centroid()).length() < 1e-12);
+        assert!((moments.ixx() - expected.ixx()).abs() < 1e-12);
+        assert!((moments.iyy() - expected.iyy()).abs() < 1e-12);
+        assert!((moments.ixy() - expected.ixy()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,599 @@
+use crate::bounds::BoundingBox;
+use crate::circle::Circle;
+use crate::convex::{furthest_vertex, ConvexShape};
+use crate::mass::{AreaIntegrals, SecondMomentOfArea};
+use crate::ray::{edges_crossing, hit_at, unit_direction, RayHit};
+use crate::{closest_point_on_edges, closest_point_on_segment, cross_product, to_world, Shape};
+use vector2d::Vector2D;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Triangle {
+    vertices: [Vector2D<f64>; 3],
+}
+
+impl Triangle {
+    pub fn new(a: Vector2D<f64>, b: Vector2D<f64>, c: Vector2D<f64>) -> Self {
+        Triangle {
+            vertices: [a, b, c],
+        }
+    }
+
+    pub fn vertices(&self) -> [Vector2D<f64>; 3] {
+        self.vertices
+    }
+
+    pub fn side_lengths(&self) -> [f64; 3] {
+        // side i is opposite vertex i
+        let [a, b, c] = self.vertices;
+
+        [(c - b).length(), (a - c).length(), (b - a).length()]
+    }
+
+    pub fn centroid(&self) -> Vector2D<f64> {
+        let [a, b, c] = self.vertices;
+
+        (a + b + c) / 3.0
+    }
+
+    pub fn incircle(&self) -> Circle {
+        let [a, b, c] = self.vertices;
+        let [la, lb, lc] = self.side_lengths();
+        let perimeter = la + lb + lc;
+
+        let incenter = (a * la + b * lb + c * lc) / perimeter;
+        let inradius = 2.0 * self.area() / perimeter;
+
+        Circle::new(incenter, inradius)
+    }
+
+    pub fn circumcircle(&self) -> Circle {
+        let [a, b, c] = self.vertices;
+        let d = 2.0 * (a.x * (b.y - c.y) + b.x * (c.y - a.y) + c.x * (a.y - b.y));
+
+        let a_squared = a.length_squared();
+        let b_squared = b.length_squared();
+        let c_squared = c.length_squared();
+
+        let circumcenter = Vector2D::new(
+            (a_squared * (b.y - c.y) + b_squared * (c.y - a.y) + c_squared * (a.y - b.y)) / d,
+            (a_squared * (c.x - b.x) + b_squared * (a.x - c.x) + c_squared * (b.x - a.x)) / d,
+        );
+
+        Circle::new(circumcenter, (a - circumcenter).length())
+    }
+}
+
+impl Shape for Triangle {
+    fn area(&self) -> f64 {
+        let [a, b, c] = self.vertices;
+
+        cross_product(&(b - a), &(c - a)).abs() * 0.5
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.side_lengths().iter().sum()
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        let [a, b, c] = self.vertices;
+        let winding = cross_product(&(b - a), &(c - a)).signum();
+
+        let mut distance_squared = f64::MAX;
+        let mut inside = true;
+        for (start, end) in [(a, b), (b, c), (c, a)] {
+            let closest = closest_point_on_segment(point, &start, &end);
+            distance_squared = distance_squared.min((*point - closest).length_squared());
+
+            // inside means on the same side of every edge as the opposite vertex
+            if winding * cross_product(&(end - start), &(*point - start)) < 0.0 {
+                inside = false;
+            }
+        }
+
+        if inside {
+            -distance_squared.sqrt()
+        } else {
+            distance_squared.sqrt()
+        }
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(self.vertices)
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        Circle::enclosing(&self.vertices)
+    }
+
+    fn largest_inscribed_circle(&self) -> Circle {
+        self.incircle()
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        let [a, b, c] = self.vertices;
+
+        closest_point_on_edges(point, [(a, b), (b, c), (c, a)])
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        let direction = unit_direction(direction);
+        let [a, b, c] = self.vertices;
+        let t = edges_crossing(origin, &direction, max_distance, [(a, b), (b, c), (c, a)])?;
+
+        Some(hit_at(self, origin, &direction, t))
+    }
+
+    fn centroid(&self) -> Vector2D<f64> {
+        Triangle::centroid(self)
+    }
+
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        AreaIntegrals::polygon(&self.vertices()).second_moment_of_area()
+    }
+}
+
+impl ConvexShape for Triangle {
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        furthest_vertex(self.vertices(), direction)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IsoscelesTriangle {
+    center: Vector2D<f64>, // centroid
+    base: f64,
+    height: f64,
+    rotation_angle_in_degrees: f64,
+}
+
+impl IsoscelesTriangle {
+    pub fn new(
+        center: Vector2D<f64>,
+        base: f64,
+        height: f64,
+        rotation_angle_in_degrees: f64,
+    ) -> Self {
+        IsoscelesTriangle {
+            center,
+            base,
+            height,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn base(&self) -> f64 {
+        self.base
+    }
+
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+
+    pub fn leg_length(&self) -> f64 {
+        (self.base * 0.5).hypot(self.height)
+    }
+
+    pub fn vertices(&self) -> [Vector2D<f64>; 3] {
+        self.as_triangle().vertices()
+    }
+
+    pub fn centroid(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn incircle(&self) -> Circle {
+        self.as_triangle().incircle()
+    }
+
+    pub fn circumcircle(&self) -> Circle {
+        self.as_triangle().circumcircle()
+    }
+
+    /// The apex points up (+y) before rotation; the base is below the centroid.
+    pub fn as_triangle(&self) -> Triangle {
+        let apex = Vector2D::new(0.0, self.height * 2.0 / 3.0);
+        let base_right = Vector2D::new(self.base * 0.5, -self.height / 3.0);
+        let base_left = Vector2D::new(-self.base * 0.5, -self.height / 3.0);
+
+        let [apex, base_left, base_right] = to_world(
+            [apex, base_left, base_right],
+            self.center,
+            self.rotation_angle_in_degrees,
+        );
+
+        Triangle::new(apex, base_left, base_right)
+    }
+}
+
+impl Shape for IsoscelesTriangle {
+    fn area(&self) -> f64 {
+        self.base * self.height * 0.5
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.base + 2.0 * self.leg_length()
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        self.as_triangle().sdf(point)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        self.as_triangle().bounding_box()
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        self.as_triangle().bounding_circle()
+    }
+
+    fn largest_inscribed_circle(&self) -> Circle {
+        self.incircle()
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        self.as_triangle().closest_point(point)
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        self.as_triangle()
+            .ray_intersect(origin, direction, max_distance)
+    }
+
+    fn centroid(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        AreaIntegrals::polygon(&self.vertices()).second_moment_of_area()
+    }
+}
+
+impl ConvexShape for IsoscelesTriangle {
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        furthest_vertex(self.vertices(), direction)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EquilateralTriangle {
+    center: Vector2D<f64>, // centroid
+    side_length: f64,
+    rotation_angle_in_degrees: f64,
+}
+
+impl EquilateralTriangle {
+    pub fn new(center: Vector2D<f64>, side_length: f64, rotation_angle_in_degrees: f64) -> Self {
+        EquilateralTriangle {
+            center,
+            side_length,
+            rotation_angle_in_degrees,
+        }
+    }
+
+    pub fn center(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn side_length(&self) -> f64 {
+        self.side_length
+    }
+
+    pub fn rotation_angle_in_degrees(&self) -> f64 {
+        self.rotation_angle_in_degrees
+    }
+
+    pub fn height(&self) -> f64 {
+        self.side_length * 3.0_f64.sqrt() * 0.5
+    }
+
+    pub fn inradius(&self) -> f64 {
+        self.height() / 3.0
+    }
+
+    pub fn circumradius(&self) -> f64 {
+        self.height() * 2.0 / 3.0
+    }
+
+    pub fn vertices(&self) -> [Vector2D<f64>; 3] {
+        self.as_triangle().vertices()
+    }
+
+    pub fn centroid(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    pub fn incircle(&self) -> Circle {
+        Circle::new(self.center, self.inradius())
+    }
+
+    pub fn circumcircle(&self) -> Circle {
+        Circle::new(self.center, self.circumradius())
+    }
+
+    /// One vertex points up (+y) before rotation.
+    pub fn as_triangle(&self) -> Triangle {
+        IsoscelesTriangle::new(
+            self.center,
+            self.side_length,
+            self.height(),
+            self.rotation_angle_in_degrees,
+        )
+        .as_triangle()
+    }
+}
+
+impl Shape for EquilateralTriangle {
+    fn area(&self) -> f64 {
+        self.side_length * self.height() * 0.5
+    }
+
+    fn perimeter(&self) -> f64 {
+        3.0 * self.side_length
+    }
+
+    fn sdf(&self, point: &Vector2D<f64>) -> f64 {
+        self.as_triangle().sdf(point)
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        self.as_triangle().bounding_box()
+    }
+
+    fn bounding_circle(&self) -> Circle {
+        self.as_triangle().bounding_circle()
+    }
+
+    fn largest_inscribed_circle(&self) -> Circle {
+        self.incircle()
+    }
+
+    fn closest_point(&self, point: &Vector2D<f64>) -> Vector2D<f64> {
+        self.as_triangle().closest_point(point)
+    }
+
+    fn ray_intersect(
+        &self,
+        origin: &Vector2D<f64>,
+        direction: &Vector2D<f64>,
+        max_distance: f64,
+    ) -> Option<RayHit> {
+        self.as_triangle()
+            .ray_intersect(origin, direction, max_distance)
+    }
+
+    fn centroid(&self) -> Vector2D<f64> {
+        self.center
+    }
+
+    fn second_moment_of_area(&self) -> SecondMomentOfArea {
+        AreaIntegrals::polygon(&self.vertices()).second_moment_of_area()
+    }
+}
+
+impl ConvexShape for EquilateralTriangle {
+    fn support(&self, direction: &Vector2D<f64>) -> Vector2D<f64> {
+        furthest_vertex(self.vertices(), direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bounds::BoundingBox;
+    use crate::triangle::{EquilateralTriangle, IsoscelesTriangle, Triangle};
+    use crate::{get_area, get_sdf, Shape};
+    use vector2d::Vector2D;
+
+    #[test]
+    fn create_triangle() {
+        let triangle = Triangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(4.0, 0.0),
+            Vector2D::new(0.0, 3.0),
+        );
+
+        assert_eq!(
+            format!("The triangle is: {triangle:?}"),
+            "The triangle is: Triangle { vertices: [Vector2D { x: 0.0, y: 0.0 }, Vector2D { x: 4.0, y: 0.0 }, Vector2D { x: 0.0, y: 3.0 }] }"
+        );
+
+        assert_eq!(triangle.side_lengths(), [5.0, 3.0, 4.0]);
+        assert_eq!(get_area(&triangle), 6.0);
+        assert_eq!(triangle.perimeter(), 12.0);
+
+        assert_eq!(triangle.centroid(), Vector2D::new(4.0 / 3.0, 1.0));
+
+        let incircle = triangle.incircle();
+        assert_eq!(incircle.center(), Vector2D::new(1.0, 1.0));
+        assert_eq!(incircle.radius(), 1.0);
+
+        let circumcircle = triangle.circumcircle();
+        assert_eq!(circumcircle.center(), Vector2D::new(2.0, 1.5));
+        assert_eq!(circumcircle.radius(), 2.5);
+    }
+
+    #[test]
+    fn triangle_equality() {
+        let triangle1 = Triangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(4.0, 0.0),
+            Vector2D::new(0.0, 3.0),
+        );
+        let triangle2 = Triangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(4.0, 0.0),
+            Vector2D::new(0.0, 3.0),
+        );
+        let triangle3 = Triangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(5.0, 0.0),
+            Vector2D::new(0.0, 3.0),
+        );
+
+        assert!(triangle1 == triangle2);
+        assert!(triangle1 != triangle3);
+    }
+
+    #[test]
+    fn triangle_sdf() {
+        let triangle = Triangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(4.0, 0.0),
+            Vector2D::new(0.0, 3.0),
+        );
+
+        assert_eq!(
+            get_sdf(&triangle, &Vector2D::new(1.0, 1.0)),
+            -0.9999999999999999
+        ); // incenter
+        assert_eq!(triangle.sdf(&Vector2D::new(2.0, 0.0)), 0.0);
+        assert_eq!(triangle.sdf(&Vector2D::new(2.0, -2.0)), 2.0);
+        assert_eq!(triangle.sdf(&Vector2D::new(-3.0, -4.0)), 5.0);
+        assert_eq!(triangle.sdf(&Vector2D::new(4.0, 3.0)), 2.4);
+
+        // the winding order of the vertices does not matter
+        let clockwise = Triangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(0.0, 3.0),
+            Vector2D::new(4.0, 0.0),
+        );
+
+        assert_eq!(clockwise.sdf(&Vector2D::new(1.0, 1.0)), -0.9999999999999999);
+        assert_eq!(clockwise.sdf(&Vector2D::new(4.0, 3.0)), 2.4);
+    }
+
+    #[test]
+    fn create_isosceles_triangle() {
+        let triangle = IsoscelesTriangle::new(Vector2D::new(10.0, 10.0), 6.0, 12.0, 0.0);
+
+        assert_eq!(
+            format!("The triangle is: {triangle:?}"),
+            "The triangle is: IsoscelesTriangle { center: Vector2D { x: 10.0, y: 10.0 }, base: 6.0, height: 12.0, rotation_angle_in_degrees: 0.0 }"
+        );
+
+        assert_eq!(
+            triangle.vertices(),
+            [
+                Vector2D::new(10.0, 18.0),
+                Vector2D::new(7.0, 6.0),
+                Vector2D::new(13.0, 6.0)
+            ]
+        );
+        assert_eq!(triangle.centroid(), Vector2D::new(10.0, 10.0));
+        assert_eq!(triangle.leg_length(), 12.36931687685298);
+
+        assert_eq!(get_area(&triangle), 36.0);
+        assert_eq!(triangle.perimeter(), 30.73863375370596);
+        assert_eq!(triangle.area(), triangle.as_triangle().area());
+    }
+
+    #[test]
+    fn isosceles_triangle_sdf() {
+        let triangle = IsoscelesTriangle::new(Vector2D::new(10.0, 10.0), 6.0, 12.0, 0.0);
+
+        assert_eq!(triangle.sdf(&Vector2D::new(10.0, 6.0)), 0.0);
+        assert_eq!(triangle.sdf(&Vector2D::new(10.0, 4.0)), 2.0);
+        assert_eq!(triangle.sdf(&Vector2D::new(10.0, 20.0)), 2.0);
+        assert_eq!(triangle.sdf(&Vector2D::new(10.0, 7.0)), -1.0);
+
+        let upside_down = IsoscelesTriangle::new(Vector2D::new(10.0, 10.0), 6.0, 12.0, 180.0);
+
+        assert!((upside_down.sdf(&Vector2D::new(10.0, 14.0))).abs() < 1e-12);
+        assert!((upside_down.sdf(&Vector2D::new(10.0, 0.0)) - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn create_equilateral_triangle() {
+        let triangle = EquilateralTriangle::new(Vector2D::new(0.0, 0.0), 6.0, 0.0);
+
+        assert_eq!(triangle.side_length(), 6.0);
+        assert_eq!(triangle.height(), 5.196152422706632);
+        assert_eq!(triangle.inradius(), 1.7320508075688774);
+        assert_eq!(triangle.circumradius(), 3.464101615137755);
+
+        assert_eq!(get_area(&triangle), 15.588457268119896);
+        assert_eq!(triangle.perimeter(), 18.0);
+
+        let incircle = triangle.as_triangle().incircle();
+        assert!((incircle.radius() - triangle.inradius()).abs() < 1e-12);
+        assert!(incircle.center().length() < 1e-12);
+
+        let circumcircle = triangle.as_triangle().circumcircle();
+        assert!((circumcircle.radius() - triangle.circumradius()).abs() < 1e-12);
+        assert!(circumcircle.center().length() < 1e-12);
+    }
+
+    #[test]
+    fn equilateral_triangle_sdf() {
+        let triangle = EquilateralTriangle::new(Vector2D::new(0.0, 0.0), 6.0, 0.0);
+
+        assert_eq!(triangle.sdf(&Vector2D::new(0.0, 0.0)), -1.7320508075688772); // center
+        assert!(triangle.sdf(&Vector2D::new(0.0, -1.7320508075688772)).abs() < 1e-12);
+        assert_eq!(
+            triangle.sdf(&Vector2D::new(0.0, 4.464101615137754)),
+            0.9999999999999996
+        );
+
+        // every rotation by a multiple of 120 degrees maps the triangle onto itself
+        let rotated = EquilateralTriangle::new(Vector2D::new(0.0, 0.0), 6.0, 120.0);
+        let point = Vector2D::new(1.0, 2.0);
+        assert!((rotated.sdf(&point) - triangle.sdf(&point)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn triangle_bounding_box() {
+        let triangle = Triangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(10.0, 2.0),
+            Vector2D::new(3.0, 8.0),
+        );
+
+        assert_eq!(
+            triangle.bounding_box(),
+            BoundingBox::new(Vector2D::new(0.0, 0.0), Vector2D::new(10.0, 8.0))
+        );
+
+        let isosceles = IsoscelesTriangle::new(Vector2D::new(0.0, 0.0), 10.0, 6.0, 0.0);
+        let bounds = isosceles.bounding_box();
+        assert_eq!(bounds.width(), 10.0);
+        assert_eq!(bounds.height(), 6.0);
+    }
+
+    #[test]
+    fn triangle_bounding_and_inscribed_circles() {
+        // obtuse: the longest side is a diameter, the circumcircle is larger
+        let triangle = Triangle::new(
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(10.0, 0.0),
+            Vector2D::new(4.0, 2.0),
+        );
+        let circle = triangle.bounding_circle();
+        assert_eq!(circle.center(), Vector2D::new(5.0, 0.0));
+        assert_eq!(circle.radius(), 5.0);
+        assert!(triangle.circumcircle().radius() > 5.0);
+
+        assert_eq!(triangle.largest_inscribed_circle(), triangle.incircle());
+
+        let equilateral = EquilateralTriangle::new(Vector2D::new(0.0, 0.0), 10.0, 0.0);
+        let circle = equilateral.bounding_circle();
+        assert!((circle.radius() - equilateral.circumradius()).abs() < 1e-12);
+        assert!((circle.center() - equilateral.centroid()).length() < 1e-12);
+    }
+}